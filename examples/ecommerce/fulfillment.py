@@ -0,0 +1,128 @@
+"""
+Saga example: order fulfillment across two bounded contexts.
+OrderCreated (orders) -> reserve stock (inventory) -> charge payment (payments) -> done;
+a declined payment aborts the saga and releases the reserved stock.
+To run: uvicorn fulfillment:app --reload, then POST /orders/commands/create_order.
+"""
+import sys
+from dataclasses import dataclass, field
+from pathlib import Path
+
+sys.path.insert(0, str(Path(__file__).resolve().parent))
+
+from urich import Application
+from urich.ddd import Command, DomainModule
+from urich.domain import AppError, DomainEvent, EventBus
+from urich.saga import Abort, Complete, Saga, SagaModule, SendCommand
+
+from orders.domain import OrderCreated
+from orders.module import orders_module
+
+
+# --- inventory context ---
+
+@dataclass
+class ReserveStock(Command, context="inventory"):
+    order_id: str
+    quantity: int
+
+
+@dataclass
+class ReleaseStock(Command, context="inventory"):
+    order_id: str
+
+
+@dataclass
+class StockReservedForOrder(DomainEvent):
+    order_id: str
+
+
+class Stock:
+    def __init__(self) -> None:
+        self.available = 10
+        self.reserved: dict[str, int] = {}
+
+
+async def reserve_stock(cmd: ReserveStock, stock: Stock, event_bus: EventBus) -> None:
+    if cmd.quantity > stock.available:
+        raise AppError.conflict("INSUFFICIENT_STOCK", f"Only {stock.available} left")
+    stock.available -= cmd.quantity
+    stock.reserved[cmd.order_id] = cmd.quantity
+    await event_bus.publish(StockReservedForOrder(order_id=cmd.order_id))
+
+
+def release_stock(cmd: ReleaseStock, stock: Stock) -> None:
+    stock.available += stock.reserved.pop(cmd.order_id, 0)
+
+
+# --- payments context ---
+
+@dataclass
+class ChargePayment(Command, context="payments"):
+    order_id: str
+    amount_cents: int
+
+
+@dataclass
+class PaymentCharged(DomainEvent):
+    order_id: str
+
+
+@dataclass
+class PaymentDeclined(DomainEvent):
+    order_id: str
+    reason: str
+
+
+async def charge_payment(cmd: ChargePayment, event_bus: EventBus) -> None:
+    if cmd.amount_cents > 100_00:
+        await event_bus.publish(PaymentDeclined(order_id=cmd.order_id, reason="limit exceeded"))
+    else:
+        await event_bus.publish(PaymentCharged(order_id=cmd.order_id))
+
+
+inventory_module = (
+    DomainModule("inventory")
+    .command(ReserveStock, reserve_stock)
+    .command(ReleaseStock, release_stock)
+)
+payments_module = DomainModule("payments").command(ChargePayment, charge_payment)
+
+
+# --- the saga ---
+
+@dataclass
+class FulfillmentState:
+    total_cents: int = 0
+    steps: list[str] = field(default_factory=list)
+
+
+class OrderFulfillment(Saga[FulfillmentState]):
+    events = (OrderCreated, StockReservedForOrder, PaymentCharged, PaymentDeclined)
+    starts = (OrderCreated,)
+
+    def initial_state(self) -> FulfillmentState:
+        return FulfillmentState()
+
+    def correlation_id(self, event) -> str:
+        return event.order_id
+
+    def on_event(self, state: FulfillmentState, event):
+        state.steps.append(type(event).__name__)
+        if isinstance(event, OrderCreated):
+            state.total_cents = event.total_cents
+            return [SendCommand(ReserveStock(event.order_id, 1), compensation=ReleaseStock(event.order_id))]
+        if isinstance(event, StockReservedForOrder):
+            return [SendCommand(ChargePayment(event.order_id, state.total_cents))]
+        if isinstance(event, PaymentCharged):
+            return [Complete()]
+        return [Abort(f"payment declined: {event.reason}")]
+
+
+app = Application()
+app.container.register_instance(Stock, Stock())
+app.register(orders_module)
+app.register(inventory_module)
+app.register(payments_module)
+app.register(SagaModule().saga(OrderFulfillment))
+app.openapi(title="Fulfillment", version="0.1.0")
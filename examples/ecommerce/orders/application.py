@@ -16,6 +16,13 @@ class CreateOrder(Command):
     total_cents: int
 
 
+@dataclass
+class OrderPlaced:
+    """Typed result of CreateOrder (serialized by the framework, documented in OpenAPI)."""
+    order_id: str
+    total_cents: int
+
+
 @dataclass
 class GetOrder(Query):
     order_id: str
@@ -35,11 +42,11 @@ class CreateOrderHandler:
         self._repo = order_repository
         self._event_bus = event_bus
 
-    async def __call__(self, cmd: CreateOrder) -> str:
+    async def __call__(self, cmd: CreateOrder) -> OrderPlaced:
         order = Order(id=cmd.order_id, customer_id=cmd.customer_id, total_cents=cmd.total_cents)
         await self._repo.add(order)
         await self._event_bus.publish(OrderCreated(order_id=order.id, customer_id=order.customer_id, total_cents=order.total_cents))
-        return order.id
+        return OrderPlaced(order_id=order.id, total_cents=order.total_cents)
 
 
 class GetOrderHandler:
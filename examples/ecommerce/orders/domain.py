@@ -1,6 +1,6 @@
 """Orders domain: aggregate and events."""
 from dataclasses import dataclass
-from urich.domain import DomainEvent
+from urich.domain import AppError, DomainEvent
 
 
 @dataclass
@@ -32,5 +32,7 @@ class Inventory:
 
     def reserve(self, quantity: int, order_id: str) -> None:
         if quantity > self.quantity:
-            raise ValueError("Insufficient stock")
+            raise AppError.conflict(
+                "INSUFFICIENT_STOCK", f"Only {self.quantity} of {self.sku} left", details={"available": self.quantity}
+            )
         self.quantity -= quantity
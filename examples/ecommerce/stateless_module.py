@@ -1,19 +1,25 @@
 """
 Stateless bounded context: no aggregate, no repository.
-Shows DomainModule with only commands and queries (e.g. calculator, validator).
+Shows DomainModule with only commands and queries (e.g. calculator, validator),
+built from the types' context= option.
 """
 from dataclasses import dataclass
 from urich.ddd import DomainModule, Command, Query
 
 
 @dataclass
-class CalculateCommission(Command):
+class CalculateCommission(
+    Command,
+    name="calculate",
+    description="Commission in cents for an amount and a percentage rate.",
+    context="commission",
+):
     amount_cents: int
     rate_percent: float
 
 
 @dataclass
-class ValidateAmount(Query):
+class ValidateAmount(Query, context="commission", description="Whether an amount is acceptable."):
     amount_cents: int
 
 
@@ -25,8 +31,8 @@ def validate_amount_handler(query: ValidateAmount) -> dict:
     return {"valid": query.amount_cents >= 0, "amount_cents": query.amount_cents}
 
 
-commission_module = (
-    DomainModule("commission")
-    .command(CalculateCommission, calculate_commission_handler)
-    .query(ValidateAmount, validate_amount_handler)
-)
+# POST /commission/commands/calculate, GET|POST /commission/queries/validate_amount
+commission_module = DomainModule.from_types({
+    CalculateCommission: calculate_commission_handler,
+    ValidateAmount: validate_amount_handler,
+})
@@ -2,7 +2,7 @@
 Urich — async DDD framework for microservices.
 Application is composed from module objects via app.register(module).
 """
-from urich.core import Application, Container, Module, HttpModule, Config
+from urich.core import Application, Container, Module, HttpModule, Config, MergeError, HttpError
 
 __all__ = [
     "Application",
@@ -10,4 +10,6 @@ __all__ = [
     "Module",
     "HttpModule",
     "Config",
+    "MergeError",
+    "HttpError",
 ]
@@ -1,7 +1,10 @@
 """
-CLI for prototyping: create-app, add-context, add-aggregate.
+CLI for prototyping: new, create-app, add-context, add-aggregate; schema-diff and test-examples for contract checks.
 Generated code composes a DomainModule and registers via app.register(module).
 """
+import json
+import re
+from importlib.metadata import PackageNotFoundError, version
 from pathlib import Path
 
 try:
@@ -24,6 +27,7 @@ def _snake(name: str) -> str:
 
 
 _CONFIG_PY = '"""Config (env/file)."""\n# from dataclasses import dataclass\n# settings = ...\n'
+_DIFF_MODES = ("both", "readers", "writers")  # schema-diff --mode values
 
 
 @app.command()
@@ -64,17 +68,15 @@ _CONTEXT_FILES = [
 ]
 
 
-@app.command()
-def add_context(
-    name: str = typer.Argument(..., help="Bounded context name (e.g. orders)"),
-    directory: Path = typer.Option(Path("."), "--dir", "-d", help="App root directory"),
-    force: bool = typer.Option(False, "--force", "-f", help="Overwrite existing context files"),
-) -> None:
-    """Add bounded context: folder with domain, application, infrastructure, module (skeleton)."""
-    _ensure_typer()
-    ctx_dir = directory / name
-    ctx_dir.mkdir(parents=True, exist_ok=True)
+def _source_root(directory: Path) -> Path:
+    """Where contexts and main.py live: <dir>/src for projects made by `urich new`, else <dir>."""
+    src = directory / "src"
+    return src if (src / "main.py").exists() else directory
+
 
+def _write_context(ctx_dir: Path, name: str, force: bool) -> list[str]:
+    """Context skeleton files; returns the names of existing files left alone."""
+    ctx_dir.mkdir(parents=True, exist_ok=True)
     skipped = []
     for filename, template in _CONTEXT_FILES:
         path = ctx_dir / filename
@@ -82,7 +84,18 @@ def add_context(
             path.write_text(template.format(context=name), encoding="utf-8")
         else:
             skipped.append(filename)
+    return skipped
+
 
+@app.command()
+def add_context(
+    name: str = typer.Argument(..., help="Bounded context name (e.g. orders)"),
+    directory: Path = typer.Option(Path("."), "--dir", "-d", help="App root directory"),
+    force: bool = typer.Option(False, "--force", "-f", help="Overwrite existing context files"),
+) -> None:
+    """Add bounded context: folder with domain, application, infrastructure, module (skeleton)."""
+    _ensure_typer()
+    skipped = _write_context(_source_root(directory) / name, name, force)
     if skipped:
         typer.echo(f"Context «{name}» already exists (some files skipped). Use --force to overwrite.")
     typer.echo(f"Add aggregate: urich add-aggregate {name} <AggregateName> --dir {directory}")
@@ -111,10 +124,19 @@ def add_aggregate(
 ) -> None:
     """Add aggregate to context: domain, application, infrastructure, module (DomainModule with command/query)."""
     _ensure_typer()
-    ctx_dir = directory / context
+    root = _source_root(directory)
+    ctx_dir = root / context
     if not ctx_dir.is_dir():
         typer.echo(f"Context folder not found: {ctx_dir}. Run first: urich add-context {context} --dir {directory}", err=True)
         raise typer.Exit(1)
+    _write_aggregate(ctx_dir, context, aggregate)
+    if _wire_context(root / "main.py", context):
+        typer.echo(f"Aggregate «{aggregate}» in «{context}»: {ctx_dir}/. Registered {context}_module in {root / 'main.py'}")
+    else:
+        typer.echo(f"Aggregate «{aggregate}» in «{context}»: {ctx_dir}/. In main.py: from {context}.module import {context}_module; app.register({context}_module)")
+
+
+def _write_aggregate(ctx_dir: Path, context: str, aggregate: str) -> None:
     agg_lower = _snake(aggregate)
     fmt = {"context": context, "aggregate": aggregate, "aggregate_lower": agg_lower}
 
@@ -148,7 +170,150 @@ def add_aggregate(
             existing = path.read_text(encoding="utf-8") if path.exists() else ""
             path.write_text(existing + template.format(**fmt), encoding="utf-8")
 
-    typer.echo(f"Aggregate «{aggregate}» in «{context}»: {ctx_dir}/. In main.py: from {context}.module import {context}_module; app.register({context}_module)")
+
+def _wire_context(main_py: Path, context: str) -> bool:
+    """Add the context's import and app.register(...) to main.py (once). False when main.py is
+    missing or has no `app = Application(...)` line to register after."""
+    if not main_py.exists():
+        return False
+    lines = main_py.read_text(encoding="utf-8").splitlines()
+    import_line = f"from {context}.module import {context}_module"
+    register_line = f"app.register({context}_module)"
+    if register_line in (line.strip() for line in lines):
+        return True
+    lines = [line for line in lines if line not in (f"# {import_line}", f"# {register_line}")]  # create-app hints
+    anchor = None
+    for i, line in enumerate(lines):
+        if re.match(r"app\s*=\s*Application\(", line) or line.startswith("app.register("):
+            anchor = i
+    if anchor is None:
+        return False
+    lines.insert(anchor + 1, register_line)
+    if import_line not in lines:
+        last_import = max((i for i, line in enumerate(lines) if line.startswith(("import ", "from "))), default=-1)
+        lines.insert(last_import + 1, import_line)
+    main_py.write_text("\n".join(lines) + "\n", encoding="utf-8")
+    return True
+
+
+def _urich_requirement() -> str:
+    try:
+        return f"urich>={version('urich')}"
+    except PackageNotFoundError:
+        return "urich"
+
+
+@app.command()
+def new(
+    name: str = typer.Argument(..., help="Project name (folder and package name)"),
+    directory: Path = typer.Option(Path("."), "--dir", "-d", help="Parent directory"),
+    context: str = typer.Option("orders", "--context", "-c", help="Example bounded context"),
+    aggregate: str = typer.Option("Order", "--aggregate", "-a", help="Example aggregate (PascalCase)"),
+    force: bool = typer.Option(False, "--force", "-f", help="Write into an existing non-empty folder"),
+) -> None:
+    """Create a project: pyproject.toml, .gitignore, src/main.py, src/config.py and an example context."""
+    _ensure_typer()
+    root = directory / name
+    if root.exists() and any(root.iterdir()) and not force:
+        typer.echo(f"{root} exists and is not empty. Use --force to write into it.", err=True)
+        raise typer.Exit(1)
+    src = root / "src"
+    src.mkdir(parents=True, exist_ok=True)
+    files = [
+        (root / "pyproject.toml", T.PYPROJECT_TOML.format(name=name, urich=_urich_requirement())),
+        (root / ".gitignore", T.GITIGNORE),
+        (src / "main.py", T.PROJECT_MAIN_PY.format(name=name)),
+        (src / "config.py", _CONFIG_PY),
+    ]
+    for path, content in files:
+        path.write_text(content, encoding="utf-8")
+    _write_context(src / context, context, force=True)
+    _write_aggregate(src / context, context, aggregate)
+    _wire_context(src / "main.py", context)
+    typer.echo(
+        f"Created: {root}/ (pyproject.toml, src/main.py, src/{context}/). "
+        f"Run: cd {root} && pip install urich uvicorn && uvicorn main:app --app-dir src --reload"
+    )
+
+
+@app.command()
+def schema_diff(
+    old: Path = typer.Argument(..., help="Old JSON Schema"),
+    new: Path = typer.Argument(..., help="New JSON Schema"),
+    mode: str = typer.Option("both", "--mode", "-m", help="Fail on changes breaking: both | readers | writers"),
+) -> None:
+    """Compare two JSON Schemas and print a compatibility report. Exit 1 on breaking changes."""
+    _ensure_typer()
+    from urich.core.schema_compat import schema_compat
+
+    if mode not in _DIFF_MODES:
+        typer.echo(f"Unknown --mode {mode!r}: use one of {' | '.join(_DIFF_MODES)}", err=True)
+        raise typer.Exit(2)
+    old_doc = json.loads(old.read_text(encoding="utf-8"))
+    new_doc = json.loads(new.read_text(encoding="utf-8"))
+    report = schema_compat(
+        old_doc,
+        new_doc,
+        old_components=old_doc.get("components", {}).get("schemas", {}),
+        new_components=new_doc.get("components", {}).get("schemas", {}),
+    )
+    for change in report.changes:
+        typer.echo(f"[{change.level}] {change.path}: {change.message}")
+    typer.echo(f"Result: {report.level}")
+
+    if mode == "readers":
+        failed = not report.compatible_for_readers
+    elif mode == "writers":
+        failed = not report.compatible_for_writers
+    else:
+        failed = report.level != "compatible"
+    if failed:
+        raise typer.Exit(1)
+
+
+def _load_app(target: str):
+    """Application named by "module:attribute" (e.g. main:app), imported with the current directory on sys.path."""
+    import importlib
+    import sys
+
+    module_name, _, attribute = target.partition(":")
+    if not module_name or not attribute:
+        typer.echo(f"Expected module:attribute (e.g. main:app), got {target!r}", err=True)
+        raise typer.Exit(2)
+    sys.path.insert(0, str(Path.cwd()))
+    try:
+        return getattr(importlib.import_module(module_name), attribute)
+    except (ImportError, AttributeError) as e:
+        typer.echo(f"Cannot load {target}: {e}", err=True)
+        raise typer.Exit(2)
+
+
+@app.command()
+def test_examples(
+    target: str = typer.Argument(..., help="Application as module:attribute, e.g. main:app"),
+    bypass_middleware: bool = typer.Option(False, "--bypass-middleware", help="Skip middlewares (e.g. AuthModule)"),
+    validate_responses: bool = typer.Option(
+        True, "--validate-responses/--no-validate-responses", help="Check bodies against response schemas"
+    ),
+) -> None:
+    """Run the request examples registered on routes in-process and print a report. Exit 1 on failures."""
+    _ensure_typer()
+    import asyncio
+
+    from urich.testing import TestClient
+
+    application = _load_app(target)
+
+    async def run():
+        async with TestClient(application):
+            return await application.run_example_tests(
+                bypass_middleware=bypass_middleware, validate_responses=validate_responses
+            )
+
+    report = asyncio.run(run())
+    typer.echo(str(report))
+    if not report.all_passed():
+        raise typer.Exit(1)
 
 
 def main() -> None:
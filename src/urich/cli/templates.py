@@ -2,7 +2,7 @@
 
 DOMAIN_PY = '''"""Domain {context}: aggregate and events."""
 from dataclasses import dataclass
-from urich.domain import DomainEvent
+from urich.domain import AggregateRoot, DomainEvent
 
 
 @dataclass
@@ -12,10 +12,12 @@ class {aggregate}Created(DomainEvent):
     # add fields
 
 
-@dataclass
-class {aggregate}:
-    id: str
-    # add fields
+class {aggregate}(AggregateRoot):
+    """Aggregate root; type name "{aggregate_lower}" (class {aggregate}(AggregateRoot, name=...) to change it)."""
+
+    def __init__(self, id: str) -> None:
+        super().__init__(id=id)
+        # add fields
 '''
 
 APPLICATION_PY = '''"""Application layer: commands, queries, handlers."""
@@ -123,10 +125,12 @@ class {aggregate}Created(DomainEvent):
     # add fields
 
 
-@dataclass
-class {aggregate}:
-    id: str
-    # add fields
+class {aggregate}(AggregateRoot):
+    """Aggregate root; type name "{aggregate_lower}" (class {aggregate}(AggregateRoot, name=...) to change it)."""
+
+    def __init__(self, id: str) -> None:
+        super().__init__(id=id)
+        # add fields
 '''
 
 APPLICATION_PY_APPEND = '''
@@ -218,6 +222,34 @@ app = Application()
 # Run: uvicorn main:app --reload
 '''
 
+PROJECT_MAIN_PY = '''"""Entry point of {name}: app is composed from modules."""
+from urich import Application
+
+app = Application()
+app.openapi(title="{name}", version="0.1.0")
+
+# Run: uvicorn main:app --app-dir src --reload
+'''
+
+PYPROJECT_TOML = '''[project]
+name = "{name}"
+version = "0.1.0"
+requires-python = ">=3.12"
+dependencies = [
+    "{urich}",
+    "uvicorn>=0.30",
+]
+'''
+
+GITIGNORE = '''__pycache__/
+*.py[cod]
+.venv/
+.env
+*.egg-info/
+dist/
+build/
+'''
+
 CONTEXT_SKELETON = '''"""Domain {context}."""
 from urich.domain import DomainEvent
 
@@ -2,12 +2,37 @@ from urich.core.app import Application
 from urich.core.container import Container
 from urich.core.module import Module
 from urich.core.routing import HttpModule
+from urich.core.groups import RouteGroup
 from urich.core.config import Config
+from urich.core.builder import AppBuilder, ConfigError, ConfiguredServer, DocsConfig, ServerConfig, ServerHandle
+from urich.core.merge import MergeError
+from urich.core.errors import HttpError
+from urich.core.examples import Example, ExampleResult, ExampleTestReport
+from urich.core.multipart import UploadedFile
+from urich.core.validation import SchemaValidationError, ValidationIssue
+from urich.core.context import current_request, request_state
 
 __all__ = [
     "Application",
     "Container",
     "Module",
     "HttpModule",
+    "RouteGroup",
     "Config",
+    "AppBuilder",
+    "ConfigError",
+    "ConfiguredServer",
+    "DocsConfig",
+    "ServerConfig",
+    "ServerHandle",
+    "MergeError",
+    "HttpError",
+    "Example",
+    "ExampleResult",
+    "ExampleTestReport",
+    "UploadedFile",
+    "SchemaValidationError",
+    "ValidationIssue",
+    "current_request",
+    "request_state",
 ]
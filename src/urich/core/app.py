@@ -1,13 +1,64 @@
 """Application — Starlette wrapper; app is composed from modules via app.register(module)."""
 from __future__ import annotations
 
-from typing import Any
+import re
+from urllib.parse import urlencode
+from typing import TYPE_CHECKING, Any, Sequence
 
 from starlette.applications import Starlette
-from starlette.routing import Route
+from starlette.routing import Match, Route
 
+from urich.core.body_limit import DEFAULT_MAX_BODY_SIZE, BodyLimitMiddleware
 from urich.core.container import Container
+from urich.core.errors import error_response, install_error_handlers
+from urich.core.idempotency import (
+    DEFAULT_IDEMPOTENCY_TTL,
+    DEFAULT_LOCK_TIMEOUT,
+    DEFAULT_MAX_RESPONSE_SIZE,
+    IdempotencyMiddleware,
+    IdempotencySettings,
+    IdempotencyStore,
+    InMemoryIdempotencyStore,
+)
 from urich.core.module import Module
+from urich.core.openapi import resolve_refs, response_object, schema_ref, spec_etag
+from urich.core.schema_hash import canonical_json, route_schema_hash
+from urich.core.timeout import DEFAULT_REQUEST_TIMEOUT, RequestTimeoutMiddleware
+from urich.core.urls import under_prefix
+from urich.core.validation import RouteValidation, ValidationObserver, ValidationSettings
+
+if TYPE_CHECKING:
+    from urich.core.builder import AppBuilder, ServerConfig
+    from urich.core.examples import Example, ExampleTestReport
+    from urich.core.groups import RouteGroup
+    from urich.core.versioning import ApiVersion
+
+APP_LIMIT: Any = object()  # max_body_size= / timeout= default of add_route and DomainModule: use the app setting
+
+
+def _operation_id(method: str, path: str, explicit: str | None, *, several: bool) -> str:
+    """Explicit id (suffixed with the method when shared by several methods) or method + path in snake case."""
+    if explicit is not None:
+        return f"{explicit}_{method.lower()}" if several else explicit
+    return re.sub(r"[^a-zA-Z0-9]+", "_", f"{method} {path}").strip("_").lower()
+
+
+def _route_rank(route: Route) -> int:
+    """Match priority: 0 exact path, 1 with {param} segments, 2 catch-all {name:path}."""
+    if not route.param_convertors:
+        return 0
+    return 2 if _wildcard_prefix(route.path) is not None else 1
+
+
+def _wildcard_prefix(path: str) -> str | None:
+    """Prefix before a trailing {name:path} segment, or None if the route has no catch-all."""
+    m = re.search(r"\{[a-zA-Z_][a-zA-Z0-9_]*:path\}$", path)
+    return path[: m.start()] if m else None
+
+
+def _expand_wildcard(path: str) -> str:
+    """Trailing "*rest" segment -> Starlette "{rest:path}" (e.g. /assets/*rest)."""
+    return re.sub(r"/\*([a-zA-Z_][a-zA-Z0-9_]*)$", r"/{\1:path}", path)
 
 
 class Application:
@@ -16,14 +67,76 @@ class Application:
     Each module is an object with register_into(app).
     """
 
-    def __init__(self, config: Any = None) -> None:
+    def __init__(
+        self,
+        config: Any = None,
+        *,
+        max_body_size: int | None = DEFAULT_MAX_BODY_SIZE,
+        request_timeout: float | None = DEFAULT_REQUEST_TIMEOUT,
+    ) -> None:
         self._starlette = Starlette(routes=[])
+        install_error_handlers(self._starlette)
+        self._idempotency = IdempotencySettings()
+        self._idempotent: set[tuple[str, str]] = set()  # (path, METHOD) of routes honouring Idempotency-Key
+        self._starlette.add_middleware(IdempotencyMiddleware, application=self)  # innermost: body already limited
+        self._max_body_size = max_body_size  # bytes; None = unlimited
+        self._body_limits: dict[tuple[str, str], int | None] = {}  # (path, METHOD) -> per-route override
+        self._starlette.add_middleware(BodyLimitMiddleware, application=self)
+        self._request_timeout = request_timeout  # seconds until the response starts; None = no timeout
+        self._timeouts: dict[tuple[str, str], float | None] = {}  # (path, METHOD) -> per-route override
+        self._starlette.add_middleware(RequestTimeoutMiddleware, application=self)
+        self._validation = ValidationSettings()
+        self._route_validation: dict[tuple[str, str], RouteValidation] = {}  # (path, METHOD) -> payload validation
+        self._examples: dict[tuple[str, str], list[Example]] = {}  # (path, METHOD) -> request examples
         self._modules: list[Module] = []
         self._container = Container()
         self._route_schemas: dict[tuple[str, str], dict[str, Any]] = {}  # (path, method) -> OpenAPI op extras
+        self._schema_hashes: dict[str, str] = {}  # "METHOD path" -> stable schema hash
+        self._route_names: dict[str, tuple[str, list[str]]] = {}  # name -> (path, methods)
+        self._routes_version = 0  # bumped on every route table change (caches key on it)
+        self._operation_ids: dict[str, str] = {}  # OpenAPI operationId -> "METHOD path"
+        self._schemas: dict[str, dict[str, Any]] = {}  # OpenAPI components.schemas
+        self._security_schemes: dict[str, dict[str, Any]] = {}  # OpenAPI components.securitySchemes
+        self._default_security: list[dict[str, list[str]]] | None = None  # requirement of every operation
+        self._public_prefixes: list[str] = []  # paths needing no credentials (spec and AuthModule)
+        self._public_routes: set[tuple[str, str]] = set()  # (path, METHOD) registered with public=True
+        self._middleware_chain: Any = None  # MiddlewareChain, installed on first add_middleware
+        self._middleware_exempt: set[str] = set()  # paths add_middleware middlewares skip
+        self._rpc_methods: dict[str, list[str]] = {}  # RPC route path -> method names (for route_table)
+        self._command_handlers: dict[type, Any] = {}  # command type -> async fn(command) (for dispatch)
+        self._versions: dict[str, ApiVersion] = {}  # API version name -> route group
+        self._version_header: bytes | None = None  # negotiation header (lower-case), None = off
+        self._default_version: str | None = None
+        self._openapi_spec: dict[str, Any] | None = None  # built by openapi()
+        self._openapi_cache: dict[str | None, tuple[bytes, str]] = {}  # version -> (serialized spec, ETag)
+        self._published_events: dict[str, dict[str, Any]] = {}  # event wire name -> payload schema (AsyncAPI)
         if config is not None:
             self._container.register_instance(type(config), config)
             self._container.register_instance("config", config)
+        from urich.events.topic_hub import TopicHub
+
+        self._container.register_instance(TopicHub, TopicHub())
+
+    @staticmethod
+    def builder(settings: ServerConfig | dict[str, Any] | None = None) -> AppBuilder:
+        """Fluent setup with server settings checked at build():
+        Application.builder().port(8080).openapi("Orders", "1.0").register(orders).build().
+        settings: a ServerConfig or a dict for ServerConfig.from_dict."""
+        from urich.core.builder import AppBuilder
+
+        return AppBuilder(settings)
+
+    def run(self, host: str = "127.0.0.1", port: int = 8000, **server_options: Any) -> None:
+        """Blocking: serve this app with uvicorn (pip install urich[server]). Shim over ConfiguredServer;
+        server_options go to uvicorn.Config. Invalid host/port raise ConfigError."""
+        from urich.core.builder import ConfigError, ConfiguredServer, ServerConfig
+
+        log_level = server_options.pop("log_level", "info")
+        settings = ServerConfig(host=host, port=port, log_level=log_level, server_options=server_options)
+        problems = settings.validate()
+        if problems:
+            raise ConfigError(problems)
+        ConfiguredServer(self, settings).run()
 
     def register(self, module: Module) -> Application:
         """Register a module (DomainModule, EventBusModule, routes, etc.). Returns self for chaining."""
@@ -31,6 +144,60 @@ class Application:
         self._modules.append(module)
         return self
 
+    async def dispatch(self, command: Any) -> Any:
+        """Run the handler a DomainModule registered for type(command) in-process (no HTTP) and return
+        its result as the handler returned it. LookupError if no module handles that command type."""
+        handler = self._command_handlers.get(type(command))
+        if handler is None:
+            raise LookupError(f"No handler registered for command {type(command).__qualname__}")
+        return await handler(command)
+
+    def group(self, prefix: str, *, tags: list[str] | None = None) -> RouteGroup:
+        """Routes and modules under prefix, with default OpenAPI tags and group-only middlewares:
+        app.group("/api/v1").register(orders). See RouteGroup."""
+        from urich.core.groups import RouteGroup
+
+        return RouteGroup(self, prefix, tags=tags)
+
+    def version(self, name: str) -> ApiVersion:
+        """Route group for API version name ("v1"): path prefix /v1, OpenAPI tag, own route and schema
+        names; the same call returns the same version. .deprecate(...) adds deprecation headers."""
+        from urich.core.versioning import ApiVersion, ApiVersionMiddleware
+
+        if name not in self._versions:
+            if not self._versions:
+                self._starlette.add_middleware(ApiVersionMiddleware, application=self)
+            self._versions[name] = ApiVersion(self, name)
+        return self._versions[name]
+
+    def version_negotiation(self, header: str = "Accept-Version", *, default: str | None = None) -> Application:
+        """Serve unprefixed paths (/orders/...) from the version named in header, or default when the
+        header is missing; paths that match a route as they are stay unversioned. Returns self."""
+        self._version_header = header.lower().encode("latin-1")
+        self._default_version = default
+        return self
+
+    def idempotency(
+        self,
+        store: IdempotencyStore | None = None,
+        *,
+        header: str = "Idempotency-Key",
+        ttl: float = DEFAULT_IDEMPOTENCY_TTL,
+        max_response_size: int = DEFAULT_MAX_RESPONSE_SIZE,
+        lock_timeout: float = DEFAULT_LOCK_TIMEOUT,
+    ) -> Application:
+        """Configure idempotent routes: store (default in-memory), header name, ttl (seconds a response
+        is replayed), max_response_size (larger bodies are not stored) and lock_timeout (seconds a
+        pending claim lives if the process dies mid-request). Returns self."""
+        self._idempotency = IdempotencySettings(
+            store=store if store is not None else InMemoryIdempotencyStore(),
+            header=header.lower().encode("latin-1"),
+            ttl=ttl,
+            max_response_size=max_response_size,
+            lock_timeout=lock_timeout,
+        )
+        return self
+
     def add_route(
         self,
         path: str,
@@ -38,15 +205,96 @@ class Application:
         methods: list[str] | None = None,
         *,
         openapi_body_schema: dict[str, Any] | None = None,
+        openapi_body_media_type: str = "application/json",
         openapi_parameters: list[dict[str, Any]] | None = None,
         openapi_tags: list[str] | None = None,
         openapi_security: list[dict[str, Any]] | None = None,
+        openapi_response_schema: dict[str, Any] | None = None,
+        openapi_response_status: int = 200,
+        openapi_response_media_type: str = "application/json",
+        openapi_operation_id: str | None = None,
+        openapi_summary: str | None = None,
+        openapi_description: str | None = None,
+        name: str | None = None,
+        max_body_size: Any = APP_LIMIT,
+        timeout: Any = APP_LIMIT,
+        idempotent: bool = False,
+        public: bool = False,
+        validation: RouteValidation | None = None,
+        examples: Sequence[Example] | None = None,
+        replace: bool = False,
     ) -> None:
-        """Add an HTTP route. Optional openapi_* for Swagger (schemas, parameters, tags, security)."""
+        """Add an HTTP route. Optional openapi_* for Swagger (schemas, parameters, tags, security,
+        request body media type (openapi_body_media_type, e.g. "multipart/form-data"),
+        response schema under openapi_response_status as openapi_response_media_type; without it the
+        spec shows a generic 200, with status 204 a response without body;
+        operationId defaults to method + path, e.g. post_orders_commands_create_order, and must be unique).
+        name: stable name for url_for(); must be unique. A trailing "*rest" segment is a catch-all
+        (same as "{rest:path}"), matched after exact and {param} routes; one per prefix and method.
+        max_body_size: bytes allowed for this route (None = unlimited); default uses the app limit.
+        timeout: seconds the handler has to start its response (None = no timeout); default uses the
+        app's request_timeout.
+        idempotent: a retry carrying the same Idempotency-Key header gets the stored response instead
+        of running the handler again (see app.idempotency).
+        public: no credentials needed: empty security array in the spec (overriding a default scheme)
+        and AuthModule lets the route through.
+        validation: RouteValidation of an endpoint that validates its payload (DomainModule passes one):
+        it follows validation_sampling() and shows in route_table(); skipped routes are marked
+        x-skip-validation in the spec.
+        examples: request Examples, shown on the request body in the spec and sent by
+        run_example_tests() (with POST when the route has it, else its first method).
+        A (method, path) pair can be registered once: a second registration raises ValueError unless
+        replace=True, which removes those methods from the existing route first.
+        """
         if methods is None:
             methods = ["GET"]
-        route = Route(path, endpoint, methods=methods)
-        self._starlette.routes.append(route)
+        path = _expand_wildcard(path)
+        self._check_duplicate(path, methods, replace=replace)
+        prefix = _wildcard_prefix(path)
+        if prefix is not None:
+            wanted = {m.upper() for m in methods}
+            for existing in self._starlette.routes:
+                if (
+                    isinstance(existing, Route)
+                    and _wildcard_prefix(existing.path) == prefix
+                    and wanted & set(existing.methods or [])
+                ):
+                    raise ValueError(f"Catch-all route {existing.path} already registered for {prefix}")
+        if name is not None:
+            if name in self._route_names:
+                raise ValueError(f"Route name {name!r} already registered for {self._route_names[name][0]}")
+            self._route_names[name] = (path, list(methods))
+        operation_ids = {
+            method: _operation_id(method, path, openapi_operation_id, several=len(methods) > 1) for method in methods
+        }
+        for method, op_id in operation_ids.items():
+            owner = self._operation_ids.get(op_id)
+            if owner is not None and owner != f"{method.upper()} {path}":
+                raise ValueError(f"OpenAPI operationId {op_id!r} already used by {owner}")
+        route = Route(path, endpoint, methods=methods, name=name)
+        self._insert_route(route)
+        if max_body_size is not APP_LIMIT:
+            for method in methods:
+                self._body_limits[(path, method.upper())] = max_body_size
+        if timeout is not APP_LIMIT:
+            for method in methods:
+                self._timeouts[(path, method.upper())] = timeout
+        if idempotent:
+            for method in methods:
+                self._idempotent.add((path, method.upper()))
+        if public:
+            for method in methods:
+                self._public_routes.add((path, method.upper()))
+        example_method = "POST" if any(m.upper() == "POST" for m in methods) else methods[0].upper()
+        if examples:
+            self._examples[(path, example_method)] = list(examples)
+        if validation is not None:
+            validation.path = path
+            validation.settings = self._validation
+            for method in methods:
+                self._route_validation[(path, method.upper())] = validation
+            if openapi_security is None:
+                openapi_security = []
         for method in methods:
             key = (path, method.lower())
             if key not in self._route_schemas:
@@ -56,54 +304,528 @@ class Application:
             if method.lower() == "post" and openapi_body_schema is not None:
                 self._route_schemas[key]["requestBody"] = {
                     "required": True,
-                    "content": {"application/json": {"schema": openapi_body_schema}},
+                    "content": {openapi_body_media_type: {"schema": openapi_body_schema}},
                 }
             if openapi_tags is not None:
                 self._route_schemas[key]["tags"] = openapi_tags
             if openapi_security is not None:
                 self._route_schemas[key]["security"] = openapi_security
+            self._route_schemas[key]["operationId"] = operation_ids[method]
+            self._operation_ids[operation_ids[method]] = f"{method.upper()} {path}"
+            if openapi_summary is not None:
+                self._route_schemas[key]["summary"] = openapi_summary
+            if openapi_description is not None:
+                self._route_schemas[key]["description"] = openapi_description
+            if validation is not None and validation.skip:
+                self._route_schemas[key]["x-skip-validation"] = True
+            documented = {e.name: e.openapi() for e in examples or () if e.body is not None}
+            if documented and method.upper() == example_method:
+                self._route_schemas[key]["examples"] = documented
+            if openapi_response_schema is not None or openapi_response_status == 204:
+                self._route_schemas[key]["responses"] = response_object(
+                    openapi_response_status, openapi_response_schema, openapi_response_media_type
+                )
+            self._schema_hashes[f"{method.upper()} {path}"] = route_schema_hash(
+                method, path, resolve_refs(self._route_schemas[key], self._schemas)
+            )
+
+    def register_schema(self, name: str, schema: dict[str, Any]) -> dict[str, Any]:
+        """Add schema to OpenAPI components.schemas and return {"$ref": "#/components/schemas/<name>"}
+        for openapi_body_schema / openapi_response_schema or other schemas. Registering the same name
+        again with the same content is a no-op; different content raises ValueError.
+        DomainModule registers command, query and result types under their class names.
+        """
+        existing = self._schemas.get(name)
+        if existing is not None and canonical_json(existing) != canonical_json(schema):
+            raise ValueError(f"OpenAPI schema {name!r} already registered with different content")
+        self._schemas[name] = schema
+        return schema_ref(name)
+
+    def validation_sampling(self, percent: float) -> Application:
+        """Validate the payload of percent of the requests on each DomainModule route (100 = all, the
+        default). Chosen per route by a counter, not at random; a sampled request that fails
+        validation is still rejected, the others go to the handler unchecked. Returns self."""
+        if not 0 <= percent <= 100:
+            raise ValueError(f"Validation sampling must be between 0 and 100 percent, got {percent}")
+        self._validation.sample_rate = percent / 100
+        return self
+
+    def on_validation(self, observer: ValidationObserver) -> Application:
+        """Call observer(method, path, seconds) after each payload validation (MetricsModule uses
+        it for the validation duration histogram). Returns self."""
+        self._validation.observers.append(observer)
+        return self
+
+    def validation_stats(self) -> dict[str, dict[str, Any]]:
+        """"METHOD path" -> {"mode": "on" / "sampled" / "skipped", "validated": requests validated,
+        "seconds": total validation time} for routes that validate their payload. Methods of one
+        route (a query's GET and POST) share the counts."""
+        return {
+            f"{method} {path}": {"mode": v.mode, "validated": v.validated, "seconds": v.seconds}
+            for (path, method), v in sorted(self._route_validation.items())
+        }
+
+    async def run_example_tests(
+        self, *, bypass_middleware: bool = False, validate_responses: bool = True
+    ) -> ExampleTestReport:
+        """Send every route example (add_route / DomainModule examples=) through the app in-process
+        and check its status (2xx unless Example.expect_status) and, with validate_responses, the JSON
+        body against the documented response schema. bypass_middleware: straight to the routes, past
+        AuthModule and other middlewares (error handlers still apply). Lifespan hooks don't run: call
+        it inside `async with TestClient(app)` if handlers need them.
+        """
+        from starlette.middleware.exceptions import ExceptionMiddleware
+
+        from urich.core.examples import run_examples
+
+        target: Any = self
+        if bypass_middleware:
+            target = ExceptionMiddleware(self._starlette.router, handlers=self._starlette.exception_handlers)
+        cases = [
+            (method, path, examples, self._route_schemas.get((path, method.lower()), {}).get("responses", {}))
+            for (path, method), examples in sorted(self._examples.items())
+        ]
+        return await run_examples(target, cases, self._schemas, validate_responses=validate_responses)
+
+    def add_security_scheme(self, name: str, scheme: dict[str, Any], *, default: bool = False) -> Application:
+        """Add an OpenAPI security scheme (components.securitySchemes), e.g.
+        add_security_scheme("bearerAuth", {"type": "http", "scheme": "bearer", "bearerFormat": "JWT"}).
+        default=True requires it on every operation not marked public or given its own security.
+        The same name with different content raises ValueError. Spec metadata only: enforcement is
+        the auth middleware's (AuthModule adds its scheme as default). Returns self.
+        """
+        existing = self._security_schemes.get(name)
+        if existing is not None and canonical_json(existing) != canonical_json(scheme):
+            raise ValueError(f"Security scheme {name!r} already registered with different content")
+        self._security_schemes[name] = scheme
+        if default:
+            self._default_security = [{name: []}]
+        return self
+
+    def exempt_from_middleware(self, *paths: str) -> Application:
+        """Exact paths that add_middleware middlewares and per-request modules (ConcurrencyLimitModule,
+        TenantModule) skip, e.g. health probes. Returns self."""
+        self._middleware_exempt.update(paths)
+        return self
+
+    def is_middleware_exempt(self, path: str) -> bool:
+        return path in self._middleware_exempt
+
+    def public_path(self, prefix: str) -> Application:
+        """Paths under prefix need no credentials: their operations get an empty security array in the
+        spec and AuthModule lets them through. AuthModule.exclude(prefix) calls this. Returns self."""
+        if prefix not in self._public_prefixes:
+            self._public_prefixes.append(prefix)
+        return self
+
+    def is_public(self, scope: dict[str, Any]) -> bool:
+        """True for requests under a public_path() prefix or to a route added with public=True."""
+        path = scope["path"]
+        if any(under_prefix(path, p) for p in self._public_prefixes):
+            return True
+        if self._public_routes:
+            route = self._route_for(scope)
+            return route is not None and (route.path, scope["method"]) in self._public_routes
+        return False
+
+    def _check_duplicate(self, path: str, methods: list[str], *, replace: bool) -> None:
+        wanted = {m.upper() for m in methods}
+        for existing in list(self._starlette.routes):
+            if not isinstance(existing, Route) or existing.path != path:
+                continue
+            taken = wanted & set(existing.methods or [])
+            if not taken:
+                continue
+            if not replace:
+                raise ValueError(f"Route {', '.join(sorted(taken))} {path} already registered")
+            remaining = set(existing.methods or []) - taken
+            if not remaining or remaining == {"HEAD"}:
+                self._starlette.routes.remove(existing)
+                if existing.name in self._route_names and self._route_names[existing.name][0] == path:
+                    del self._route_names[existing.name]
+            else:
+                existing.methods = remaining
+            for method in taken:
+                self._operation_ids.pop(self._route_schemas.get((path, method.lower()), {}).get("operationId"), None)
+                self._route_schemas.pop((path, method.lower()), None)
+                self._schema_hashes.pop(f"{method} {path}", None)
+                self._body_limits.pop((path, method), None)
+                self._timeouts.pop((path, method), None)
+                self._idempotent.discard((path, method))
+                self._public_routes.discard((path, method))
+                self._route_validation.pop((path, method), None)
+                self._examples.pop((path, method), None)
+
+    @property
+    def routes_version(self) -> int:
+        """Changes whenever routes are added, replaced, mounted or merged in; cache by it."""
+        return self._routes_version
+
+    def _routes_changed(self) -> None:
+        self._routes_version += 1
+
+    def _insert_route(self, route: Route) -> None:
+        """Keep exact paths ahead of parameterized ones so /orders/summary wins over /orders/{id}."""
+        self._routes_changed()
+        rank = _route_rank(route)
+        routes = self._starlette.routes
+        for i, existing in enumerate(routes):
+            if isinstance(existing, Route) and _route_rank(existing) > rank:
+                routes.insert(i, route)
+                return
+        routes.append(route)
+
+    def _body_limit_for(self, scope: dict[str, Any]) -> int | None:
+        """Body size limit for the route matching scope (per-route override, else app default)."""
+        if self._body_limits:
+            for route in self._starlette.routes:
+                if isinstance(route, Route) and route.matches(scope)[0] == Match.FULL:
+                    return self._body_limits.get((route.path, scope["method"]), self._max_body_size)
+        return self._max_body_size
+
+    def _timeout_for(self, scope: dict[str, Any]) -> float | None:
+        """Request timeout for the route matching scope (per-route override, else app default)."""
+        if self._timeouts:
+            route = self._route_for(scope)
+            if route is not None:
+                return self._timeouts.get((route.path, scope["method"]), self._request_timeout)
+        return self._request_timeout
+
+    def _route_for(self, scope: dict[str, Any]) -> Route | None:
+        for route in self._starlette.routes:
+            if isinstance(route, Route) and route.matches(scope)[0] == Match.FULL:
+                return route
+        return None
+
+    def url_for(self, name: str, params: dict[str, Any] | None = None, *, base_url: str | None = None) -> str:
+        """URL of a named route: path params substituted (percent-encoded); remaining params become
+        the query string for GET routes. Raises UrlError on unknown name, missing or extra params.
+        base_url (e.g. "https://api.example.com") makes the URL absolute.
+        """
+        from urich.core.urls import UrlError, build_url
+
+        if name not in self._route_names:
+            raise UrlError(f"No route named {name!r}")
+        path, methods = self._route_names[name]
+        query_allowed = any(m.upper() == "GET" for m in methods)
+        return build_url(path, params, query_allowed=query_allowed, base_url=base_url)
+
+    def merge(self, other: Application) -> Application:
+        """Host another independently built Application in this process. Returns self.
+        Call before openapi(). Other's routes keep their own middlewares; event subscriptions
+        and container entries are combined. Raises MergeError listing route/container collisions.
+        """
+        from urich.core.merge import merge_applications
+
+        return merge_applications(self, other)
 
     def mount(self, path: str, app: Starlette) -> None:
         """Mount a sub-app at prefix. Called by modules from register_into."""
         from starlette.routing import Mount
         self._starlette.routes.append(Mount(path, app=app))
+        self._routes_changed()
 
     def openapi(
         self,
         *,
         title: str = "API",
         version: str = "0.1.0",
-        docs_path: str = "/docs",
+        docs_path: str | None = "/docs",
         openapi_path: str = "/openapi.json",
         security_schemes: dict[str, Any] | None = None,
         global_security: list[dict[str, Any]] | None = None,
     ) -> Application:
         """Add OpenAPI spec and Swagger UI. Call after all modules are registered. Returns self.
-        security_schemes and global_security are passed through to the OpenAPI spec (components.securitySchemes, security).
+        security_schemes and global_security are passed through to the OpenAPI spec (components.securitySchemes, security),
+        on top of add_security_scheme() schemes and the default requirement.
+        Paths are ordinary routes (middlewares apply); docs_path=None serves the spec without Swagger UI.
+        Not calling openapi() at all leaves both paths to normal routing.
         """
-        from urich.core.openapi import build_openapi_spec, SWAGGER_UI_HTML
-        from starlette.responses import HTMLResponse, JSONResponse
+        from urich.core.openapi import build_openapi_spec, etag_matches, SWAGGER_UI_HTML
+        from starlette.responses import HTMLResponse, Response
 
         spec = build_openapi_spec(
             self._starlette.routes,
             title=title,
             version=version,
             route_schemas=self._route_schemas,
-            security_schemes=security_schemes,
-            global_security=global_security,
+            security_schemes={**self._security_schemes, **(security_schemes or {})},
+            global_security=global_security if global_security is not None else self._default_security,
+            public_prefixes=self._public_prefixes,
+            schemas=self._schemas,
         )
-        self._openapi_spec = spec  # type: ignore[attr-defined]
+        self._openapi_spec = spec
+        self._openapi_cache.clear()
 
         async def openapi_endpoint(request: Any) -> Any:
-            return JSONResponse(spec)
+            name = request.query_params.get("version")
+            if name is not None and name not in self._versions:
+                return error_response(404, f"Unknown API version {name!r}")
+            body, etag = self._openapi_serialized(name)
+            headers = {"ETag": etag, "Cache-Control": "no-cache"}
+            if etag_matches(request.headers.get("if-none-match"), etag):
+                return Response(status_code=304, headers=headers)
+            return Response(body, media_type="application/json", headers=headers)
 
         async def docs_endpoint(request: Any) -> Any:
-            return HTMLResponse(SWAGGER_UI_HTML.replace("/openapi.json", openapi_path))
+            name = request.query_params.get("version")
+            url = openapi_path + (f"?{urlencode({'version': name})}" if name else "")
+            return HTMLResponse(SWAGGER_UI_HTML.replace("/openapi.json", url))
 
         self.add_route(openapi_path, openapi_endpoint, methods=["GET"])
-        self.add_route(docs_path, docs_endpoint, methods=["GET"])
+        if docs_path is not None:
+            self.add_route(docs_path, docs_endpoint, methods=["GET"])
+        return self
+
+    def openapi_spec_cached(self, version: str | None = None) -> bytes:
+        """The served /openapi.json body (or one API version's spec): serialized once with sorted
+        keys, byte-identical across calls. LookupError before openapi() or for an unknown version."""
+        if version is not None and version not in self._versions:
+            raise LookupError(f"Unknown API version {version!r}")
+        return self._openapi_serialized(version)[0]
+
+    def _openapi_serialized(self, version: str | None) -> tuple[bytes, str]:
+        """(body, ETag) for the spec or a version's spec, computed on first use."""
+        if self._openapi_spec is None:
+            raise LookupError("openapi() has not been called")
+        if version not in self._openapi_cache:
+            spec = self._openapi_spec
+            if version is not None:
+                from urich.core.versioning import spec_for_version
+
+                spec = spec_for_version(spec, self._versions[version], list(self._versions))
+            body = canonical_json(spec).encode("utf-8")
+            self._openapi_cache[version] = (body, spec_etag(body))
+        return self._openapi_cache[version]
+
+    def declare_published_event(self, event: type | str, schema: dict[str, Any] | None = None) -> Application:
+        """Declare an event this service emits, for asyncapi_spec(): an event class (wire name and
+        dataclass schema derived) or a wire name with its payload schema. Returns self."""
+        from urich.core.asyncapi import event_schema
+        from urich.domain.events import event_name
+
+        if isinstance(event, str):
+            self._published_events[event] = schema or {"type": "object"}
+        else:
+            self._published_events[event_name(event)] = schema or event_schema(event)
         return self
 
+    def asyncapi_spec(self, title: str = "API", version: str = "0.1.0") -> dict[str, Any]:
+        """AsyncAPI 2.6 document: a channel per event subscribed on the EventBus or declared with
+        declare_published_event(), plus the RPC methods (x-rpc-methods)."""
+        from urich.core.asyncapi import build_asyncapi_spec
+        from urich.domain.events import EventBus
+
+        try:
+            subscriptions = dict(getattr(self._container.resolve(EventBus), "_handlers", {}))
+        except KeyError:
+            subscriptions = {}
+        return build_asyncapi_spec(
+            title=title,
+            version=version,
+            subscriptions=subscriptions,
+            published=self._published_events,
+            rpc_methods=self._rpc_methods,
+        )
+
+    def asyncapi(self, *, title: str = "API", version: str = "0.1.0", path: str = "/asyncapi.json") -> Application:
+        """Serve asyncapi_spec() at GET {path}, built and serialized on the first request, with an
+        ETag (304 on If-None-Match) like /openapi.json. Call after all modules are registered."""
+        from starlette.responses import Response
+
+        from urich.core.openapi import etag_matches
+
+        cached: list[tuple[bytes, str]] = []
+
+        async def asyncapi_endpoint(request: Any) -> Any:
+            if not cached:
+                body = canonical_json(self.asyncapi_spec(title, version)).encode("utf-8")
+                cached.append((body, spec_etag(body)))
+            body, etag = cached[0]
+            headers = {"ETag": etag, "Cache-Control": "no-cache"}
+            if etag_matches(request.headers.get("if-none-match"), etag):
+                return Response(status_code=304, headers=headers)
+            return Response(body, media_type="application/json", headers=headers)
+
+        self.add_route(path, asyncapi_endpoint, methods=["GET"])
+        return self
+
+    def error_reporter(
+        self,
+        reporter: Any = None,
+        *,
+        status_threshold: int = 500,
+        rate_limit: int = 10,
+        queue_size: int = 1000,
+    ) -> Application:
+        """Report unhandled exceptions and responses >= status_threshold to reporter
+        (ErrorReporter; default LoggingErrorReporter). Reporting runs in a background task with a
+        bounded queue; identical events are limited to rate_limit per minute. Returns self.
+        """
+        from urich.core.error_reporting import (
+            ErrorReporter,
+            ErrorReportingDispatcher,
+            ErrorReportingMiddleware,
+            LoggingErrorReporter,
+        )
+
+        dispatcher = ErrorReportingDispatcher(
+            reporter if reporter is not None else LoggingErrorReporter(),
+            queue_size=queue_size,
+            rate_limit=rate_limit,
+        )
+        self._container.register_instance(ErrorReporter, dispatcher.reporter)
+        self._container.register_instance(ErrorReportingDispatcher, dispatcher)
+        self._starlette.add_middleware(
+            ErrorReportingMiddleware, application=self, dispatcher=dispatcher, status_threshold=status_threshold
+        )
+        return self
+
+    def add_middleware(self, middleware: Any) -> Application:
+        """Wrap every request: async def mw(request, call_next) -> Response. call_next runs the rest
+        of the chain and the handler; mw may short-circuit, or change/replace the response.
+        First registered runs outermost (first in, last out). Returns self.
+        """
+        if self._middleware_chain is None:
+            from urich.core.middleware import install_chain
+
+            self._middleware_chain = install_chain(self._starlette, self._middleware_exempt)
+        self._middleware_chain.middlewares.append(middleware)
+        return self
+
+    def warm_up(self) -> Application:
+        """Create all container singletons on startup (container.warm_up()), so a broken
+        registration fails the startup instead of the first request. Returns self.
+        """
+        self._starlette.router.on_startup.append(self._container.warm_up)
+        return self
+
+    def route_schema_hashes(self) -> dict[str, str]:
+        """Stable per-route schema hashes: "METHOD path" -> hex digest (method, path, request/response schema)."""
+        return dict(self._schema_hashes)
+
+    def schema_hashes(self, *, endpoint: str | None = None, strict: bool = False) -> Application:
+        """Opt in to X-Schema-Hash response headers. Clients sending X-Expected-Schema-Hash that
+        differs get X-Schema-Drift: true (or 412 with strict=True). endpoint: also serve the
+        path -> hash map at this path (e.g. "/_schema_hashes"); hidden by default. Returns self.
+        """
+        from urich.core.schema_hash import SchemaHashMiddleware
+
+        if endpoint is not None:
+            from starlette.responses import JSONResponse
+
+            async def schema_hashes_endpoint(request: Any) -> Any:
+                return JSONResponse(self.route_schema_hashes())
+
+            self.add_route(endpoint, schema_hashes_endpoint, methods=["GET"])
+        self._starlette.add_middleware(SchemaHashMiddleware, application=self, strict=strict)
+        return self
+
+    def route_table(self) -> list[dict[str, Any]]:
+        """Registered HTTP routes sorted by path, then method: method, path, name, operationId,
+        tags, whether a request/response schema is attached, its schema hash and payload validation
+        mode (None for routes that don't validate). Mounted apps appear with method "*".
+        """
+        table: list[dict[str, Any]] = []
+        for route in self._starlette.routes:
+            if not isinstance(route, Route):
+                table.append({"method": "*", "path": getattr(route, "path", ""), "name": getattr(route, "name", None)})
+                continue
+            for method in sorted(route.methods or ["GET"]):
+                if method == "HEAD" and "GET" in (route.methods or []):
+                    continue
+                extras = self._route_schemas.get((route.path, method.lower()), {})
+                table.append({
+                    "method": method,
+                    "path": route.path,
+                    "name": route.name if route.name in self._route_names else None,
+                    "operation_id": extras.get("operationId"),
+                    "tags": extras.get("tags", []),
+                    "schema": "requestBody" in extras or "parameters" in extras or "responses" in extras,
+                    "schema_hash": self._schema_hashes.get(f"{method} {route.path}"),
+                    "validation": self._validation_mode(route.path, method),
+                })
+        table.sort(key=lambda r: (r["path"], r["method"]))
+        return table
+
+    def _validation_mode(self, path: str, method: str) -> str | None:
+        validation = self._route_validation.get((path, method))
+        return validation.mode if validation is not None else None
+
+    def find_route(self, method: str, path: str) -> dict[str, Any] | None:
+        """route_table() row for method and path; path is a template ("/orders/{id}") or a concrete
+        path ("/orders/42"), resolved as a request would be. None if nothing matches."""
+        method = method.upper()
+        rows = [r for r in self.route_table() if r["method"] == method]
+        for row in rows:
+            if row["path"] == path:
+                return row
+        scope = {"type": "http", "method": method, "path": path, "root_path": "", "query_string": b"", "headers": []}
+        route = self._route_for(scope)
+        if route is None:
+            return None
+        return next((r for r in rows if r["path"] == route.path), None)
+
+    def route_by_name(self, name: str) -> dict[str, Any] | None:
+        """route_table() row of the route registered with add_route(name=...); names stay the same
+        across restarts and registration order, so they are the key to log or look routes up by."""
+        if name not in self._route_names:
+            return None
+        path, methods = self._route_names[name]
+        wanted = {m.upper() for m in methods}
+        return next((r for r in self.route_table() if r["path"] == path and r["method"] in wanted), None)
+
+    def rpc_route_for(self, method_name: str) -> str | None:
+        """RPC route path whose RpcServer serves method_name (e.g. "get_employee"), else None."""
+        name = method_name.replace("/", "_")
+        for route_path, names in sorted(self._rpc_methods.items()):
+            if name in names:
+                return route_path
+        return None
+
+    def event_handlers(self, event_type: type | str) -> list[Any]:
+        """Handlers subscribed on the EventBus to event_type (class or qualified name), in call order."""
+        from urich.domain.events import EventBus
+
+        try:
+            bus = self._container.resolve(EventBus)
+        except KeyError:
+            return []
+        for subscribed_type, subscribed in getattr(bus, "_handlers", {}).items():
+            if subscribed_type is event_type or subscribed_type.__qualname__ == event_type:
+                return list(subscribed)
+        return []
+
+    def debug_routes(self, path: str = "/_routes") -> Application:
+        """Serve GET {path} with the route table, RPC method names per RPC route, event
+        subscriptions (event type -> handler names) and validation_stats(). Off unless called; don't expose publicly. Returns self.
+        """
+        from starlette.responses import JSONResponse
+
+        async def routes_endpoint(request: Any) -> Any:
+            return JSONResponse({
+                "routes": self.route_table(),
+                "rpc": {p: list(names) for p, names in sorted(self._rpc_methods.items())},
+                "events": self._event_subscriptions(),
+                "validation": self.validation_stats(),
+            })
+
+        self.add_route(path, routes_endpoint, methods=["GET"])
+        return self
+
+    def _event_subscriptions(self) -> dict[str, list[str]]:
+        from urich.domain.events import EventBus
+
+        try:
+            bus = self._container.resolve(EventBus)
+        except KeyError:
+            return {}
+        handlers = getattr(bus, "_handlers", {})
+        return {
+            event_type.__qualname__: [getattr(h, "__qualname__", type(h).__qualname__) for h in subscribed]
+            for event_type, subscribed in sorted(handlers.items(), key=lambda item: item[0].__qualname__)
+        }
+
     @property
     def container(self) -> Container:
         """DI container: registration and resolution of dependencies."""
@@ -0,0 +1,63 @@
+"""AsyncAPI 2.6 document: event channels the app consumes and emits, and its RPC methods."""
+from __future__ import annotations
+
+from typing import Any
+
+from urich.core.openapi import schema_from_dataclass
+
+ASYNCAPI_VERSION = "2.6.0"
+
+
+def event_schema(event_type: type) -> dict[str, Any]:
+    """Payload schema of an event class (dataclass fields); {"type": "object"} for other classes."""
+    return schema_from_dataclass(event_type)
+
+
+def build_asyncapi_spec(
+    *,
+    title: str,
+    version: str,
+    subscriptions: dict[type | str, list[Any]],
+    published: dict[str, dict[str, Any]],
+    rpc_methods: dict[str, list[str]] | None = None,
+) -> dict[str, Any]:
+    """
+    One channel per event wire name. AsyncAPI 2 describes operations from the client's side:
+    events this app handles (subscriptions: event type -> handlers) get a `publish` operation
+    (others publish, the app receives), events it declares (published: name -> payload schema)
+    get a `subscribe` operation. RPC methods (route path -> names) go to x-rpc-methods.
+    """
+    from urich.domain.events import event_name
+
+    channels: dict[str, dict[str, Any]] = {}
+    for event_type, handlers in subscriptions.items():
+        name = event_type if isinstance(event_type, str) else event_name(event_type)
+        channels.setdefault(name, {})["publish"] = {
+            "operationId": f"consume_{name}",
+            "summary": f"Handled by {', '.join(_handler_name(h) for h in handlers)}",
+            "x-handlers": [_handler_name(h) for h in handlers],
+            "message": {"name": name, "payload": _payload(event_type)},
+        }
+    for name, schema in published.items():
+        channels.setdefault(name, {})["subscribe"] = {
+            "operationId": f"emit_{name}",
+            "message": {"name": name, "payload": schema},
+        }
+    spec: dict[str, Any] = {
+        "asyncapi": ASYNCAPI_VERSION,
+        "info": {"title": title, "version": version},
+        "channels": {name: dict(sorted(ops.items())) for name, ops in sorted(channels.items())},
+    }
+    if rpc_methods:
+        spec["x-rpc-methods"] = [
+            {"path": path, "methods": sorted(names)} for path, names in sorted(rpc_methods.items())
+        ]
+    return spec
+
+
+def _payload(event_type: type | str) -> dict[str, Any]:
+    return {"type": "object"} if isinstance(event_type, str) else event_schema(event_type)
+
+
+def _handler_name(handler: Any) -> str:
+    return getattr(handler, "__qualname__", None) or type(handler).__qualname__
@@ -0,0 +1,74 @@
+"""Request body size limit: oversized bodies get 413 before routing and handlers run."""
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+from urich.core.errors import error_response
+
+if TYPE_CHECKING:
+    from urich.core.app import Application
+
+DEFAULT_MAX_BODY_SIZE = 10 * 1024 * 1024
+
+
+async def _reject(limit: int, scope: dict[str, Any], receive: Any, send: Any) -> None:
+    response = error_response(413, f"Request body exceeds {limit} bytes", code="PAYLOAD_TOO_LARGE")
+    await response(scope, receive, send)
+
+
+class BodyLimitMiddleware:
+    """
+    ASGI middleware: rejects a declared Content-Length above the limit at once; otherwise reads
+    the body (stopping as soon as the limit is passed) and replays it to the app.
+    The limit comes from Application._body_limit_for(scope) (per-route override or app default).
+    """
+
+    def __init__(self, app: Any, *, application: Application) -> None:
+        self.app = app
+        self._application = application
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        if scope["type"] != "http":
+            await self.app(scope, receive, send)
+            return
+        limit = self._application._body_limit_for(scope)
+        if limit is None:
+            await self.app(scope, receive, send)
+            return
+        for name, value in scope.get("headers", []):
+            if name == b"content-length":
+                try:
+                    declared = int(value)
+                except ValueError:
+                    declared = 0
+                if declared > limit:
+                    await _reject(limit, scope, receive, send)
+                    return
+                break
+
+        chunks: list[bytes] = []
+        size = 0
+        while True:
+            message = await receive()
+            if message["type"] == "http.disconnect":
+                return
+            chunk = message.get("body", b"")
+            size += len(chunk)
+            if size > limit:
+                await _reject(limit, scope, receive, send)
+                return
+            chunks.append(chunk)
+            if not message.get("more_body", False):
+                break
+
+        body = b"".join(chunks)
+        replayed = False
+
+        async def replay() -> dict[str, Any]:
+            nonlocal replayed
+            if not replayed:
+                replayed = True
+                return {"type": "http.request", "body": body, "more_body": False}
+            return await receive()
+
+        await self.app(scope, replay, send)
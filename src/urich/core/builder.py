@@ -0,0 +1,432 @@
+"""
+Application builder: server settings in one ServerConfig, checked when the app is built.
+Application.builder().host(...).port(...).openapi(title, version).register(module).build().
+"""
+from __future__ import annotations
+
+import asyncio
+import dataclasses
+import logging
+import socket
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any, Awaitable, Mapping
+
+from urich.core.body_limit import DEFAULT_MAX_BODY_SIZE
+from urich.core.config import Config
+from urich.core.module import Module
+from urich.core.timeout import DEFAULT_REQUEST_TIMEOUT
+
+if TYPE_CHECKING:
+    from urich.core.app import Application
+
+logger = logging.getLogger("urich.server")
+
+DEFAULT_SHUTDOWN_GRACE = 30.0  # seconds requests in flight get to finish on shutdown
+
+# Curated settings per profile (fields not listed keep the ServerConfig defaults).
+PROFILES: dict[str, dict[str, Any]] = {
+    "production": {
+        "host": "0.0.0.0",
+        "request_timeout": DEFAULT_REQUEST_TIMEOUT,
+        "max_body_size": 1024 * 1024,
+        "docs": None,
+        "log_level": "warning",
+        "server_options": {"proxy_headers": True, "access_log": False},
+    },
+    "development": {
+        "host": "127.0.0.1",
+        "request_timeout": None,
+        "max_body_size": None,
+        "docs": "default",  # DocsConfig()
+        "log_level": "debug",
+        "server_options": {},
+    },
+}
+
+
+class ConfigError(ValueError):
+    """Invalid server configuration; .problems lists every problem found, not just the first."""
+
+    def __init__(self, problems: list[str]) -> None:
+        self.problems = problems
+        super().__init__("Invalid server configuration: " + "; ".join(problems))
+
+
+@dataclass
+class DocsConfig:
+    """OpenAPI spec and Swagger UI, as Application.openapi() takes them. docs_path=None: spec only."""
+
+    title: str = "API"
+    version: str = "0.1.0"
+    docs_path: str | None = "/docs"
+    openapi_path: str = "/openapi.json"
+
+
+@dataclass
+class ServerConfig:
+    """
+    Everything the builder sets: listen address, request limits and docs (None = no OpenAPI routes).
+    Limits take None to disable them, as in Application(...). validate() lists the problems,
+    warnings() the settings that are likely mistakes.
+
+    production() / development() start from curated profiles (profile records which):
+
+    | Setting         | production                           | development  |
+    |-----------------|--------------------------------------|--------------|
+    | host            | 0.0.0.0                              | 127.0.0.1    |
+    | request_timeout | 30 s                                 | None         |
+    | max_body_size   | 1 MiB                                | None         |
+    | docs            | None                                 | DocsConfig() |
+    | log_level       | warning                              | debug        |
+    | server_options  | proxy_headers=True, access_log=False | {}           |
+    """
+
+    host: str = "127.0.0.1"
+    port: int = 8000
+    request_timeout: float | None = DEFAULT_REQUEST_TIMEOUT
+    max_body_size: int | None = DEFAULT_MAX_BODY_SIZE
+    docs: DocsConfig | None = None
+    log_level: str = "info"
+    shutdown_grace: float | None = DEFAULT_SHUTDOWN_GRACE  # seconds to drain requests on shutdown; None = no limit
+    server_options: dict[str, Any] = field(default_factory=dict)  # extra uvicorn.Config keyword arguments
+    profile: str | None = None  # "production" / "development" when built from a profile
+
+    @classmethod
+    def for_profile(cls, profile: str, **overrides: Any) -> ServerConfig:
+        """The curated settings of profile (see PROFILES), with overrides on top."""
+        if profile not in PROFILES:
+            raise ConfigError([f"unknown profile {profile!r} (use one of {', '.join(PROFILES)})"])
+        values = dict(PROFILES[profile])
+        if values.get("docs") == "default":
+            values["docs"] = DocsConfig()
+        values["server_options"] = dict(values.get("server_options", {}))
+        return cls(**{**values, **overrides, "profile": profile})
+
+    @classmethod
+    def production(cls, **overrides: Any) -> ServerConfig:
+        return cls.for_profile("production", **overrides)
+
+    @classmethod
+    def development(cls, **overrides: Any) -> ServerConfig:
+        return cls.for_profile("development", **overrides)
+
+    def overrides(self) -> dict[str, tuple[Any, Any]]:
+        """Settings that differ from the profile: name -> (profile value, actual value). {} without a profile."""
+        if self.profile is None:
+            return {}
+        base = ServerConfig.for_profile(self.profile)
+        return {
+            f.name: (getattr(base, f.name), getattr(self, f.name))
+            for f in dataclasses.fields(self)
+            if getattr(base, f.name) != getattr(self, f.name)
+        }
+
+    def warnings(self) -> list[str]:
+        """Combinations that are almost certainly mistakes; they don't stop build()."""
+        out: list[str] = []
+        production = self.profile == "production"
+        if production and self.docs is not None:
+            out.append("OpenAPI docs are served in the production profile")
+        if production and self.request_timeout is None:
+            out.append("no request timeout in the production profile")
+        if production and self.max_body_size is None:
+            out.append("no body size limit in the production profile")
+        if production and self.log_level == "debug":
+            out.append("debug logging in the production profile")
+        if production and self.server_options.get("reload"):
+            out.append("auto-reload is enabled in the production profile")
+        if self.profile == "development" and self.host not in ("127.0.0.1", "localhost", "::1"):
+            out.append(f"development profile listens on {self.host}, not only on localhost")
+        if self.request_timeout is None and self.server_options.get("timeout_keep_alive", 0) > 60:
+            out.append("no request timeout while keep-alive connections are held over 60 s")
+        return out
+
+    def summary(self) -> str:
+        """Startup summary: active profile and the settings overridden from it."""
+        lines = [f"Server profile: {self.profile or 'custom'} ({self.host}:{self.port})"]
+        for name, (base, actual) in self.overrides().items():
+            lines.append(f"  override {name}: {base!r} -> {actual!r}")
+        return "\n".join(lines)
+
+    def validate(self) -> list[str]:
+        problems: list[str] = []
+        if not self.host:
+            problems.append("host is empty")
+        if not 0 <= self.port < 65536:
+            problems.append(f"port {self.port} is out of range 0-65535 (0 picks a free port)")
+        if self.request_timeout is not None and self.request_timeout <= 0:
+            problems.append(f"request_timeout must be positive or None, got {self.request_timeout}")
+        if self.max_body_size is not None and self.max_body_size < 0:
+            problems.append(f"max_body_size must be >= 0 or None, got {self.max_body_size}")
+        if self.shutdown_grace is not None and self.shutdown_grace < 0:
+            problems.append(f"shutdown_grace must be >= 0 or None, got {self.shutdown_grace}")
+        if self.docs is not None:
+            if not self.docs.title.strip():
+                problems.append("OpenAPI title is empty")
+            if not self.docs.version.strip():
+                problems.append("OpenAPI version is empty")
+            for name in ("openapi_path", "docs_path"):
+                path = getattr(self.docs, name)
+                if path is not None and not path.startswith("/"):
+                    problems.append(f"{name} {path!r} must start with '/'")
+        return problems
+
+    @classmethod
+    def from_dict(cls, data: Mapping[str, Any]) -> ServerConfig:
+        """
+        Build from a plain dict (a settings file, Config.load_from_env()): keys are the field names,
+        docs is a dict of DocsConfig fields; openapi_title / openapi_version are accepted as well.
+        profile ("production" / "development") starts from that profile; the other keys override it.
+        String values are converted ("8000" -> 8000, "none" -> None). Unknown keys raise ConfigError.
+        """
+        data = dict(data)
+        profile = data.pop("profile", None) or None
+        base = cls.for_profile(profile) if profile is not None else cls()
+        docs = data.pop("docs", base.docs)
+        title, version = data.pop("openapi_title", None), data.pop("openapi_version", None)
+        if isinstance(docs, Mapping):
+            docs = DocsConfig(**docs)
+        if title is not None or version is not None:
+            docs = dataclasses.replace(
+                docs or DocsConfig(),
+                **{k: v for k, v in (("title", title), ("version", version)) if v is not None},
+            )
+        known = {f.name for f in dataclasses.fields(cls)} - {"docs", "profile"}
+        unknown = sorted(set(data) - known)
+        if unknown:
+            raise ConfigError([f"unknown setting {key!r}" for key in unknown])
+        problems: list[str] = []
+        for key, convert in (
+            ("port", int),
+            ("request_timeout", float),
+            ("max_body_size", int),
+            ("shutdown_grace", float),
+        ):
+            value = data.get(key)
+            if isinstance(value, str):
+                try:
+                    data[key] = None if value.strip().lower() in ("", "none") and key != "port" else convert(value)
+                except ValueError:
+                    problems.append(f"{key} {value!r} is not a number")
+        if problems:
+            raise ConfigError(problems)
+        return dataclasses.replace(base, **data, docs=docs)
+
+    @classmethod
+    def from_env(cls, prefix: str = "URICH_", **defaults: Any) -> ServerConfig:
+        """From environment variables: URICH_PROFILE, URICH_HOST, URICH_PORT, URICH_REQUEST_TIMEOUT,
+        URICH_OPENAPI_TITLE..."""
+        return cls.from_dict(Config.load_from_env(prefix, **defaults))
+
+
+def _copy(settings: ServerConfig) -> ServerConfig:
+    return dataclasses.replace(settings, server_options=dict(settings.server_options))
+
+
+class ServerHandle:
+    """
+    A server started with ConfiguredServer.start(). shutdown() stops accepting connections, gives
+    requests in flight up to config.shutdown_grace seconds to finish (the rest are cancelled), then
+    runs the lifespan shutdown hooks. wait() returns once the server has stopped, whatever stopped it.
+    """
+
+    def __init__(self, server: Any, task: asyncio.Task[None], local_addr: tuple[str, int]) -> None:
+        self._server = server  # uvicorn.Server
+        self._task = task
+        self.local_addr = local_addr  # (host, port) actually bound; the port is real even for port 0
+
+    @property
+    def running(self) -> bool:
+        return not self._task.done()
+
+    @property
+    def active_connections(self) -> int:
+        """Open client connections (drained by shutdown())."""
+        return len(self._server.server_state.connections)
+
+    async def shutdown(self) -> None:
+        """Graceful stop; returns when the lifespan shutdown hooks have run."""
+        self._server.should_exit = True
+        await self.wait()
+
+    def abort(self) -> None:
+        """Stop at once: connections are dropped without the grace period and the lifespan shutdown
+        hooks are skipped. await wait() for the server to be gone."""
+        self._server.force_exit = True
+        self._server.should_exit = True
+
+    async def wait(self) -> None:
+        await asyncio.shield(self._task)
+
+
+class ConfiguredServer:
+    """
+    A built application with its ServerConfig. await serve() runs uvicorn in the current event loop
+    until a signal (or the shutdown awaitable) stops it; run() is the blocking wrapper; start()
+    serves in the background and returns a ServerHandle. Serving starts with bind(), so a
+    port 0 config listens on a free port, reported by ServerHandle.local_addr. Needs uvicorn
+    (pip install urich[server]).
+    """
+
+    def __init__(self, app: Application, config: ServerConfig) -> None:
+        self.app = app
+        self.config = config
+
+    def uvicorn_config(self) -> Any:
+        import uvicorn
+
+        options = {"timeout_graceful_shutdown": self.config.shutdown_grace, **self.config.server_options}
+        return uvicorn.Config(
+            self.app,
+            host=self.config.host,
+            port=self.config.port,
+            log_level=self.config.log_level,
+            **options,
+        )
+
+    def _log_settings(self) -> None:
+        logger.info("%s", self.config.summary())
+        for warning in self.config.warnings():
+            logger.warning("Server config: %s", warning)
+
+    def bind(self) -> socket.socket:
+        """Bind phase: a socket bound to config.host and config.port (0 picks a free port; read it
+        with getsockname()). Raises OSError right away if the address is taken."""
+        family = socket.AF_INET6 if ":" in self.config.host else socket.AF_INET
+        sock = socket.socket(family, socket.SOCK_STREAM)
+        try:
+            sock.setsockopt(socket.SOL_SOCKET, socket.SO_REUSEADDR, 1)
+            sock.bind((self.config.host, self.config.port))
+        except OSError:
+            sock.close()
+            raise
+        return sock
+
+    async def start(self, sock: socket.socket | None = None) -> ServerHandle:
+        """Serve in a background task of the current event loop on sock (default: bind()); returns
+        once the server accepts connections (lifespan startup done). RuntimeError if it stopped
+        during startup."""
+        import uvicorn
+
+        sock = sock if sock is not None else self.bind()
+        host, port = sock.getsockname()[:2]
+        self._log_settings()
+        server = uvicorn.Server(self.uvicorn_config())
+        task = asyncio.create_task(server.serve(sockets=[sock]))
+        while not server.started:
+            if task.done():
+                task.result()  # re-raise the server's error
+                raise RuntimeError("Server stopped during startup (see the uvicorn log)")
+            await asyncio.sleep(0.01)
+        return ServerHandle(server, task, (host, port))
+
+    async def serve(self, shutdown: Awaitable[Any] | None = None) -> None:
+        """Serve until SIGINT / SIGTERM, or until the shutdown awaitable completes (e.g. event.wait()),
+        then shut down gracefully (see ServerHandle.shutdown)."""
+        handle = await self.start()
+        if shutdown is None:
+            await handle.wait()
+            return
+        stop = asyncio.ensure_future(shutdown)
+        stopped = asyncio.ensure_future(handle.wait())
+        try:
+            await asyncio.wait({stop, stopped}, return_when=asyncio.FIRST_COMPLETED)
+        finally:
+            stop.cancel()
+            await handle.shutdown()
+
+    def run(self) -> None:
+        asyncio.run(self.serve())
+
+
+class AppBuilder:
+    """
+    Fluent Application setup: server knobs go to one ServerConfig, modules are registered in order,
+    and openapi() is applied last, after all modules. build() checks the configuration
+    (ConfigError listing every problem) and returns the Application; build_server() wraps it
+    in a ConfiguredServer.
+    """
+
+    def __init__(self, settings: ServerConfig | Mapping[str, Any] | None = None) -> None:
+        if isinstance(settings, Mapping):
+            settings = ServerConfig.from_dict(settings)
+        self._settings = _copy(settings) if settings is not None else ServerConfig()
+        self._app_config: Any = None
+        self._modules: list[Module] = []
+
+    @property
+    def settings(self) -> ServerConfig:
+        return self._settings
+
+    def host(self, host: str) -> AppBuilder:
+        self._settings.host = host
+        return self
+
+    def port(self, port: int) -> AppBuilder:
+        self._settings.port = port
+        return self
+
+    def request_timeout(self, seconds: float | None) -> AppBuilder:
+        self._settings.request_timeout = seconds
+        return self
+
+    def max_body_size(self, size: int | None) -> AppBuilder:
+        self._settings.max_body_size = size
+        return self
+
+    def log_level(self, level: str) -> AppBuilder:
+        self._settings.log_level = level
+        return self
+
+    def shutdown_grace(self, seconds: float | None) -> AppBuilder:
+        """Seconds requests in flight get to finish on shutdown (None = wait for all of them)."""
+        self._settings.shutdown_grace = seconds
+        return self
+
+    def server_options(self, **options: Any) -> AppBuilder:
+        """Extra uvicorn.Config arguments (ssl_keyfile, proxy_headers, limit_concurrency...)."""
+        self._settings.server_options.update(options)
+        return self
+
+    def openapi(self, title: str = "API", version: str = "0.1.0", **docs: Any) -> AppBuilder:
+        """Shorthand for docs(DocsConfig(title, version, ...))."""
+        return self.docs(DocsConfig(title=title, version=version, **docs))
+
+    def docs(self, docs: DocsConfig | None) -> AppBuilder:
+        self._settings.docs = docs
+        return self
+
+    def config(self, config: Any) -> AppBuilder:
+        """Application config object, as Application(config=...)."""
+        self._app_config = config
+        return self
+
+    def register(self, module: Module) -> AppBuilder:
+        self._modules.append(module)
+        return self
+
+    def build(self) -> Application:
+        from urich.core.app import Application
+
+        problems = self._settings.validate()
+        if problems:
+            raise ConfigError(problems)
+        settings = self._settings
+        app = Application(
+            self._app_config,
+            max_body_size=settings.max_body_size,
+            request_timeout=settings.request_timeout,
+        )
+        for module in self._modules:
+            app.register(module)
+        if settings.docs is not None:
+            app.openapi(
+                title=settings.docs.title,
+                version=settings.docs.version,
+                docs_path=settings.docs.docs_path,
+                openapi_path=settings.docs.openapi_path,
+            )
+        return app
+
+    def build_server(self) -> ConfiguredServer:
+        return ConfiguredServer(self.build(), _copy(self._settings))
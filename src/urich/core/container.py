@@ -8,11 +8,13 @@ T = TypeVar("T")
 
 
 def _resolve_annotation(ann: str, cls: type[Any]) -> Any:
-    """Resolve a string annotation (from __future__ annotations) to the actual class."""
+    """Resolve a string annotation (from __future__ annotations) to the actual class.
+    Looks in cls's module, then in the modules of its bases (an inherited __init__)."""
     import sys
-    mod = sys.modules.get(cls.__module__)
-    if mod is not None and hasattr(mod, ann):
-        return getattr(mod, ann)
+    for klass in cls.__mro__:
+        mod = sys.modules.get(klass.__module__)
+        if mod is not None and hasattr(mod, ann):
+            return getattr(mod, ann)
     return ann
 
 
@@ -26,6 +28,8 @@ def _instantiate_with_container(container: Container, cls: type[T]) -> T:
         ann = param.annotation
         if isinstance(ann, str):
             ann = _resolve_annotation(ann, cls)
+        if param.default is not inspect.Parameter.empty and not container.has(ann):
+            continue  # optional setting: keep the default
         kwargs[name] = container.resolve(ann)
     return cls(**kwargs)
 
@@ -54,6 +58,10 @@ class Container:
         self._singletons[key] = instance
         self._singleton_keys.add(key)
 
+    def has(self, key: type[Any] | str) -> bool:
+        """True if key is registered (resolve would not raise KeyError for it)."""
+        return key in self._registry
+
     def resolve(self, key: type[T] | type[Any] | str) -> T:
         """Resolve an instance by type or key."""
         if key not in self._registry:
@@ -65,6 +73,17 @@ class Container:
             self._singletons[key] = instance
         return instance
 
+    def warm_up(self) -> list[type[Any] | str]:
+        """Create every registered singleton now (e.g. at startup) instead of on first resolve,
+        so construction errors surface before traffic. Returns the keys created.
+        """
+        created: list[type[Any] | str] = []
+        for key in list(self._singleton_keys):
+            if self._singletons.get(key) is None:
+                self.resolve(key)
+                created.append(key)
+        return created
+
     def register_class(self, cls: type[T], singleton: bool = True) -> None:
         """Register a class: on resolve an instance is created with dependencies from the container."""
         self.register(key=cls, factory=lambda: _instantiate_with_container(self, cls), singleton=singleton)
@@ -0,0 +1,30 @@
+"""Current request for code that only receives a payload (DomainModule handlers, RPC methods)."""
+from __future__ import annotations
+
+import contextvars
+from typing import Any
+
+_request: contextvars.ContextVar[Any] = contextvars.ContextVar("urich_request", default=None)
+
+
+def current_request() -> Any:
+    """Starlette Request being handled by a DomainModule or RPC endpoint in this task, or None."""
+    return _request.get()
+
+
+def request_state() -> Any:
+    """request.state of the current request: values set by middlewares (scope["state"]), e.g.
+    request_state().tenant_id. Raises LookupError outside a request."""
+    request = _request.get()
+    if request is None:
+        raise LookupError("request_state() called outside a request")
+    return request.state
+
+
+def bind_request(request: Any) -> contextvars.Token[Any]:
+    """Make request current; pass the token to unbind_request when done."""
+    return _request.set(request)
+
+
+def unbind_request(token: contextvars.Token[Any]) -> None:
+    _request.reset(token)
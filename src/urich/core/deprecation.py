@@ -0,0 +1,56 @@
+"""
+Runtime deprecation warnings for old API usage: each feature key is reported once per process.
+URICH_DEPRECATIONS=deny turns any tracked deprecation into an error (enforce migration in CI).
+"""
+from __future__ import annotations
+
+import logging
+import os
+import warnings
+from dataclasses import dataclass
+
+logger = logging.getLogger("urich.deprecation")
+
+ENV_MODE = "URICH_DEPRECATIONS"
+
+
+class UrichDeprecationError(RuntimeError):
+    """Deprecated API used while URICH_DEPRECATIONS=deny."""
+
+
+@dataclass(frozen=True)
+class DeprecationInfo:
+    """One deprecated feature seen in this process; replacement says what to use instead."""
+
+    feature_key: str
+    message: str
+    replacement: str | None = None
+
+
+_seen: dict[str, DeprecationInfo] = {}
+
+
+def warn(feature_key: str, message: str, *, replacement: str | None = None) -> None:
+    """Report use of a deprecated feature. First use per key logs a warning and emits
+    DeprecationWarning; with URICH_DEPRECATIONS=deny every use raises UrichDeprecationError.
+    """
+    info = DeprecationInfo(feature_key, message, replacement)
+    text = f"[{feature_key}] {message}" + (f" Use instead: {replacement}" if replacement else "")
+    if os.environ.get(ENV_MODE, "").lower() == "deny":
+        _seen.setdefault(feature_key, info)
+        raise UrichDeprecationError(text)
+    if feature_key in _seen:
+        return
+    _seen[feature_key] = info
+    logger.warning(text)
+    warnings.warn(text, DeprecationWarning, stacklevel=3)
+
+
+def deprecations_seen() -> list[DeprecationInfo]:
+    """Deprecated features used so far in this process, in first-use order."""
+    return list(_seen.values())
+
+
+def reset_deprecations() -> None:
+    """Forget seen keys (for tests)."""
+    _seen.clear()
@@ -0,0 +1,217 @@
+"""Error reporting hook: forward unhandled exceptions and 5xx responses to an error tracker."""
+from __future__ import annotations
+
+import asyncio
+import logging
+import time
+import traceback
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any, Protocol, runtime_checkable
+
+if TYPE_CHECKING:
+    from urich.core.app import Application
+
+logger = logging.getLogger("urich.errors")
+
+REDACTED = "[redacted]"
+DEFAULT_REDACT_HEADERS = frozenset({"authorization", "cookie", "set-cookie", "proxy-authorization", "x-api-key"})
+
+
+@dataclass
+class ErrorEvent:
+    """
+    One reportable error. kind: "exception" (unhandled in a handler) or "http_error"
+    (response status at or above the threshold). request: method, path, query, redacted headers.
+    """
+
+    kind: str
+    route: str
+    status: int
+    message: str
+    request_id: str | None = None
+    request: dict[str, Any] = field(default_factory=dict)
+    exception_type: str | None = None
+    traceback: str | None = None
+
+    @property
+    def fingerprint(self) -> str:
+        return f"{self.kind}:{self.route}:{self.exception_type or self.status}"
+
+
+@runtime_checkable
+class ErrorReporter(Protocol):
+    """Receives error events off the request path. Implement for Sentry, Rollbar, etc."""
+
+    def report(self, event: ErrorEvent) -> Any:
+        ...
+
+
+class LoggingErrorReporter:
+    """Default reporter: writes events to the "urich.errors" logger."""
+
+    def report(self, event: ErrorEvent) -> None:
+        logger.error(
+            "%s on %s (status %s, request %s): %s%s",
+            event.kind,
+            event.route,
+            event.status,
+            event.request_id,
+            event.message,
+            f"\n{event.traceback}" if event.traceback else "",
+        )
+
+
+class SentryErrorReporter:
+    """Reporter for Sentry (requires sentry-sdk). dsn: e.g. from config; None = already initialized."""
+
+    def __init__(self, dsn: str | None = None, **options: Any) -> None:
+        try:
+            import sentry_sdk
+        except ImportError:
+            raise RuntimeError("SentryErrorReporter requires sentry-sdk; pip install sentry-sdk")
+        if dsn is not None:
+            sentry_sdk.init(dsn=dsn, **options)
+        self._sdk = sentry_sdk
+
+    def report(self, event: ErrorEvent) -> None:
+        with self._sdk.push_scope() as scope:
+            scope.set_tag("urich.kind", event.kind)
+            scope.set_tag("urich.route", event.route)
+            if event.request_id:
+                scope.set_tag("request_id", event.request_id)
+            scope.set_context("request", event.request)
+            scope.fingerprint = [event.fingerprint]
+            self._sdk.capture_message(f"{event.message}\n{event.traceback or ''}", level="error")
+
+
+class ErrorReportingDispatcher:
+    """
+    Bounded queue + background task between the request path and the reporter.
+    Identical events (same fingerprint) are limited to rate_limit per minute; overflow and
+    rate-limited events are counted in dropped / rate_limited.
+    """
+
+    def __init__(
+        self,
+        reporter: ErrorReporter,
+        *,
+        queue_size: int = 1000,
+        rate_limit: int = 10,
+        redact_headers: frozenset[str] = DEFAULT_REDACT_HEADERS,
+    ) -> None:
+        self.reporter = reporter
+        self.rate_limit = rate_limit
+        self.redact_headers = frozenset(h.lower() for h in redact_headers)
+        self.dropped = 0
+        self.rate_limited = 0
+        self._queue_size = queue_size
+        self._queue: asyncio.Queue[ErrorEvent] | None = None
+        self._task: asyncio.Task[None] | None = None
+        self._windows: dict[str, tuple[float, int]] = {}
+
+    def _allow(self, fingerprint: str) -> bool:
+        now = time.monotonic()
+        start, count = self._windows.get(fingerprint, (now, 0))
+        if now - start >= 60:
+            start, count = now, 0
+        if count >= self.rate_limit:
+            self._windows[fingerprint] = (start, count)
+            return False
+        self._windows[fingerprint] = (start, count + 1)
+        return True
+
+    def submit(self, event: ErrorEvent) -> None:
+        """Enqueue without blocking; never raises into the request path."""
+        if not self._allow(event.fingerprint):
+            self.rate_limited += 1
+            return
+        if self._queue is None:
+            self._queue = asyncio.Queue(maxsize=self._queue_size)
+        if self._task is None or self._task.done():
+            self._task = asyncio.get_running_loop().create_task(self._drain())
+        try:
+            self._queue.put_nowait(event)
+        except asyncio.QueueFull:
+            self.dropped += 1
+
+    async def _drain(self) -> None:
+        assert self._queue is not None
+        while True:
+            event = await self._queue.get()
+            try:
+                result = self.reporter.report(event)
+                if hasattr(result, "__await__"):
+                    await result
+            except Exception:
+                logger.exception("error reporter failed")
+            finally:
+                self._queue.task_done()
+
+    async def flush(self) -> None:
+        """Wait until queued events are reported (tests, shutdown)."""
+        if self._queue is not None:
+            await self._queue.join()
+
+    def summarize_request(self, scope: dict[str, Any]) -> dict[str, Any]:
+        headers: dict[str, str] = {}
+        for name, value in scope.get("headers", []):
+            key = name.decode("latin-1").lower()
+            headers[key] = REDACTED if key in self.redact_headers else value.decode("latin-1")
+        return {
+            "method": scope.get("method"),
+            "path": scope.get("path"),
+            "query_string": scope.get("query_string", b"").decode("latin-1"),
+            "headers": headers,
+        }
+
+
+class ErrorReportingMiddleware:
+    """ASGI middleware: reports unhandled exceptions (then re-raises) and responses >= status_threshold."""
+
+    def __init__(self, app: Any, *, application: Application, dispatcher: ErrorReportingDispatcher, status_threshold: int = 500) -> None:
+        self.app = app
+        self._application = application
+        self._dispatcher = dispatcher
+        self._status_threshold = status_threshold
+
+    def _route(self, scope: dict[str, Any]) -> str:
+        from starlette.routing import Match, Route
+
+        for route in self._application.starlette.routes:
+            if isinstance(route, Route) and route.matches(scope)[0] == Match.FULL:
+                return route.path
+        return "unmatched"
+
+    def _event(self, scope: dict[str, Any], **fields: Any) -> ErrorEvent:
+        """Event for scope; the request id is RequestIdMiddleware's (scope["state"]), else the
+        client's X-Request-Id header."""
+        request = self._dispatcher.summarize_request(scope)
+        request_id = (scope.get("state") or {}).get("request_id") or request["headers"].get("x-request-id")
+        return ErrorEvent(route=self._route(scope), request_id=request_id, request=request, **fields)
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        if scope["type"] != "http":
+            await self.app(scope, receive, send)
+            return
+
+        async def send_checked(message: dict[str, Any]) -> None:
+            if message["type"] == "http.response.start" and message["status"] >= self._status_threshold:
+                self._dispatcher.submit(
+                    self._event(scope, kind="http_error", status=message["status"], message=f"HTTP {message['status']}")
+                )
+            await send(message)
+
+        try:
+            await self.app(scope, receive, send_checked)
+        except Exception as e:
+            self._dispatcher.submit(
+                self._event(
+                    scope,
+                    kind="exception",
+                    status=500,
+                    message=str(e) or type(e).__name__,
+                    exception_type=type(e).__qualname__,
+                    traceback=traceback.format_exc(),
+                )
+            )
+            raise
@@ -0,0 +1,151 @@
+"""HTTP errors: typed exception for handlers and one JSON error body shape for every layer."""
+from __future__ import annotations
+
+import logging
+from http import HTTPStatus
+from typing import Any
+
+from starlette.responses import JSONResponse
+
+from urich.domain.errors import AppError
+
+logger = logging.getLogger("urich.errors")
+
+
+def default_error_code(status: int) -> str:
+    """NOT_FOUND for 404, VALIDATION_ERROR for 422, etc."""
+    if status == 422:
+        return "VALIDATION_ERROR"
+    if status == 500:
+        return "INTERNAL"
+    try:
+        return HTTPStatus(status).phrase.upper().replace(" ", "_").replace("-", "_")
+    except ValueError:
+        return "ERROR"
+
+
+def error_body(code: str, message: str, details: Any = None) -> dict[str, Any]:
+    """{"error": {"code": ..., "message": ...[, "details": ...]}}"""
+    err: dict[str, Any] = {"code": code, "message": message}
+    if details is not None:
+        err["details"] = details
+    return {"error": err}
+
+
+def error_response(
+    status: int,
+    message: str,
+    *,
+    code: str | None = None,
+    details: Any = None,
+    headers: dict[str, str] | None = None,
+) -> JSONResponse:
+    """JSON error response with the standard body."""
+    return JSONResponse(
+        error_body(code or default_error_code(status), message, details),
+        status_code=status,
+        headers=headers,
+    )
+
+
+class HttpError(AppError):
+    """
+    Raise from a handler to answer with an explicit status, e.g.
+    raise HttpError(404, "Order not found") or HttpError(409, "Already paid", code="ORDER_PAID").
+    headers are added to the response (e.g. WWW-Authenticate on 401).
+    An AppError with the status first and an optional code; domain code should prefer AppError.
+    """
+
+    def __init__(
+        self,
+        status: int,
+        message: str,
+        *,
+        code: str | None = None,
+        details: Any = None,
+        headers: dict[str, str] | None = None,
+    ) -> None:
+        super().__init__(code or default_error_code(status), message, status=status, details=details)
+        self.headers = headers
+
+    def to_response(self) -> JSONResponse:
+        return error_response(self.status, self.message, code=self.code, details=self.details, headers=self.headers)
+
+
+def allowed_methods(routes: list[Any], scope: dict[str, Any]) -> list[str]:
+    """Methods registered for the request path across all routes (Route.methods include HEAD for GET)."""
+    from starlette.routing import Match, Route
+
+    methods: set[str] = set()
+    for route in routes:
+        if isinstance(route, Route) and route.matches(scope)[0] != Match.NONE:
+            methods |= set(route.methods or [])
+    return sorted(methods)
+
+
+def route_template(routes: list[Any], scope: dict[str, Any]) -> str | None:
+    """Path template of the route fully matching the request (e.g. /orders/{order_id}), or None."""
+    from starlette.routing import Match, Route
+
+    for route in routes:
+        if isinstance(route, Route) and route.matches(scope)[0] == Match.FULL:
+            return route.path
+    return None
+
+
+def install_error_handlers(starlette: Any) -> None:
+    """Render AppError (and HttpError), Starlette HTTPException (404/405...), ConcurrencyError (409 CONFLICT) and
+    unhandled errors as JSON error bodies.
+    OPTIONS on a known path answers 204 with Allow instead of 405.
+    """
+    from starlette.exceptions import HTTPException
+    from starlette.responses import Response
+
+    from urich.domain.repository import ConcurrencyError
+
+    async def http_error(request: Any, exc: HttpError) -> JSONResponse:
+        return exc.to_response()
+
+    async def app_error(request: Any, exc: AppError) -> JSONResponse:
+        return error_response(exc.status, exc.message, code=exc.code, details=exc.details)
+
+    async def http_exception(request: Any, exc: HTTPException) -> Response:
+        if exc.status_code == 405 and request.method == "OPTIONS":
+            allow = allowed_methods(starlette.routes, request.scope) + ["OPTIONS"]
+            return Response(status_code=204, headers={"Allow": ", ".join(allow)})
+        headers = dict(getattr(exc, "headers", None) or {})
+        if exc.status_code == 405:
+            # Starlette lists only the first matching route's methods; a path can have several routes.
+            allow = allowed_methods(starlette.routes, request.scope)
+            headers["Allow"] = ", ".join(allow)
+            return error_response(
+                405,
+                f"Method {request.method} not allowed for {request.url.path}",
+                details={"allowed": allow},
+                headers=headers,
+            )
+        return error_response(exc.status_code, str(exc.detail), headers=headers or None)
+
+    async def unhandled(request: Any, exc: Exception) -> JSONResponse:
+        # Generic body for the client; Starlette re-raises exc afterwards so the server logs the traceback.
+        logger.error(
+            "Unhandled %s in %s %s",
+            type(exc).__name__,
+            request.method,
+            route_template(starlette.routes, request.scope) or request.url.path,
+        )
+        return error_response(500, "Internal server error")
+
+    async def concurrency_error(request: Any, exc: ConcurrencyError) -> JSONResponse:
+        return error_response(
+            409,
+            str(exc),
+            code="CONFLICT",
+            details={"id": str(exc.id), "expected_version": exc.expected_version, "actual_version": exc.actual_version},
+        )
+
+    starlette.add_exception_handler(HttpError, http_error)
+    starlette.add_exception_handler(AppError, app_error)
+    starlette.add_exception_handler(ConcurrencyError, concurrency_error)
+    starlette.add_exception_handler(HTTPException, http_exception)
+    starlette.add_exception_handler(Exception, unhandled)
@@ -0,0 +1,198 @@
+"""
+Request examples on routes: shown in the OpenAPI spec and run as contract tests by
+app.run_example_tests() (or `urich test-examples`), so documented examples keep working.
+"""
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from typing import Any, Sequence
+
+# (method, path template, examples, OpenAPI responses object of the operation)
+ExampleCase = tuple[str, str, Sequence["Example"], dict[str, Any]]
+
+
+class Example:
+    """
+    One request example of a route: Example("two items", body={...}). body is the JSON body,
+    params the query string, path_params the values of {param} segments. The run passes on any
+    2xx status; .expect_status(422) makes it an intentionally invalid example.
+    """
+
+    def __init__(
+        self,
+        name: str,
+        *,
+        body: Any = None,
+        params: dict[str, Any] | None = None,
+        headers: dict[str, str] | None = None,
+        path_params: dict[str, Any] | None = None,
+        summary: str | None = None,
+    ) -> None:
+        self.name = name
+        self.body = body
+        self.params = params
+        self.headers = headers
+        self.path_params = path_params
+        self.summary = summary
+        self.statuses: tuple[int, ...] = ()  # empty = any 2xx
+
+    def expect_status(self, *statuses: int) -> Example:
+        """Statuses the example passes with (instead of 2xx). Returns self."""
+        self.statuses = statuses
+        return self
+
+    def accepts(self, status: int) -> bool:
+        return status in self.statuses if self.statuses else 200 <= status < 300
+
+    @property
+    def expected(self) -> str:
+        return " or ".join(str(s) for s in self.statuses) if self.statuses else "2xx"
+
+    def openapi(self) -> dict[str, Any]:
+        """OpenAPI example object: {"summary": ..., "value": body}."""
+        example: dict[str, Any] = {"value": self.body}
+        if self.summary is not None:
+            example["summary"] = self.summary
+        return example
+
+
+@dataclass(frozen=True)
+class ExampleResult:
+    """Outcome of one example: problems is empty when it passed, else the status mismatch (with the
+    response body) or each place the response differs from the documented response schema."""
+
+    method: str
+    path: str
+    example: str
+    status: int | None
+    problems: tuple[str, ...] = ()
+
+    @property
+    def passed(self) -> bool:
+        return not self.problems
+
+    def __str__(self) -> str:
+        head = f"{'PASS' if self.passed else 'FAIL'} {self.method} {self.path} [{self.example}]"
+        return "\n".join([head, *(f"    {problem}" for problem in self.problems)])
+
+
+@dataclass
+class ExampleTestReport:
+    """Results of app.run_example_tests(), in route order."""
+
+    results: list[ExampleResult] = field(default_factory=list)
+
+    @property
+    def failures(self) -> list[ExampleResult]:
+        return [r for r in self.results if not r.passed]
+
+    def all_passed(self) -> bool:
+        return not self.failures
+
+    def __str__(self) -> str:
+        lines = [str(r) for r in self.results]
+        lines.append(f"{len(self.results) - len(self.failures)} passed, {len(self.failures)} failed")
+        return "\n".join(lines)
+
+
+_TYPES: dict[str, Any] = {
+    "string": str,
+    "integer": int,
+    "number": (int, float),
+    "boolean": bool,
+    "array": list,
+    "object": dict,
+    "null": type(None),
+}
+
+
+def _is_type(value: Any, name: str) -> bool:
+    if isinstance(value, bool) and name in ("integer", "number"):
+        return False
+    if name == "integer" and isinstance(value, float):
+        return value.is_integer()
+    return isinstance(value, _TYPES.get(name, object))
+
+
+def schema_issues(value: Any, schema: Any, path: str = "") -> list[str]:
+    """Where a JSON value differs from a (ref-resolved) JSON schema: type, nullable, enum,
+    required, properties, items, anyOf / oneOf / allOf. Other keywords are not checked."""
+    if not isinstance(schema, dict) or "$ref" in schema:
+        return []
+    where = path or "/"
+    if value is None and schema.get("nullable"):
+        return []
+    for keyword in ("anyOf", "oneOf"):
+        options = schema.get(keyword)
+        if options and all(schema_issues(value, option, path) for option in options):
+            return [f"{where}: matches none of {keyword}"]
+    issues: list[str] = []
+    for option in schema.get("allOf", []):
+        issues.extend(schema_issues(value, option, path))
+    expected = schema.get("type")
+    if expected is not None:
+        names = expected if isinstance(expected, list) else [expected]
+        if not any(_is_type(value, name) for name in names):
+            got = next((n for n in _TYPES if _is_type(value, n)), type(value).__name__)
+            return issues + [f"{where}: expected {' or '.join(names)}, got {got}"]
+    if "enum" in schema and value not in schema["enum"]:
+        issues.append(f"{where}: {value!r} is not one of {schema['enum']!r}")
+    if isinstance(value, dict):
+        for name in schema.get("required", []):
+            if name not in value:
+                issues.append(f"{path}/{name}: required field missing")
+        for name, sub in schema.get("properties", {}).items():
+            if name in value:
+                issues.extend(schema_issues(value[name], sub, f"{path}/{name}"))
+    if isinstance(value, list) and isinstance(schema.get("items"), dict):
+        for i, item in enumerate(value):
+            issues.extend(schema_issues(item, schema["items"], f"{path}/{i}"))
+    return issues
+
+
+def _excerpt(content: bytes, limit: int = 200) -> str:
+    text = content.decode("utf-8", errors="replace")
+    return text if len(text) <= limit else text[:limit] + "..."
+
+
+async def run_examples(
+    asgi_app: Any, cases: Sequence[ExampleCase], schemas: dict[str, Any], *, validate_responses: bool = True
+) -> ExampleTestReport:
+    """Send each case's examples through asgi_app in-process and check status and response body
+    (schemas: components the response schemas reference). See Application.run_example_tests."""
+    from urich.core.openapi import resolve_refs
+    from urich.core.urls import build_url
+    from urich.testing import TestClient
+
+    client = TestClient(asgi_app, raise_server_exceptions=False)  # a handler error is a 500 entry, not a crash
+    report = ExampleTestReport()
+    for method, path, examples, responses in cases:
+        for example in examples:
+            try:
+                url = build_url(path, example.path_params)
+                response = await client.request(
+                    method, url, params=example.params, headers=example.headers, json=example.body
+                )
+            except Exception as e:
+                report.results.append(
+                    ExampleResult(method, path, example.name, None, (f"raised {type(e).__name__}: {e}",))
+                )
+                continue
+            problems: list[str] = []
+            if not example.accepts(response.status_code):
+                problems.append(
+                    f"status {response.status_code}, expected {example.expected}: {_excerpt(response.content)}"
+                )
+            elif validate_responses:
+                documented = responses.get(str(response.status_code), {}).get("content", {})
+                schema = documented.get("application/json", {}).get("schema")
+                if schema is not None:
+                    try:
+                        body = response.json()
+                    except json.JSONDecodeError:
+                        problems.append("response body is not JSON")
+                    else:
+                        problems.extend(schema_issues(body, resolve_refs(schema, schemas)))
+            report.results.append(ExampleResult(method, path, example.name, response.status_code, tuple(problems)))
+    return report
@@ -0,0 +1,90 @@
+"""Route groups: modules and routes under a shared prefix, default OpenAPI tags and group-only middlewares."""
+from __future__ import annotations
+
+import functools
+import inspect
+from typing import TYPE_CHECKING, Any, Callable
+
+from starlette.concurrency import run_in_threadpool
+from starlette.requests import Request
+from starlette.responses import Response
+
+from urich.core.middleware import Middleware
+from urich.core.module import Module
+
+if TYPE_CHECKING:
+    from urich.core.app import Application
+
+
+class RouteGroup:
+    """
+    app.group("/api/v1", tags=["v1"]).register(orders).add_middleware(require_api_key)
+    Modules register into a group exactly as into the app: add_route and mount get the prefix,
+    routes without openapi_tags get the group's tags, and everything else (container, schemas,
+    Starlette middlewares) is the application's. Group middlewares (same signature as
+    app.add_middleware) run only for the group's routes, after the global ones; a nested group's
+    middlewares run after its parent's. Routes given as ASGI apps (not functions) skip them.
+    """
+
+    def __init__(self, parent: Application | RouteGroup, prefix: str, *, tags: list[str] | None = None) -> None:
+        self._parent = parent
+        self.prefix = "/" + prefix.strip("/") if prefix.strip("/") else ""
+        self.tags = tags
+        self.middlewares: list[Middleware] = []
+
+    @property
+    def app(self) -> Application:
+        """The application the group belongs to."""
+        parent = self._parent
+        return parent.app if isinstance(parent, RouteGroup) else parent
+
+    def register(self, module: Module) -> RouteGroup:
+        """Register a module (DomainModule, HttpModule, ...) under the group. Returns self for chaining."""
+        module.register_into(self)  # type: ignore[arg-type]
+        self.app._modules.append(module)
+        return self
+
+    def group(self, prefix: str, *, tags: list[str] | None = None) -> RouteGroup:
+        """Nested group: prefixes compose, tags default to this group's."""
+        return RouteGroup(self, prefix, tags=tags if tags is not None else self.tags)
+
+    def add_middleware(self, middleware: Middleware) -> RouteGroup:
+        """async def mw(request, call_next) -> Response, for this group's routes only. Returns self."""
+        self.middlewares.append(middleware)
+        return self
+
+    def add_route(self, path: str, endpoint: Callable[..., Any], methods: list[str] | None = None, **kwargs: Any) -> None:
+        if self.tags is not None and kwargs.get("openapi_tags") is None:
+            kwargs["openapi_tags"] = self.tags
+        self._parent.add_route(self.prefix + path, self._wrap(endpoint), methods, **kwargs)
+
+    def mount(self, path: str, app: Any) -> None:
+        self._parent.mount(self.prefix + path, app)
+
+    def _wrap(self, endpoint: Callable[..., Any]) -> Callable[..., Any]:
+        if not (inspect.isfunction(endpoint) or inspect.ismethod(endpoint)):
+            return endpoint  # ASGI app
+
+        async def call_endpoint(request: Request) -> Response:
+            if inspect.iscoroutinefunction(endpoint):
+                return await endpoint(request)
+            return await run_in_threadpool(endpoint, request)
+
+        @functools.wraps(endpoint)
+        async def grouped(request: Request) -> Response:
+            # Read at request time: middlewares may be added after the routes.
+            middlewares = self.middlewares
+
+            async def run(index: int, req: Request) -> Response:
+                if index == len(middlewares):
+                    return await call_endpoint(req)
+                return await middlewares[index](req, lambda r: run(index + 1, r))
+
+            return await run(0, request)
+
+        return grouped
+
+    def __getattr__(self, name: str) -> Any:
+        if name == "_parent":
+            raise AttributeError(name)
+        return getattr(self._parent, name)
@@ -0,0 +1,258 @@
+"""
+Idempotency keys: a retried request with the same Idempotency-Key gets the stored response
+instead of running the handler again. Opt-in per route (add_route(idempotent=True),
+DomainModule.command(..., idempotent=True)); store and limits via app.idempotency(...).
+"""
+from __future__ import annotations
+
+import asyncio
+import hashlib
+import heapq
+import logging
+import time
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any, Callable, Protocol, runtime_checkable
+
+from urich.core.errors import error_response
+
+if TYPE_CHECKING:
+    from urich.core.app import Application
+
+DEFAULT_IDEMPOTENCY_TTL = 24 * 60 * 60.0
+DEFAULT_MAX_RESPONSE_SIZE = 64 * 1024
+DEFAULT_LOCK_TIMEOUT = 60.0
+DEFAULT_MAX_ENTRIES = 10_000
+MAX_KEY_LENGTH = 255
+REPLAYED_HEADER = (b"idempotency-replayed", b"true")
+
+logger = logging.getLogger("urich.idempotency")
+
+
+@dataclass
+class IdempotencyRecord:
+    """Stored outcome of one (method, route, key). status is None while the first request is executing."""
+
+    fingerprint: str
+    status: int | None = None
+    headers: list[tuple[bytes, bytes]] = field(default_factory=list)
+    body: bytes = b""
+
+    @property
+    def completed(self) -> bool:
+        return self.status is not None
+
+
+@runtime_checkable
+class IdempotencyStore(Protocol):
+    """Where records live (in memory, Redis, a database table). claim must be atomic: it stores the
+    pending record only if the key is absent and reports whether it did."""
+
+    async def get(self, key: str) -> IdempotencyRecord | None:
+        ...
+
+    async def claim(self, key: str, record: IdempotencyRecord, ttl: float) -> bool:
+        ...
+
+    async def put(self, key: str, record: IdempotencyRecord, ttl: float) -> None:
+        ...
+
+    async def release(self, key: str) -> None:
+        ...
+
+
+class InMemoryIdempotencyStore:
+    """Single-process store with per-record expiry. Expired records are dropped on every claim/put;
+    beyond max_entries the oldest records are evicted first (a retry of an evicted key runs the
+    handler again). Memory stays below max_entries x max_response_size. clock (monotonic seconds)
+    can be replaced in tests."""
+
+    def __init__(self, *, max_entries: int = DEFAULT_MAX_ENTRIES, clock: Callable[[], float] = time.monotonic) -> None:
+        if max_entries < 1:
+            raise ValueError("max_entries must be at least 1")
+        self._max_entries = max_entries
+        self._clock = clock
+        self._records: dict[str, tuple[float, IdempotencyRecord]] = {}
+        self._expiries: list[tuple[float, str]] = []  # heap; entries of replaced records are skipped
+
+    def __len__(self) -> int:
+        return len(self._records)
+
+    async def get(self, key: str) -> IdempotencyRecord | None:
+        entry = self._records.get(key)
+        if entry is None:
+            return None
+        expires_at, record = entry
+        if expires_at <= self._clock():
+            del self._records[key]
+            return None
+        return record
+
+    async def claim(self, key: str, record: IdempotencyRecord, ttl: float) -> bool:
+        self._purge()
+        if await self.get(key) is not None:
+            return False
+        self._store(key, record, ttl)
+        return True
+
+    async def put(self, key: str, record: IdempotencyRecord, ttl: float) -> None:
+        self._purge()
+        self._store(key, record, ttl)
+
+    async def release(self, key: str) -> None:
+        self._records.pop(key, None)
+
+    def _store(self, key: str, record: IdempotencyRecord, ttl: float) -> None:
+        expires_at = self._clock() + ttl
+        self._records[key] = (expires_at, record)
+        heapq.heappush(self._expiries, (expires_at, key))
+        while len(self._records) > self._max_entries:
+            del self._records[next(iter(self._records))]
+
+    def _purge(self) -> None:
+        now = self._clock()
+        while self._expiries and self._expiries[0][0] <= now:
+            expires_at, key = heapq.heappop(self._expiries)
+            entry = self._records.get(key)
+            if entry is not None and entry[0] == expires_at:
+                del self._records[key]
+        if len(self._expiries) > 2 * self._max_entries:
+            # Drop heap entries of released, evicted or replaced records.
+            self._expiries = [(e, k) for k, (e, _) in self._records.items()]
+            heapq.heapify(self._expiries)
+
+
+@dataclass
+class IdempotencySettings:
+    """app.idempotency(...) options. ttl: seconds a response is replayed; max_response_size: larger
+    response bodies are not stored (the key is released); lock_timeout: seconds a pending claim
+    blocks duplicates if the process dies mid-request."""
+
+    store: IdempotencyStore = field(default_factory=InMemoryIdempotencyStore)
+    header: bytes = b"idempotency-key"
+    ttl: float = DEFAULT_IDEMPOTENCY_TTL
+    max_response_size: int = DEFAULT_MAX_RESPONSE_SIZE
+    lock_timeout: float = DEFAULT_LOCK_TIMEOUT
+
+
+def _header(scope: dict[str, Any], name: bytes) -> str | None:
+    for key, value in scope.get("headers", []):
+        if key.lower() == name:
+            return value.decode("latin-1").strip()
+    return None
+
+
+async def _replay(record: IdempotencyRecord, send: Any) -> None:
+    await send({"type": "http.response.start", "status": record.status, "headers": record.headers + [REPLAYED_HEADER]})
+    await send({"type": "http.response.body", "body": record.body})
+
+
+class IdempotencyMiddleware:
+    """
+    ASGI middleware for idempotent routes. With an Idempotency-Key header the request is keyed by
+    method, route template and key, and fingerprinted by a hash of its body:
+    - stored response, same body: replayed as is, plus Idempotency-Replayed: true;
+    - stored response, different body: 422 IDEMPOTENCY_KEY_REUSED;
+    - first request with that key still executing: 409 IDEMPOTENCY_IN_PROGRESS (retry later);
+    - otherwise the handler runs; responses below 500 are stored, errors release the key.
+    Requests without the header run normally.
+    """
+
+    def __init__(self, app: Any, *, application: Application) -> None:
+        self.app = app
+        self._application = application
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        application = self._application
+        if scope["type"] != "http" or not application._idempotent:
+            await self.app(scope, receive, send)
+            return
+        route = application._route_for(scope)
+        if route is None or (route.path, scope["method"]) not in application._idempotent:
+            await self.app(scope, receive, send)
+            return
+        settings = application._idempotency
+        key = _header(scope, settings.header)
+        if key is None:
+            await self.app(scope, receive, send)
+            return
+        if not key or len(key) > MAX_KEY_LENGTH:
+            response = error_response(
+                400, f"Idempotency key must be 1-{MAX_KEY_LENGTH} characters", code="INVALID_IDEMPOTENCY_KEY"
+            )
+            await response(scope, receive, send)
+            return
+
+        chunks: list[bytes] = []
+        while True:
+            message = await receive()
+            if message["type"] == "http.disconnect":
+                return
+            chunks.append(message.get("body", b""))
+            if not message.get("more_body", False):
+                break
+        body = b"".join(chunks)
+        fingerprint = hashlib.sha256(body).hexdigest()
+        store_key = f"{scope['method']} {route.path} {key}"
+        store = settings.store
+
+        if not await store.claim(store_key, IdempotencyRecord(fingerprint), settings.lock_timeout):
+            existing = await store.get(store_key)
+            if existing is not None and existing.fingerprint != fingerprint:
+                response = error_response(
+                    422, "Idempotency key was already used with a different request body", code="IDEMPOTENCY_KEY_REUSED"
+                )
+            elif existing is not None and existing.completed:
+                await _replay(existing, send)
+                return
+            else:
+                response = error_response(
+                    409,
+                    "A request with this idempotency key is still being processed",
+                    code="IDEMPOTENCY_IN_PROGRESS",
+                    headers={"Retry-After": "1"},
+                )
+            await response(scope, receive, send)
+            return
+
+        replayed = False
+
+        async def replay_body() -> dict[str, Any]:
+            nonlocal replayed
+            if not replayed:
+                replayed = True
+                return {"type": "http.request", "body": body, "more_body": False}
+            return await receive()
+
+        record = IdempotencyRecord(fingerprint)
+        size = 0
+        oversized = False
+
+        async def recording_send(message: dict[str, Any]) -> None:
+            nonlocal size, oversized
+            if message["type"] == "http.response.start":
+                record.status = message["status"]
+                record.headers = list(message.get("headers", []))
+            elif message["type"] == "http.response.body" and not oversized:
+                chunk = message.get("body", b"")
+                size += len(chunk)
+                if size > settings.max_response_size:
+                    oversized = True
+                    record.body = b""
+                else:
+                    record.body += chunk
+            await send(message)
+
+        try:
+            await self.app(scope, replay_body, recording_send)
+        except BaseException:
+            await asyncio.shield(store.release(store_key))
+            raise
+        if record.status is None or record.status >= 500 or oversized:
+            if oversized:
+                logger.warning(
+                    "Response of %s %s exceeds %s bytes; idempotency key not stored",
+                    scope["method"], route.path, settings.max_response_size,
+                )
+            await store.release(store_key)
+            return
+        await store.put(store_key, record, settings.ttl)
@@ -0,0 +1,155 @@
+"""Merge two independently built Applications into one process (Application.merge)."""
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+from starlette.routing import Route
+
+from urich.core.schema_hash import canonical_json
+
+if TYPE_CHECKING:
+    from urich.core.app import Application
+
+
+class MergeError(Exception):
+    """Applications cannot be merged: route or container collisions (all listed in .collisions)."""
+
+    def __init__(self, collisions: list[str]) -> None:
+        self.collisions = collisions
+        super().__init__("Cannot merge applications:\n" + "\n".join(f"  - {c}" for c in collisions))
+
+
+def _endpoint_name(route: Route) -> str:
+    endpoint = route.endpoint
+    return getattr(endpoint, "__qualname__", None) or type(endpoint).__name__
+
+
+def _route_collisions(host: Application, other: Application) -> list[str]:
+    host_routes: dict[tuple[str, str], Route] = {}
+    for route in host.starlette.routes:
+        if isinstance(route, Route):
+            for method in route.methods or ["GET"]:
+                host_routes[(route.path, method)] = route
+    out: list[str] = []
+    for route in other.starlette.routes:
+        if not isinstance(route, Route):
+            continue
+        for method in sorted(route.methods or ["GET"]):
+            if method == "HEAD":
+                continue
+            existing = host_routes.get((route.path, method))
+            if existing is not None:
+                out.append(
+                    f"route {method} {route.path}: host {_endpoint_name(existing)} vs merged {_endpoint_name(route)}"
+                )
+    return out
+
+
+def _container_collisions(host: Application, other: Application, skip: set[Any]) -> list[str]:
+    out: list[str] = []
+    host_c, other_c = host.container, other.container
+    for key in other_c._registry:
+        if key in skip or key not in host_c._registry:
+            continue
+        same = (
+            host_c._registry[key] is other_c._registry[key]
+            or (
+                host_c._singletons.get(key) is not None
+                and host_c._singletons.get(key) is other_c._singletons.get(key)
+            )
+        )
+        if not same:
+            out.append(f"container key {getattr(key, '__qualname__', key)!r} registered in both applications")
+    return out
+
+
+def merge_applications(host: Application, other: Application) -> Application:
+    """
+    Add other's routes, container entries, event subscriptions, OpenAPI metadata and lifespan
+    hooks to host. Other's routes are dispatched through other's own Starlette app, so its
+    middlewares apply only to its own routes. Raises MergeError listing every collision.
+    """
+    from urich.domain.events import EventBus, InProcessEventDispatcher
+    from urich.events.topic_hub import TopicHub
+
+    def _bus(app: Application) -> Any:
+        try:
+            return app.container.resolve(EventBus)
+        except KeyError:
+            return None
+
+    host_bus, other_bus = _bus(host), _bus(other)
+    shared_bus = isinstance(host_bus, InProcessEventDispatcher) and isinstance(other_bus, InProcessEventDispatcher)
+    skip: set[Any] = {TopicHub}
+    if shared_bus:
+        skip |= {EventBus, InProcessEventDispatcher}
+
+    collisions = _route_collisions(host, other) + _container_collisions(host, other, skip)
+    collisions += [
+        f"route name {name!r} registered in both applications"
+        for name in other._route_names
+        if name in host._route_names
+    ]
+    collisions += [
+        f"OpenAPI operationId {op_id!r}: host {host._operation_ids[op_id]} vs merged {owner}"
+        for op_id, owner in other._operation_ids.items()
+        if op_id in host._operation_ids and host._operation_ids[op_id] != owner
+    ]
+    collisions += [
+        f"OpenAPI schema {name!r} registered in both applications with different content"
+        for name, schema in other._schemas.items()
+        if name in host._schemas and canonical_json(host._schemas[name]) != canonical_json(schema)
+    ]
+    if collisions:
+        raise MergeError(collisions)
+
+    # Routes: same paths/methods, handled by other's app (its middleware stack included).
+    for route in other.starlette.routes:
+        if isinstance(route, Route):
+            host.starlette.routes.append(
+                Route(route.path, other.starlette, methods=list(route.methods or ["GET"]), name=route.name)
+            )
+        else:
+            host.starlette.routes.append(route)
+    host._routes_changed()
+    host._route_names.update(other._route_names)
+    host._route_schemas.update(other._route_schemas)
+    host._schema_hashes.update(other._schema_hashes)
+    host._body_limits.update(other._body_limits)
+    host._timeouts.update(other._timeouts)
+    host._route_validation.update(other._route_validation)
+    host._examples.update(other._examples)
+    host._operation_ids.update(other._operation_ids)
+    host._schemas.update(other._schemas)
+    host._rpc_methods.update(other._rpc_methods)
+    host._command_handlers.update(other._command_handlers)
+    host._published_events.update(other._published_events)
+    for name, scheme in other._security_schemes.items():
+        host._security_schemes.setdefault(name, scheme)
+    host._public_routes.update(other._public_routes)
+
+    # Events: one in-process bus so either app's publish reaches both apps' subscribers.
+    if shared_bus and host_bus is not other_bus:
+        for event_type, handlers in other_bus._handlers.items():
+            for handler in handlers:
+                host_bus.subscribe(event_type, handler)
+        other.container.register_instance(EventBus, host_bus)
+        other.container.register_instance(InProcessEventDispatcher, host_bus)
+
+    other.container.register_instance(TopicHub, host.container.resolve(TopicHub))
+
+    # Container: other's entries resolve through other's container (shared singletons).
+    for key in other.container._registry:
+        if key in skip or key in host.container._registry:
+            continue
+        host.container.register(key, lambda k=key: other.container.resolve(k), singleton=False)
+
+    # Lifespan hooks from both apps run.
+    host_router, other_router = host.starlette.router, other.starlette.router
+    for attr in ("on_startup", "on_shutdown"):
+        hooks = getattr(other_router, attr, None)
+        if hooks:
+            getattr(host_router, attr).extend(hooks)
+
+    host._modules.extend(other._modules)
+    return host
@@ -0,0 +1,42 @@
+"""Function middlewares around the handler: async def mw(request, call_next) -> Response."""
+from __future__ import annotations
+
+from typing import Any, Awaitable, Callable
+
+from starlette.middleware.base import BaseHTTPMiddleware
+from starlette.requests import Request
+from starlette.responses import Response
+
+CallNext = Callable[[Request], Awaitable[Response]]
+Middleware = Callable[[Request, CallNext], Awaitable[Response]]
+
+
+class MiddlewareChain:
+    """
+    Middlewares in registration order: the first registered runs first on the way in and
+    last on the way out. Each may short-circuit (return without call_next), or inspect,
+    mutate or replace the response returned by call_next. Requests to exempt_paths
+    (e.g. health probes) bypass the chain.
+    """
+
+    def __init__(self, exempt_paths: set[str] | None = None) -> None:
+        self.middlewares: list[Middleware] = []
+        self.exempt_paths = exempt_paths if exempt_paths is not None else set()
+
+    async def dispatch(self, request: Request, call_next: CallNext) -> Response:
+        if request.url.path in self.exempt_paths:
+            return await call_next(request)
+
+        async def run(index: int, req: Request) -> Response:
+            if index == len(self.middlewares):
+                return await call_next(req)
+            return await self.middlewares[index](req, lambda r: run(index + 1, r))
+
+        return await run(0, request)
+
+
+def install_chain(starlette: Any, exempt_paths: set[str] | None = None) -> MiddlewareChain:
+    """One Starlette middleware that runs the whole chain."""
+    chain = MiddlewareChain(exempt_paths)
+    starlette.add_middleware(BaseHTTPMiddleware, dispatch=chain.dispatch)
+    return chain
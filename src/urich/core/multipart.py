@@ -0,0 +1,116 @@
+"""
+multipart/form-data bodies: text fields and uploaded files. Buffered: the whole body is read
+(bounded by the route's max_body_size) and each file is capped at max_file_size.
+"""
+from __future__ import annotations
+
+import dataclasses
+import re
+from dataclasses import dataclass
+
+from urich.core.errors import HttpError
+
+DEFAULT_MAX_FILE_SIZE = 10 * 1024 * 1024
+MULTIPART = "multipart/form-data"
+
+_PARAM = re.compile(r';\s*([A-Za-z0-9_*.-]+)\s*=\s*("(?:[^"\\]|\\.)*"|[^;]*)')
+
+
+@dataclass
+class UploadedFile:
+    """A file part: form field name, client-side filename, declared content type and the bytes.
+    Declare a command field as UploadedFile (or list[UploadedFile]) to receive it."""
+
+    field: str
+    filename: str
+    content_type: str
+    data: bytes = dataclasses.field(repr=False)
+
+    @property
+    def size(self) -> int:
+        return len(self.data)
+
+    def text(self, encoding: str = "utf-8") -> str:
+        return self.data.decode(encoding)
+
+
+@dataclass
+class MultipartForm:
+    """Parsed body: text fields as (name, value) pairs in body order, and the files."""
+
+    fields: list[tuple[str, str]]
+    files: list[UploadedFile]
+
+
+def is_multipart(content_type: str | None) -> bool:
+    return (content_type or "").split(";", 1)[0].strip().lower() == MULTIPART
+
+
+def _params(header: str) -> dict[str, str]:
+    """Parameters of a header value: 'form-data; name="a"; filename="b.txt"' -> {name: a, filename: b.txt}."""
+    out: dict[str, str] = {}
+    for key, value in _PARAM.findall(header):
+        value = value.strip()
+        if value.startswith('"') and value.endswith('"'):
+            value = re.sub(r"\\(.)", r"\1", value[1:-1])
+        out[key.lower()] = value
+    return out
+
+
+def _malformed(reason: str) -> HttpError:
+    return HttpError(400, f"Malformed multipart body: {reason}", code="INVALID_MULTIPART")
+
+
+def parse_multipart(
+    body: bytes, content_type: str, *, max_file_size: int | None = DEFAULT_MAX_FILE_SIZE
+) -> MultipartForm:
+    """
+    Split a multipart/form-data body into fields and files. 400 INVALID_MULTIPART for a missing
+    boundary, a body without the closing delimiter or a part without a name; 413 PAYLOAD_TOO_LARGE
+    for a file over max_file_size (None = no per-file limit). A file input left empty by the browser
+    (no filename, no content) is skipped.
+    """
+    boundary = _params(content_type).get("boundary")
+    if not boundary or len(boundary) > 200:
+        raise _malformed("missing or invalid boundary")
+    # A delimiter is CRLF "--" boundary; the CRLF before it belongs to the delimiter, not the content.
+    chunks = (b"\r\n" + body).split(b"\r\n--" + boundary.encode("latin-1"))
+    if len(chunks) < 2 or not chunks[-1].startswith(b"--"):
+        raise _malformed("closing boundary not found")
+
+    fields: list[tuple[str, str]] = []
+    files: list[UploadedFile] = []
+    for chunk in chunks[1:-1]:
+        if not chunk.startswith(b"\r\n"):
+            raise _malformed("part not delimited by CRLF")
+        head, sep, content = chunk[2:].partition(b"\r\n\r\n")
+        if not sep:
+            raise _malformed("part headers not terminated")
+        headers: dict[str, str] = {}
+        for line in head.decode("latin-1").split("\r\n"):
+            if line:
+                name, colon, value = line.partition(":")
+                if not colon:
+                    raise _malformed(f"invalid part header {line!r}")
+                headers[name.strip().lower()] = value.strip()
+        disposition = headers.get("content-disposition", "")
+        params = _params(";" + disposition.partition(";")[2])
+        name = params.get("name")
+        if not disposition.lower().startswith("form-data") or not name:
+            raise _malformed("part without form-data name")
+        if "filename" in params:
+            if not params["filename"] and not content:
+                continue
+            if max_file_size is not None and len(content) > max_file_size:
+                raise HttpError(
+                    413, f"File {params['filename']!r} exceeds {max_file_size} bytes", code="PAYLOAD_TOO_LARGE"
+                )
+            part_type = headers.get("content-type", "application/octet-stream")
+            files.append(UploadedFile(name, params["filename"], part_type, content))
+        else:
+            charset = _params(";" + headers.get("content-type", "").partition(";")[2]).get("charset", "utf-8")
+            try:
+                fields.append((name, content.decode(charset)))
+            except (LookupError, UnicodeDecodeError):
+                raise _malformed(f"field {name!r} is not valid {charset}")
+    return MultipartForm(fields, files)
@@ -2,17 +2,19 @@
 from __future__ import annotations
 
 import dataclasses
-from typing import Any
+import hashlib
+import re
+import typing
+from http import HTTPStatus
+from typing import Any, Sequence
 
 # (path, method) -> OpenAPI request body schema or parameters
 RouteSchemas = dict[tuple[str, str], dict[str, Any]]
 
 
 def _path_to_openapi(path: str) -> str:
-    """Convert Starlette path to OpenAPI path (e.g. {path:path} -> {path})."""
-    if "{path:path}" in path:
-        return path.replace("{path:path}", "{path}")
-    return path
+    """Convert Starlette path to OpenAPI path (e.g. {path:path} -> {path}, {id:int} -> {id})."""
+    return re.sub(r"\{([a-zA-Z_][a-zA-Z0-9_]*):[a-zA-Z_]+\}", r"{\1}", path)
 
 
 def _py_type_to_json_type(t: type) -> str:
@@ -29,15 +31,70 @@ def _py_type_to_json_type(t: type) -> str:
     return "string"
 
 
+def _upload_schema(t: Any) -> dict[str, Any] | None:
+    """Binary string schema for UploadedFile, array of them for list[UploadedFile]; else None."""
+    from urich.core.multipart import UploadedFile
+
+    if t is UploadedFile:
+        return {"type": "string", "format": "binary"}
+    args = getattr(t, "__args__", ())
+    if getattr(t, "__origin__", None) is list and args and args[0] is UploadedFile:
+        return {"type": "array", "items": {"type": "string", "format": "binary"}}
+    if UploadedFile in args:  # Optional[UploadedFile]
+        return {"type": "string", "format": "binary"}
+    return None
+
+
+def _int_ranges(cls: type) -> dict[str, dict[str, Any]]:
+    """format / minimum / maximum of Int32, Int64, UInt64 (IntRange) fields, by field name."""
+    from urich.core.validation import int_range
+
+    try:
+        hints = typing.get_type_hints(cls, include_extras=True)
+    except Exception:
+        return {}
+    ranges: dict[str, dict[str, Any]] = {}
+    for name, tp in hints.items():
+        bounds = int_range(tp)
+        if bounds is not None:
+            ranges[name] = {
+                "type": "integer", "format": bounds.name, "minimum": bounds.minimum, "maximum": bounds.maximum
+            }
+    return ranges
+
+
+def has_uploads(cls: type) -> bool:
+    """True if the dataclass has UploadedFile fields (its request body is multipart/form-data)."""
+    if not dataclasses.is_dataclass(cls):
+        return False
+    try:
+        hints = typing.get_type_hints(cls)
+    except Exception:
+        return False
+    return any(_upload_schema(hints.get(f.name)) is not None for f in dataclasses.fields(cls))
+
+
 def schema_from_dataclass(cls: type) -> dict[str, Any]:
-    """Build JSON schema from a dataclass so Swagger shows required fields and types."""
+    """Build JSON schema from a dataclass so Swagger shows required fields and types
+    (UploadedFile fields as binary strings)."""
     if not dataclasses.is_dataclass(cls):
         return {"type": "object"}
+    try:
+        hints = typing.get_type_hints(cls)
+    except Exception:
+        hints = {}
+    ranges = _int_ranges(cls)
     props: dict[str, Any] = {}
     required: list[str] = []
     for f in dataclasses.fields(cls):
         if f.name.startswith("_"):
             continue
+        upload = _upload_schema(hints.get(f.name))
+        if upload is not None:
+            props[f.name] = {**upload, "description": f.name.replace("_", " ")}
+            if f.default is dataclasses.MISSING and f.default_factory is dataclasses.MISSING:
+                required.append(f.name)
+            continue
         t = f.type
         try:
             if getattr(t, "__origin__", None) is type(None) or (getattr(t, "__args__", ()) and type(None) in getattr(t, "__args__", ())):
@@ -46,7 +103,11 @@ def schema_from_dataclass(cls: type) -> dict[str, Any]:
                 t = getattr(t, "__args__", (str,))[0]
         except (IndexError, TypeError):
             pass
-        props[f.name] = {"type": _py_type_to_json_type(t), "description": f.name.replace("_", " ")}
+        props[f.name] = {
+            "type": _py_type_to_json_type(t),
+            **ranges.get(f.name, {}),
+            "description": f.name.replace("_", " "),
+        }
         if f.default is dataclasses.MISSING and f.default_factory is dataclasses.MISSING:
             required.append(f.name)
     return {"type": "object", "properties": props, "required": required}
@@ -56,6 +117,7 @@ def parameters_from_dataclass(cls: type) -> list[dict[str, Any]]:
     """Build OpenAPI query parameters from a dataclass (for GET queries)."""
     if not dataclasses.is_dataclass(cls):
         return []
+    ranges = _int_ranges(cls)
     params: list[dict[str, Any]] = []
     for f in dataclasses.fields(cls):
         if f.name.startswith("_"):
@@ -70,12 +132,77 @@ def parameters_from_dataclass(cls: type) -> list[dict[str, Any]]:
             "name": f.name,
             "in": "query",
             "required": f.default is dataclasses.MISSING and f.default_factory is dataclasses.MISSING,
-            "schema": {"type": _py_type_to_json_type(t)},
+            "schema": ranges.get(f.name) or {"type": _py_type_to_json_type(t)},
         }
         params.append(param)
     return params
 
 
+def response_object(
+    status: int, schema: dict[str, Any] | None, media_type: str = "application/json"
+) -> dict[str, Any]:
+    """OpenAPI responses object with one response: {"201": {"description": "Created", "content": ...}}.
+    204 or no schema: description only (no body)."""
+    try:
+        description = HTTPStatus(status).phrase
+    except ValueError:
+        description = "Response"
+    if status == 204 or schema is None:
+        return {str(status): {"description": description}}
+    return {str(status): {"description": description, "content": {media_type: {"schema": schema}}}}
+
+
+def command_response_schema(result_schema: dict[str, Any]) -> dict[str, Any]:
+    """Schema of the command envelope {"ok": true, "result": ...} around result_schema."""
+    return {
+        "type": "object",
+        "properties": {"ok": {"type": "boolean"}, "result": result_schema},
+        "required": ["ok"],
+    }
+
+
+REF_PREFIX = "#/components/schemas/"
+
+
+def schema_ref(name: str) -> dict[str, Any]:
+    """{"$ref": "#/components/schemas/<name>"} for a schema registered under name."""
+    return {"$ref": REF_PREFIX + name}
+
+
+def resolve_refs(schema: Any, schemas: dict[str, Any], _seen: frozenset[str] = frozenset()) -> Any:
+    """schema with component $refs replaced by their content (recursive refs are left as $ref)."""
+    if isinstance(schema, list):
+        return [resolve_refs(item, schemas, _seen) for item in schema]
+    if not isinstance(schema, dict):
+        return schema
+    ref = schema.get("$ref")
+    if isinstance(ref, str) and ref.startswith(REF_PREFIX):
+        name = ref[len(REF_PREFIX):]
+        if name in schemas and name not in _seen:
+            return resolve_refs(schemas[name], schemas, _seen | {name})
+        return schema
+    return {key: resolve_refs(value, schemas, _seen) for key, value in schema.items()}
+
+
+def pydantic_schemas(model: type) -> tuple[dict[str, Any], dict[str, Any]]:
+    """(schema, nested model schemas) of a pydantic model, nested models referenced as components."""
+    schema = model.model_json_schema(ref_template=REF_PREFIX + "{model}")  # type: ignore[attr-defined]
+    return schema, schema.pop("$defs", {})
+
+
+def spec_etag(body: bytes) -> str:
+    """Strong ETag of a serialized spec."""
+    return '"' + hashlib.sha256(body).hexdigest()[:32] + '"'
+
+
+def etag_matches(if_none_match: str | None, etag: str) -> bool:
+    """If-None-Match against etag: "*" or any listed tag, weak (W/) tags compared by value."""
+    if not if_none_match:
+        return False
+    tags = [t.strip() for t in if_none_match.split(",")]
+    return "*" in tags or any(t.removeprefix("W/") == etag for t in tags)
+
+
 def build_openapi_spec(
     routes: list[Any],
     *,
@@ -84,9 +211,13 @@ def build_openapi_spec(
     route_schemas: RouteSchemas | None = None,
     security_schemes: dict[str, Any] | None = None,
     global_security: list[dict[str, Any]] | None = None,
+    public_prefixes: Sequence[str] = (),
+    schemas: dict[str, Any] | None = None,
 ) -> dict[str, Any]:
     """Build OpenAPI 3.0 spec from Starlette routes and optional per-route request schemas.
-    security_schemes → components.securitySchemes; global_security → spec.security and default for each operation.
+    security_schemes → components.securitySchemes; global_security → spec.security and default for each operation;
+    operations under public_prefixes without their own security get an empty array (no credentials);
+    schemas (name → schema, referenced as {"$ref": "#/components/schemas/<name>"}) → components.schemas.
     """
     from starlette.routing import Route
 
@@ -106,21 +237,32 @@ def build_openapi_spec(
                     "200": {"description": "OK", "content": {"application/json": {"schema": {"type": "object"}}}},
                 },
             }
-            key = (path, method_lower)
+            key = (route.path, method_lower)
             if key in route_schemas:
                 schema = route_schemas[key]
                 if "requestBody" in schema:
                     op["requestBody"] = schema["requestBody"]
+                    if "examples" in schema:
+                        body = schema["requestBody"]
+                        content = {m: {**c, "examples": schema["examples"]} for m, c in body["content"].items()}
+                        op["requestBody"] = {**body, "content": content}
                 if "parameters" in schema:
                     op["parameters"] = schema["parameters"]
                 if "tags" in schema:
                     op["tags"] = schema["tags"]
                 if "security" in schema:
                     op["security"] = schema["security"]
+                if "responses" in schema:
+                    op["responses"] = schema["responses"]
+                for field in ("operationId", "summary", "description"):
+                    if field in schema:
+                        op[field] = schema[field]
+                op.update((k, v) for k, v in schema.items() if k.startswith("x-"))
             if "tags" not in op:
                 op["tags"] = ["default"]
             if global_security is not None and "security" not in op:
-                op["security"] = global_security
+                public = any(route.path == p or route.path.startswith(p.rstrip("/") + "/") for p in public_prefixes)
+                op["security"] = [] if public else global_security
             if method_lower == "post" and "/commands/" in path and "requestBody" not in op:
                 op["requestBody"] = {
                     "required": True,
@@ -129,13 +271,20 @@ def build_openapi_spec(
             elif method_lower == "get" and "/queries/" in path and "parameters" not in op:
                 op["parameters"] = [{"name": "query params", "in": "query", "schema": {"type": "object"}}]
             paths[path][method_lower] = op
+    # Sorted so the spec doesn't depend on registration order (stable bytes for ETags and diffs).
+    paths = {path: dict(sorted(ops.items())) for path, ops in sorted(paths.items())}
     spec: dict[str, Any] = {
         "openapi": "3.0.0",
         "info": {"title": title, "version": version},
         "paths": paths,
     }
+    components: dict[str, Any] = {}
+    if schemas:
+        components["schemas"] = dict(sorted(schemas.items()))
     if security_schemes:
-        spec["components"] = {"securitySchemes": security_schemes}
+        components["securitySchemes"] = security_schemes
+    if components:
+        spec["components"] = components
     if global_security is not None:
         spec["security"] = global_security
     return spec
@@ -16,17 +16,24 @@ class HttpModule(Module):
     def __init__(self, name: str, prefix: str | None = None) -> None:
         self.name = name
         self.prefix = prefix or f"/{name}"
-        self._routes: list[tuple[str, Any, list[str]]] = []
+        self._routes: list[tuple[str, Any, list[str], str | None]] = []
 
-    def route(self, path: str, endpoint: Callable[..., Any], methods: list[str] | None = None) -> HttpModule:
-        """Add a route. path without leading slash is under the module prefix."""
+    def route(
+        self,
+        path: str,
+        endpoint: Callable[..., Any],
+        methods: list[str] | None = None,
+        *,
+        name: str | None = None,
+    ) -> HttpModule:
+        """Add a route. path without leading slash is under the module prefix. name: for app.url_for()."""
         if methods is None:
             methods = ["GET"]
         p = path if path.startswith("/") else f"/{path}"
-        self._routes.append((p, endpoint, methods))
+        self._routes.append((p, endpoint, methods, name))
         return self
 
     def register_into(self, app: Application) -> None:
-        for path, endpoint, methods in self._routes:
+        for path, endpoint, methods, name in self._routes:
             full_path = self.prefix.rstrip("/") + path
-            app.add_route(full_path, endpoint, methods)
+            app.add_route(full_path, endpoint, methods, name=name)
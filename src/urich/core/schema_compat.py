@@ -0,0 +1,256 @@
+"""JSON Schema diff: classify changes between two schema versions as reader/writer compatible."""
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import Any
+
+# Writers produce data against the schema (clients sending a command),
+# readers consume it (the handler). A change can break either side or both.
+
+_NUMERIC_BOUNDS = {
+    # keyword -> True if a larger value is looser (accepts more data)
+    "maximum": True,
+    "exclusiveMaximum": True,
+    "maxLength": True,
+    "maxItems": True,
+    "maxProperties": True,
+    "minimum": False,
+    "exclusiveMinimum": False,
+    "minLength": False,
+    "minItems": False,
+    "minProperties": False,
+}
+
+
+@dataclass
+class SchemaChange:
+    """One difference between old and new schema at a JSON path (e.g. $.items[].sku)."""
+
+    path: str
+    message: str
+    breaks_readers: bool = False
+    breaks_writers: bool = False
+
+    @property
+    def level(self) -> str:
+        if self.breaks_readers and self.breaks_writers:
+            return "breaking"
+        if self.breaks_writers:
+            return "compatible_for_readers"
+        if self.breaks_readers:
+            return "compatible_for_writers"
+        return "compatible"
+
+
+@dataclass
+class CompatReport:
+    """All changes found by schema_compat; level summarizes them."""
+
+    changes: list[SchemaChange] = field(default_factory=list)
+
+    @property
+    def compatible_for_readers(self) -> bool:
+        return not any(c.breaks_readers for c in self.changes)
+
+    @property
+    def compatible_for_writers(self) -> bool:
+        return not any(c.breaks_writers for c in self.changes)
+
+    @property
+    def level(self) -> str:
+        """compatible | compatible_for_readers | compatible_for_writers | breaking."""
+        readers, writers = self.compatible_for_readers, self.compatible_for_writers
+        if readers and writers:
+            return "compatible"
+        if readers:
+            return "compatible_for_readers"
+        if writers:
+            return "compatible_for_writers"
+        return "breaking"
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "level": self.level,
+            "changes": [
+                {
+                    "path": c.path,
+                    "message": c.message,
+                    "level": c.level,
+                    "breaks_readers": c.breaks_readers,
+                    "breaks_writers": c.breaks_writers,
+                }
+                for c in self.changes
+            ],
+        }
+
+
+def _deref(schema: Any, components: dict[str, Any], seen: frozenset[str]) -> tuple[Any, frozenset[str]]:
+    """Follow $ref (#/components/schemas/Name or plain Name) against the components registry."""
+    while isinstance(schema, dict) and "$ref" in schema:
+        ref = schema["$ref"]
+        if ref in seen:
+            return {}, seen
+        seen = seen | {ref}
+        name = ref.rsplit("/", 1)[-1]
+        schema = components.get(name, {})
+    return schema, seen
+
+
+def _types(schema: dict[str, Any]) -> set[str] | None:
+    t = schema.get("type")
+    if t is None:
+        return None
+    types = {t} if isinstance(t, str) else set(t)
+    if schema.get("nullable"):
+        types.add("null")
+    return types
+
+
+def _covers(outer: set[str], inner: set[str]) -> bool:
+    """True if every type in inner is accepted by outer (integer is a subset of number)."""
+    return all(t in outer or (t == "integer" and "number" in outer) for t in inner)
+
+
+# (old, new): each side's $refs resolve against its own components and track their own cycles.
+_Registries = tuple[dict[str, Any], dict[str, Any]]
+_Seen = tuple[frozenset[str], frozenset[str]]
+
+
+def _compare(
+    old: Any,
+    new: Any,
+    path: str,
+    components: _Registries,
+    seen: _Seen,
+    out: list[SchemaChange],
+) -> None:
+    old, seen_old = _deref(old, components[0], seen[0])
+    new, seen_new = _deref(new, components[1], seen[1])
+    seen = (seen_old, seen_new)
+    if not isinstance(old, dict) or not isinstance(new, dict):
+        return
+
+    old_types, new_types = _types(old), _types(new)
+    if old_types != new_types:
+        if old_types is None:
+            out.append(SchemaChange(path, f"type restricted to {sorted(new_types or [])}", breaks_writers=True))
+        elif new_types is None:
+            out.append(SchemaChange(path, f"type restriction {sorted(old_types)} removed", breaks_readers=True))
+        elif _covers(old_types, new_types):
+            out.append(SchemaChange(path, f"type narrowed {sorted(old_types)} -> {sorted(new_types)}", breaks_writers=True))
+        elif _covers(new_types, old_types):
+            out.append(SchemaChange(path, f"type widened {sorted(old_types)} -> {sorted(new_types)}", breaks_readers=True))
+        else:
+            out.append(
+                SchemaChange(
+                    path,
+                    f"type changed {sorted(old_types)} -> {sorted(new_types)}",
+                    breaks_readers=True,
+                    breaks_writers=True,
+                )
+            )
+
+    _compare_enum(old, new, path, out)
+    _compare_bounds(old, new, path, out)
+    _compare_properties(old, new, path, components, seen, out)
+
+    if "items" in old or "items" in new:
+        _compare(old.get("items", {}), new.get("items", {}), f"{path}[]", components, seen, out)
+
+
+def _compare_enum(old: dict[str, Any], new: dict[str, Any], path: str, out: list[SchemaChange]) -> None:
+    if "enum" not in old and "enum" not in new:
+        return
+    if "enum" not in old:
+        out.append(SchemaChange(path, "enum introduced", breaks_writers=True))
+        return
+    if "enum" not in new:
+        out.append(SchemaChange(path, "enum removed", breaks_readers=True))
+        return
+    added = [v for v in new["enum"] if v not in old["enum"]]
+    removed = [v for v in old["enum"] if v not in new["enum"]]
+    if added:
+        out.append(SchemaChange(path, f"enum widened with {added}", breaks_readers=True))
+    if removed:
+        out.append(SchemaChange(path, f"enum narrowed, removed {removed}", breaks_writers=True))
+
+
+def _compare_bounds(old: dict[str, Any], new: dict[str, Any], path: str, out: list[SchemaChange]) -> None:
+    for key, larger_is_looser in _NUMERIC_BOUNDS.items():
+        o, n = old.get(key), new.get(key)
+        if o == n or isinstance(o, bool) or isinstance(n, bool):
+            continue
+        if o is None:
+            out.append(SchemaChange(path, f"{key} {n} added", breaks_writers=True))
+        elif n is None:
+            out.append(SchemaChange(path, f"{key} {o} removed", breaks_readers=True))
+        elif (n > o) == larger_is_looser:
+            out.append(SchemaChange(path, f"{key} relaxed {o} -> {n}", breaks_readers=True))
+        else:
+            out.append(SchemaChange(path, f"{key} tightened {o} -> {n}", breaks_writers=True))
+
+
+def _compare_properties(
+    old: dict[str, Any],
+    new: dict[str, Any],
+    path: str,
+    components: _Registries,
+    seen: _Seen,
+    out: list[SchemaChange],
+) -> None:
+    old_props: dict[str, Any] = old.get("properties") or {}
+    new_props: dict[str, Any] = new.get("properties") or {}
+    old_req = set(old.get("required") or [])
+    new_req = set(new.get("required") or [])
+
+    for name in old_props:
+        if name not in new_props:
+            out.append(SchemaChange(f"{path}.{name}", "field removed", breaks_readers=True))
+    for name in new_props:
+        if name not in old_props:
+            if name in new_req:
+                out.append(SchemaChange(f"{path}.{name}", "required field added", breaks_writers=True))
+            else:
+                out.append(SchemaChange(f"{path}.{name}", "optional field added"))
+    for name in old_props:
+        if name not in new_props:
+            continue
+        child = f"{path}.{name}"
+        if name in new_req and name not in old_req:
+            out.append(SchemaChange(child, "field became required", breaks_writers=True))
+        elif name in old_req and name not in new_req:
+            out.append(SchemaChange(child, "field became optional", breaks_readers=True))
+        _compare(old_props[name], new_props[name], child, components, seen, out)
+
+    old_extra = old.get("additionalProperties", True)
+    new_extra = new.get("additionalProperties", True)
+    if old_extra is not False and new_extra is False:
+        out.append(SchemaChange(path, "additional properties disallowed", breaks_writers=True))
+    elif old_extra is False and new_extra is not False:
+        out.append(SchemaChange(path, "additional properties allowed", breaks_readers=True))
+
+
+def schema_compat(
+    old: dict[str, Any],
+    new: dict[str, Any],
+    *,
+    components: dict[str, Any] | None = None,
+    old_components: dict[str, Any] | None = None,
+    new_components: dict[str, Any] | None = None,
+) -> CompatReport:
+    """
+    Compare two JSON Schemas and classify each change.
+    $refs (#/components/schemas/Name or Name) in old resolve against old_components and in new
+    against new_components (e.g. each document's components.schemas); components is the registry
+    for a side given none, e.g. one registry shared by two versions of a payload.
+    Added required field breaks writers, removed field breaks readers, widened enum
+    breaks readers only, narrowed type breaks writers; unrelated type change breaks both.
+    """
+    shared = components or {}
+    registries = (
+        old_components if old_components is not None else shared,
+        new_components if new_components is not None else shared,
+    )
+    changes: list[SchemaChange] = []
+    _compare(old, new, "$", registries, (frozenset(), frozenset()), changes)
+    return CompatReport(changes)
@@ -0,0 +1,105 @@
+"""Per-route schema hashes: cheap contract-drift detection for clients (X-Schema-Hash)."""
+from __future__ import annotations
+
+import hashlib
+import json
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from urich.core.app import Application
+
+HASH_HEADER = "x-schema-hash"
+EXPECTED_HASH_HEADER = "x-expected-schema-hash"
+DRIFT_HEADER = "x-schema-drift"
+
+
+def canonical_json(value: Any) -> str:
+    """Deterministic JSON: sorted keys, no whitespace. Same input -> same string across processes."""
+    return json.dumps(value, sort_keys=True, separators=(",", ":"), ensure_ascii=False, default=str)
+
+
+def route_schema_hash(method: str, path: str, schema: dict[str, Any]) -> str:
+    """Stable hash of method + path + request/response schema of one operation."""
+    doc = {
+        "method": method.upper(),
+        "path": path,
+        "request": {k: schema[k] for k in ("requestBody", "parameters") if k in schema},
+        "responses": schema.get("responses"),
+    }
+    return hashlib.sha256(canonical_json(doc).encode()).hexdigest()
+
+
+class SchemaHashMiddleware:
+    """
+    ASGI middleware: adds X-Schema-Hash to responses of routes with a known hash.
+    If the client sends X-Expected-Schema-Hash and it differs, adds X-Schema-Drift: true,
+    or answers 412 in strict mode (handler is not called).
+    """
+
+    def __init__(self, app: Any, *, application: Application, strict: bool = False) -> None:
+        self.app = app
+        self._application = application
+        self._strict = strict
+        self._routes: list[tuple[Any, dict[str, str]]] = []  # (route, METHOD -> hash), in routing order
+        self._routes_version = -1  # application.routes_version _routes was built for
+
+    def _route_hashes(self) -> list[tuple[Any, dict[str, str]]]:
+        """Routes with their hashes, rebuilt whenever the application's route table changes."""
+        from starlette.routing import Route
+
+        version = self._application.routes_version
+        if version != self._routes_version:
+            routes = self._application.starlette.routes
+            hashes = self._application.route_schema_hashes()
+            self._routes = [
+                (route, {m: hashes[f"{m} {route.path}"] for m in route.methods or [] if f"{m} {route.path}" in hashes})
+                for route in routes
+                if isinstance(route, Route)
+            ]
+            self._routes_version = version
+        return self._routes
+
+    def _route_hash(self, scope: dict[str, Any]) -> str | None:
+        from starlette.routing import Match
+
+        for route, hashes in self._route_hashes():
+            if route.matches(scope)[0] == Match.FULL:
+                return hashes.get(scope["method"].upper())
+        return None
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        if scope["type"] != "http":
+            await self.app(scope, receive, send)
+            return
+        current = self._route_hash(scope)
+        if current is None:
+            await self.app(scope, receive, send)
+            return
+
+        expected = None
+        for name, value in scope.get("headers", []):
+            if name.decode("latin-1").lower() == EXPECTED_HASH_HEADER:
+                expected = value.decode("latin-1")
+        drift = expected is not None and expected != current
+
+        if drift and self._strict:
+            from starlette.responses import JSONResponse
+
+            response = JSONResponse(
+                {"error": {"code": "SCHEMA_DRIFT", "message": "Request schema hash does not match"}},
+                status_code=412,
+                headers={HASH_HEADER: current, DRIFT_HEADER: "true"},
+            )
+            await response(scope, receive, send)
+            return
+
+        async def send_with_hash(message: dict[str, Any]) -> None:
+            if message["type"] == "http.response.start":
+                headers = list(message.get("headers", []))
+                headers.append((HASH_HEADER.encode(), current.encode()))
+                if drift:
+                    headers.append((DRIFT_HEADER.encode(), b"true"))
+                message = {**message, "headers": headers}
+            await send(message)
+
+        await self.app(scope, receive, send_with_hash)
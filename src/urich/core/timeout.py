@@ -0,0 +1,69 @@
+"""Request timeout: a handler that hasn't started its response in time is cancelled and the client gets 504."""
+from __future__ import annotations
+
+import asyncio
+import contextlib
+import logging
+from typing import TYPE_CHECKING, Any
+
+from urich.core.errors import error_response
+
+if TYPE_CHECKING:
+    from urich.core.app import Application
+
+DEFAULT_REQUEST_TIMEOUT = 30.0
+
+logger = logging.getLogger("urich.timeout")
+
+
+class RequestTimeoutMiddleware:
+    """
+    ASGI middleware: runs the request in a task; if no response has started after the timeout
+    from Application._timeout_for(scope), cancels the task and answers 504 GATEWAY_TIMEOUT.
+    Once the response has started (e.g. an SSE stream) it runs to completion.
+    """
+
+    def __init__(self, app: Any, *, application: Application) -> None:
+        self.app = app
+        self._application = application
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        if scope["type"] != "http":
+            await self.app(scope, receive, send)
+            return
+        timeout = self._application._timeout_for(scope)
+        if timeout is None:
+            await self.app(scope, receive, send)
+            return
+        started = asyncio.Event()
+
+        async def send_wrapper(message: dict[str, Any]) -> None:
+            if message["type"] == "http.response.start":
+                started.set()
+            await send(message)
+
+        task = asyncio.ensure_future(self.app(scope, receive, send_wrapper))
+        waiter = asyncio.ensure_future(started.wait())
+        try:
+            await asyncio.wait({task, waiter}, timeout=timeout, return_when=asyncio.FIRST_COMPLETED)
+        except BaseException:
+            task.cancel()
+            raise
+        finally:
+            waiter.cancel()
+        if task.done() or started.is_set():
+            await task
+            return
+        task.cancel()
+        with contextlib.suppress(asyncio.CancelledError):
+            await task
+        matched = self._application._route_for(scope)
+        route = matched.path if matched is not None else scope["path"]
+        logger.warning("Request timed out after %ss: %s %s", timeout, scope["method"], route)
+        response = error_response(
+            504,
+            f"{scope['method']} {route} did not respond within {timeout}s",
+            code="GATEWAY_TIMEOUT",
+            details={"route": route, "timeout": timeout},
+        )
+        await response(scope, receive, send)
@@ -0,0 +1,62 @@
+"""Reverse routing: build URLs for named routes from their path templates."""
+from __future__ import annotations
+
+import re
+from typing import Any
+from urllib.parse import quote, urlencode
+
+_PARAM = re.compile(r"{([a-zA-Z_][a-zA-Z0-9_]*)(?::([a-zA-Z_][a-zA-Z0-9_]*))?}")
+
+
+class UrlError(ValueError):
+    """url_for failed: unknown route name, missing or unexpected parameters."""
+
+
+def under_prefix(path: str, prefix: str) -> bool:
+    """path is prefix or below it, by whole segments: "/admin" covers "/admin/x", not "/administrator"."""
+    return path == prefix or path.startswith(prefix.rstrip("/") + "/")
+
+
+def path_param_names(template: str) -> list[str]:
+    """Names of {param} / {param:converter} segments in a route template."""
+    return [m.group(1) for m in _PARAM.finditer(template)]
+
+
+def _encode(value: Any, converter: str | None) -> str:
+    text = str(value).lower() if isinstance(value, bool) else str(value)
+    return quote(text, safe="/" if converter == "path" else "")
+
+
+def build_url(
+    template: str,
+    params: dict[str, Any] | None = None,
+    *,
+    query_allowed: bool = False,
+    base_url: str | None = None,
+) -> str:
+    """
+    Substitute percent-encoded params into template. Params not in the template go to the
+    query string when query_allowed (GET routes), otherwise they are an error. Lists become
+    repeated keys. base_url makes the URL absolute.
+    """
+    params = dict(params or {})
+    missing = [name for name in path_param_names(template) if name not in params]
+    if missing:
+        raise UrlError(f"Missing path parameters for {template!r}: {', '.join(missing)}")
+
+    def substitute(m: re.Match[str]) -> str:
+        return _encode(params.pop(m.group(1)), m.group(2))
+
+    path = _PARAM.sub(substitute, template)
+    if params and not query_allowed:
+        raise UrlError(f"Unexpected parameters for {template!r}: {', '.join(sorted(params))}")
+    pairs: list[tuple[str, str]] = []
+    for key, value in sorted(params.items()):
+        for item in value if isinstance(value, list) else [value]:
+            if item is not None:
+                pairs.append((key, str(item).lower() if isinstance(item, bool) else str(item)))
+    if pairs:
+        path = f"{path}?{urlencode(pairs, quote_via=quote)}"
+    if base_url:
+        path = base_url.rstrip("/") + path
+    return path
@@ -0,0 +1,297 @@
+"""Payload validation against dataclass field types, reported as a list of field-level issues."""
+from __future__ import annotations
+
+import dataclasses
+import itertools
+import json
+import math
+import types
+import typing
+from dataclasses import dataclass, field
+from typing import Annotated, Any, Callable, Collection
+
+from urich.core.errors import HttpError
+from urich.core.multipart import UploadedFile
+
+_JSON_TYPES = {str: "string", bool: "boolean", int: "integer", float: "number", list: "array", dict: "object"}
+
+
+@dataclass(frozen=True)
+class IntRange:
+    """Bounds of an integer field: Annotated[int, IntRange(0, 2**64 - 1, "uint64")]. Values outside
+    are a 422 naming the field (keyword minimum / maximum). name is the format shown in OpenAPI."""
+
+    minimum: int
+    maximum: int
+    name: str = "integer"
+
+
+Int32 = Annotated[int, IntRange(-(2**31), 2**31 - 1, "int32")]
+Int64 = Annotated[int, IntRange(-(2**63), 2**63 - 1, "int64")]
+UInt64 = Annotated[int, IntRange(0, 2**64 - 1, "uint64")]
+
+
+def int_range(tp: Any) -> IntRange | None:
+    """IntRange of an Annotated[int, IntRange(...)] type (also inside Optional), else None."""
+    if typing.get_origin(tp) in (typing.Union, types.UnionType):
+        options = [a for a in typing.get_args(tp) if a is not type(None)]
+        return int_range(options[0]) if len(options) == 1 else None
+    return next((m for m in getattr(tp, "__metadata__", ()) if isinstance(m, IntRange)), None)
+
+
+def _reject_constant(name: str) -> Any:
+    raise ValueError(f"{name} is not valid JSON")
+
+
+def parse_json(raw: str | bytes) -> Any:
+    """json.loads without the NaN / Infinity extensions. Integers of any size are kept exact
+    (Python ints), so 64-bit ids round-trip; 1.0 and 1e3 stay floats, which integer fields reject."""
+    return json.loads(raw, parse_constant=_reject_constant)
+
+
+@dataclass(frozen=True)
+class ValidationIssue:
+    """One violation: path is a JSON pointer to the value ("/items/0/quantity", "" for the whole
+    body), keyword the rule it broke (required, additionalProperties, type, enum, minimum, maximum)."""
+
+    path: str
+    message: str
+    keyword: str
+
+    def to_dict(self) -> dict[str, str]:
+        return {"path": self.path, "message": self.message, "keyword": self.keyword}
+
+
+class SchemaValidationError(HttpError):
+    """422 VALIDATION_ERROR whose details are {"errors": [issue, ...]}; .issues keeps the list."""
+
+    def __init__(self, issues: list[ValidationIssue], subject: str = "request") -> None:
+        self.issues = issues
+        if len(issues) == 1:
+            issue = issues[0]
+            summary = f"{issue.path.lstrip('/') or 'body'}: {issue.message}"
+        else:
+            summary = f"{len(issues)} errors"
+        super().__init__(
+            422,
+            f"Invalid {subject}: {summary}",
+            code="VALIDATION_ERROR",
+            details={"errors": [issue.to_dict() for issue in issues]},
+        )
+
+
+class _Stop(Exception):
+    """First issue found in lenient mode."""
+
+
+def _pointer(path: str, key: Any) -> str:
+    return f"{path}/{str(key).replace('~', '~0').replace('/', '~1')}"
+
+
+def _json_type(value: Any) -> str:
+    if value is None:
+        return "null"
+    if isinstance(value, UploadedFile):
+        return "file"
+    for tp, name in _JSON_TYPES.items():
+        if isinstance(value, tp):
+            return name
+    return type(value).__name__
+
+
+def _type_name(tp: Any) -> str:
+    origin = typing.get_origin(tp) or tp
+    if origin is UploadedFile:
+        return "file"
+    if origin in (list, tuple, set, frozenset):
+        return "array"
+    return _JSON_TYPES.get(origin, getattr(origin, "__name__", str(origin)))
+
+
+class _Validator:
+    def __init__(self, strict: bool) -> None:
+        self.strict = strict
+        self.issues: list[ValidationIssue] = []
+
+    def add(self, path: str, message: str, keyword: str) -> None:
+        self.issues.append(ValidationIssue(path, message, keyword))
+        if not self.strict:
+            raise _Stop
+
+    def fields(self, payload_type: type, data: dict[str, Any], path: str, unchecked: Collection[str] = ()) -> None:
+        try:
+            hints = typing.get_type_hints(payload_type, include_extras=True)
+        except Exception:
+            hints = {}
+        fields = {f.name: f for f in dataclasses.fields(payload_type) if f.init}
+        for name in data:
+            if name not in fields:
+                self.add(_pointer(path, name), "unknown field", "additionalProperties")
+        for name, f in fields.items():
+            if name not in data:
+                if f.default is dataclasses.MISSING and f.default_factory is dataclasses.MISSING:
+                    self.add(_pointer(path, name), "field required", "required")
+                continue
+            if name not in unchecked:
+                self.value(data[name], hints.get(name, Any), _pointer(path, name))
+
+    def value(self, value: Any, tp: Any, path: str) -> None:
+        origin = typing.get_origin(tp)
+        args = typing.get_args(tp)
+        if tp is Any or isinstance(tp, (str, typing.TypeVar)):
+            return
+        if origin is Annotated:
+            before = len(self.issues)
+            self.value(value, args[0], path)
+            bounds = int_range(tp)
+            if bounds is not None and len(self.issues) == before and isinstance(value, int):
+                if value < bounds.minimum:
+                    self.add(path, f"{value} is below the {bounds.name} minimum {bounds.minimum}", "minimum")
+                elif value > bounds.maximum:
+                    self.add(path, f"{value} is above the {bounds.name} maximum {bounds.maximum}", "maximum")
+            return
+        if origin in (typing.Union, types.UnionType):
+            if value is None and type(None) in args:
+                return
+            options = [a for a in args if a is not type(None)]
+            if len(options) == 1:
+                self.value(value, options[0], path)
+                return
+            if any(not self._issues_for(value, option) for option in options):
+                return
+            expected = " or ".join(_type_name(a) for a in options)
+            self.add(path, f"expected {expected}, got {_json_type(value)}", "type")
+            return
+        if origin is typing.Literal:
+            if value not in args:
+                self.add(path, f"must be one of {', '.join(repr(a) for a in args)}", "enum")
+            return
+        base = origin or tp
+        if base in (list, tuple, set, frozenset):
+            if not isinstance(value, list):
+                self.add(path, f"expected array, got {_json_type(value)}", "type")
+                return
+            item_type = args[0] if args else Any
+            if base is tuple and not (len(args) == 2 and args[1] is Ellipsis):
+                item_type = Any  # fixed-length tuples: element types not checked
+            for i, item in enumerate(value):
+                self.value(item, item_type, _pointer(path, i))
+            return
+        if base is dict:
+            if not isinstance(value, dict):
+                self.add(path, f"expected object, got {_json_type(value)}", "type")
+                return
+            if len(args) == 2:
+                for key, item in value.items():
+                    self.value(item, args[1], _pointer(path, key))
+            return
+        if base is bool:
+            ok = isinstance(value, bool)
+        elif base is int:
+            ok = isinstance(value, int) and not isinstance(value, bool)
+            if isinstance(value, float):
+                # 1.5, and also 1.0 / 1e3: JSON integers are written without fraction or exponent.
+                kind = "integral" if value.is_integer() else "fractional"
+                self.add(path, f"expected integer, got {kind} number {value!r}", "type")
+                return
+        elif base is float:
+            ok = isinstance(value, (int, float)) and not isinstance(value, bool)
+            if ok and not _fits_float(value):
+                keyword = "minimum" if value < 0 else "maximum"
+                self.add(path, f"{value!r} is out of range for a number (double precision)", keyword)
+                return
+        elif base is str:
+            ok = isinstance(value, str)
+        elif base is UploadedFile:
+            ok = isinstance(value, UploadedFile)
+        else:
+            return  # other types (nested dataclasses, UUID, datetime, ...) are passed through unchecked
+        if not ok:
+            self.add(path, f"expected {_type_name(base)}, got {_json_type(value)}", "type")
+
+    def _issues_for(self, value: Any, tp: Any) -> list[ValidationIssue]:
+        probe = _Validator(strict=True)
+        probe.value(value, tp, "")
+        return probe.issues
+
+
+def _fits_float(value: int | float) -> bool:
+    try:
+        return math.isfinite(float(value))
+    except OverflowError:
+        return False
+
+
+def validate_payload(
+    payload_type: type, data: dict[str, Any], *, strict: bool = False, unchecked: Collection[str] = ()
+) -> list[ValidationIssue]:
+    """
+    Check a JSON object against a dataclass: unknown and missing fields, and the JSON types of
+    str / int / float / bool / list[...] / dict[...] / Optional / Literal fields. int fields take
+    integers only (not 1.0 or 1e3); Annotated[int, IntRange(...)] (Int32, Int64, UInt64) adds bounds,
+    and float fields reject values a double cannot hold. Lenient (default) stops at the first issue;
+    strict collects them all. Empty list when valid.
+    unchecked: fields whose values skip the type check (e.g. path parameters, which arrive as strings).
+    """
+    validator = _Validator(strict)
+    if not isinstance(data, dict):
+        return [ValidationIssue("", f"expected object, got {_json_type(data)}", "type")]
+    if not dataclasses.is_dataclass(payload_type):
+        return []
+    try:
+        validator.fields(payload_type, data, "", unchecked)
+    except _Stop:
+        pass
+    return validator.issues
+
+
+ValidationObserver = Callable[[str, str, float], Any]  # (method, route path, seconds) per validated request
+
+
+@dataclass
+class ValidationSettings:
+    """Application-wide validation options (app.validation_sampling / app.on_validation): the share
+    of requests validated on routes that aren't skipped, and observers of each validation's duration."""
+
+    sample_rate: float = 1.0
+    observers: list[ValidationObserver] = field(default_factory=list)
+
+
+class RouteValidation:
+    """
+    Validation of one route's payload, shared by its endpoint and the application: skip turns it
+    off (documented as x-skip-validation); otherwise the application's sample_rate decides per
+    request by a per-route counter (no random numbers), e.g. 0.25 validates every fourth request.
+    validated / seconds count the requests actually validated and the time spent on them.
+    """
+
+    def __init__(self, *, skip: bool = False) -> None:
+        self.skip = skip
+        self.path: str | None = None  # route path, set by Application.add_route
+        self.settings = ValidationSettings()  # replaced by the application's in add_route
+        self.validated = 0
+        self.seconds = 0.0
+        self._requests = itertools.count()
+
+    @property
+    def mode(self) -> str:
+        """"skipped", "sampled" (sample_rate below 1) or "on"."""
+        if self.skip:
+            return "skipped"
+        return "sampled" if self.settings.sample_rate < 1.0 else "on"
+
+    def should_validate(self) -> bool:
+        if self.skip:
+            return False
+        rate = self.settings.sample_rate
+        if rate >= 1.0:
+            return True
+        n = next(self._requests)
+        return int((n + 1) * rate) > int(n * rate)
+
+    def record(self, method: str, seconds: float) -> None:
+        """One validated request took seconds; passed on to the application's observers."""
+        self.validated += 1
+        self.seconds += seconds
+        for observer in self.settings.observers:
+            observer(method, self.path or "", seconds)
@@ -0,0 +1,151 @@
+"""
+API versions: app.version("v1") is a route group under /v1 tagged "v1", with optional deprecation
+headers and Accept-Version negotiation for unprefixed paths.
+"""
+from __future__ import annotations
+
+from datetime import datetime, timezone
+from email.utils import format_datetime
+from typing import TYPE_CHECKING, Any
+
+from urich.core.groups import RouteGroup
+from urich.core.openapi import REF_PREFIX
+
+if TYPE_CHECKING:
+    from urich.core.app import Application
+
+
+def _http_date(value: datetime | str) -> str:
+    if isinstance(value, str):
+        return value
+    if value.tzinfo is None:
+        value = value.replace(tzinfo=timezone.utc)
+    return format_datetime(value.astimezone(timezone.utc), usegmt=True)
+
+
+def _prefix_refs(schema: Any, version: str) -> Any:
+    """Point component $refs at the version's namespaced schemas (Name -> v1.Name)."""
+    if isinstance(schema, list):
+        return [_prefix_refs(item, version) for item in schema]
+    if not isinstance(schema, dict):
+        return schema
+    out = {key: _prefix_refs(value, version) for key, value in schema.items()}
+    ref = out.get("$ref")
+    if isinstance(ref, str) and ref.startswith(REF_PREFIX) and not ref[len(REF_PREFIX):].startswith(f"{version}."):
+        out["$ref"] = f"{REF_PREFIX}{version}.{ref[len(REF_PREFIX):]}"
+    return out
+
+
+class ApiVersion(RouteGroup):
+    """
+    Route group for one API version. Route names become "<version>.<name>" and OpenAPI schemas
+    "<version>.<Name>", so the same modules (or modules with the same names) can be registered
+    in several versions. deprecate() adds Deprecation / Sunset / Link headers to its responses.
+    """
+
+    def __init__(self, app: Application, name: str) -> None:
+        super().__init__(app, f"/{name}", tags=[name])
+        self.name = name
+        self.deprecation: str | None = None
+        self.sunset: str | None = None
+        self.link: str | None = None
+
+    def deprecate(
+        self,
+        *,
+        since: datetime | None = None,
+        sunset: datetime | str | None = None,
+        link: str | None = None,
+    ) -> ApiVersion:
+        """Mark the version deprecated: Deprecation header ("@<unix time>" of since, or "true"),
+        Sunset (HTTP date of removal) and Link to the migration guide, on every response. Returns self."""
+        self.deprecation = f"@{int(since.timestamp())}" if since is not None else "true"
+        self.sunset = _http_date(sunset) if sunset is not None else None
+        self.link = link
+        return self
+
+    def response_headers(self) -> list[tuple[bytes, bytes]]:
+        headers: list[tuple[str, str]] = []
+        if self.deprecation is not None:
+            headers.append(("deprecation", self.deprecation))
+        if self.sunset is not None:
+            headers.append(("sunset", self.sunset))
+        if self.link is not None:
+            headers.append(("link", f'<{self.link}>; rel="deprecation"'))
+        return [(k.encode("latin-1"), v.encode("latin-1")) for k, v in headers]
+
+    def add_route(self, path: str, endpoint: Any, methods: list[str] | None = None, **kwargs: Any) -> None:
+        if kwargs.get("name") is not None:
+            kwargs["name"] = f"{self.name}.{kwargs['name']}"
+        super().add_route(path, endpoint, methods, **kwargs)
+
+    def register_schema(self, name: str, schema: dict[str, Any]) -> dict[str, Any]:
+        return self.app.register_schema(f"{self.name}.{name}", _prefix_refs(schema, self.name))
+
+    def matches(self, path: str) -> bool:
+        return path == self.prefix or path.startswith(self.prefix + "/")
+
+
+class ApiVersionMiddleware:
+    """
+    ASGI middleware: routes unprefixed paths to the version named in the negotiation header (or the
+    default version) and adds deprecation headers to responses of deprecated versions.
+    """
+
+    def __init__(self, app: Any, *, application: Application) -> None:
+        self.app = app
+        self._application = application
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        if scope["type"] != "http":
+            await self.app(scope, receive, send)
+            return
+        versions: dict[str, ApiVersion] = self._application._versions
+        path = scope["path"]
+        version = next((v for v in versions.values() if v.matches(path)), None)
+        if version is None:
+            version = self._negotiate(scope, versions)
+            if version is not None:
+                scope = dict(scope)
+                scope["path"] = version.prefix + path
+                if "raw_path" in scope:
+                    scope["raw_path"] = version.prefix.encode() + scope["raw_path"]
+        if version is None or version.deprecation is None:
+            await self.app(scope, receive, send)
+            return
+        extra = version.response_headers()
+
+        async def send_with_headers(message: dict[str, Any]) -> None:
+            if message["type"] == "http.response.start":
+                message = {**message, "headers": list(message.get("headers", [])) + extra}
+            await send(message)
+
+        await self.app(scope, receive, send_with_headers)
+
+    def _negotiate(self, scope: dict[str, Any], versions: dict[str, ApiVersion]) -> ApiVersion | None:
+        header, default = self._application._version_header, self._application._default_version
+        if header is None or self._application._route_for(scope) is not None:
+            return None  # negotiation off, or an unversioned route (health, docs) matches as is
+        wanted = None
+        for key, value in scope.get("headers", []):
+            if key.lower() == header:
+                wanted = value.decode("latin-1").strip()
+                break
+        if wanted is None:
+            wanted = default
+        return versions.get(wanted) if wanted else None
+
+
+def spec_for_version(spec: dict[str, Any], version: ApiVersion, others: list[str]) -> dict[str, Any]:
+    """The OpenAPI spec restricted to one version's paths, without other versions' schemas."""
+    out = dict(spec)
+    out["paths"] = {path: ops for path, ops in spec.get("paths", {}).items() if version.matches(path)}
+    out["info"] = {**spec.get("info", {}), "version": version.name}
+    schemas = spec.get("components", {}).get("schemas")
+    if schemas is not None:
+        foreign = tuple(f"{name}." for name in others if name != version.name)
+        out["components"] = {
+            **spec["components"],
+            "schemas": {name: schema for name, schema in schemas.items() if not name.startswith(foreign)},
+        }
+    return out
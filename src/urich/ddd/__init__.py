@@ -1,4 +1,5 @@
 from urich.ddd.domain_module import DomainModule
 from urich.ddd.commands import Command, Query
+from urich.ddd.responses import NoContent, RawBody, RawJson, Reply
 
-__all__ = ["DomainModule", "Command", "Query"]
+__all__ = ["DomainModule", "Command", "Query", "NoContent", "RawBody", "RawJson", "Reply"]
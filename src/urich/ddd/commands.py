@@ -1,14 +1,68 @@
 """Command and query — CQRS markers."""
+from __future__ import annotations
+
+import re
 from dataclasses import dataclass
+from typing import Any, ClassVar
+
+
+def _snake(name: str) -> str:
+    return re.sub(r"(?<!^)(?=[A-Z])", "_", name).lower()
+
+
+def _check_options(cls: type, kind: str, options: dict[str, Any]) -> None:
+    if options:
+        raise TypeError(
+            f"{cls.__name__}: unknown {kind} option(s) {', '.join(sorted(options))}; "
+            "expected name=, description=, context="
+        )
 
 
 @dataclass
 class Command:
-    """Command: intent to change state. One handler per command type."""
-    pass
+    """Command: intent to change state. One handler per command type.
+    Options: class CreateOrder(Command, name="create_order_v2", description="...", context="orders").
+    name: route segment (default snake_case class name); description: OpenAPI description;
+    context: bounded context for DomainModule.from_types (inherited by subclasses)."""
+
+    command_name: ClassVar[str] = "command"
+    command_description: ClassVar[str | None] = None
+    command_context: ClassVar[str | None] = None
+
+    def __init_subclass__(
+        cls,
+        name: str | None = None,
+        description: str | None = None,
+        context: str | None = None,
+        **options: Any,
+    ) -> None:
+        _check_options(cls, "command", options)
+        super().__init_subclass__()
+        cls.command_name = name or _snake(cls.__name__)
+        cls.command_description = description
+        if context is not None:
+            cls.command_context = context
 
 
 @dataclass
 class Query:
-    """Query: intent to read. One handler per query type."""
-    pass
+    """Query: intent to read. One handler per query type.
+    Same options as Command: class GetOrder(Query, name=..., description=..., context=...)."""
+
+    query_name: ClassVar[str] = "query"
+    query_description: ClassVar[str | None] = None
+    query_context: ClassVar[str | None] = None
+
+    def __init_subclass__(
+        cls,
+        name: str | None = None,
+        description: str | None = None,
+        context: str | None = None,
+        **options: Any,
+    ) -> None:
+        _check_options(cls, "query", options)
+        super().__init_subclass__()
+        cls.query_name = name or _snake(cls.__name__)
+        cls.query_description = description
+        if context is not None:
+            cls.query_context = context
@@ -4,28 +4,284 @@ Describes aggregate, repository, commands, queries, event subscriptions.
 """
 from __future__ import annotations
 
+import dataclasses
+import inspect
 import re
-from typing import Any, Callable, Type
+import time
+import typing
+from typing import Any, Callable, Collection, Sequence, Type
 
+from starlette.concurrency import run_in_threadpool
 from starlette.requests import Request
 from starlette.responses import JSONResponse, Response
 
-from urich.core.app import Application
+from urich.core.app import APP_LIMIT, Application
+from urich.core.context import bind_request, unbind_request
+from urich.core.errors import HttpError
+from urich.core.examples import Example
 from urich.core.module import Module
-from urich.core.openapi import parameters_from_dataclass, schema_from_dataclass
+from urich.core.multipart import DEFAULT_MAX_FILE_SIZE, MULTIPART, UploadedFile, is_multipart, parse_multipart
+from urich.core.validation import RouteValidation, SchemaValidationError, parse_json, validate_payload
+from urich.core.openapi import (
+    command_response_schema,
+    has_uploads,
+    parameters_from_dataclass,
+    pydantic_schemas,
+    schema_from_dataclass,
+)
 from urich.domain import Repository
 from urich.domain.events import EventBus
 from urich.ddd.commands import Command, Query
+from urich.ddd.query_params import _field_types, _strip_optional, parse_query
+from urich.ddd.responses import NoContent, RawBody, RawJson, Reply
+from urich.events.retry import DeadLetterSink, LoggingDeadLetterSink, RetryPolicy, with_retry
+from urich.events.unit_of_work import UnitOfWork
 
 
 def _snake(name: str) -> str:
     return re.sub(r"(?<!^)(?=[A-Z])", "_", name).lower()
 
 
+def _dead_letter_sink(container: Any) -> DeadLetterSink:
+    try:
+        return container.resolve(DeadLetterSink)
+    except KeyError:
+        return LoggingDeadLetterSink()
+
+
+def _route_name(payload_type: type, kind: str) -> str:
+    """Route segment: class option name= (Command.command_name / Query.query_name), else snake_case class name."""
+    return getattr(payload_type, f"{kind}_name", None) or _snake(payload_type.__name__)
+
+
+async def _read_json_object(request: Request) -> dict[str, Any]:
+    """Request body as a JSON object; empty body -> {}. Malformed JSON -> 400, non-object -> 422."""
+    raw = await request.body()
+    if not raw.strip():
+        return {}
+    try:
+        body = parse_json(raw)
+    except ValueError as e:
+        raise HttpError(400, f"Malformed JSON body: {e}", code="INVALID_JSON")
+    if not isinstance(body, dict):
+        raise HttpError(422, "Request body must be a JSON object")
+    return body
+
+
+async def _read_form(request: Request, payload_type: type, max_file_size: int | None) -> dict[str, Any]:
+    """multipart/form-data body -> payload dict: text fields coerced like query parameters, files
+    under their field name (a list for list[UploadedFile] fields)."""
+    form = parse_multipart(await request.body(), request.headers["content-type"], max_file_size=max_file_size)
+    body = parse_query(form.fields, payload_type, label="Form field")
+    hints = _field_types(payload_type)
+    files: dict[str, list[UploadedFile]] = {}
+    for upload in form.files:
+        files.setdefault(upload.field, []).append(upload)
+    for name, uploads in files.items():
+        if typing.get_origin(_strip_optional(hints.get(name))) is list:
+            body[name] = uploads
+        elif len(uploads) == 1:
+            body[name] = uploads[0]
+        else:
+            raise HttpError(422, f"Form field {name!r}: expected one file, got {len(uploads)}")
+    return body
+
+
+def _to_json(value: Any) -> Any:
+    """Dataclass / pydantic model results (also nested in lists and dicts) -> JSON-compatible values."""
+    if dataclasses.is_dataclass(value) and not isinstance(value, type):
+        return {f.name: _to_json(getattr(value, f.name)) for f in dataclasses.fields(value) if not f.name.startswith("_")}
+    if hasattr(value, "model_dump") and not isinstance(value, type):
+        return value.model_dump(mode="json")
+    if isinstance(value, (list, tuple)):
+        return [_to_json(v) for v in value]
+    if isinstance(value, dict):
+        return {k: _to_json(v) for k, v in value.items()}
+    return value
+
+
+def _result_type(handler: Any, response: Any) -> type | None:
+    """Typed result: the declared response type, else the handler's return annotation if it is a class
+    (dataclass or pydantic model). None for untyped handlers."""
+    if isinstance(response, type):
+        return response
+    if response is not None:
+        return None
+    target = handler.__call__ if isinstance(handler, type) else handler
+    try:
+        ann = typing.get_type_hints(target).get("return")
+    except Exception:
+        return None
+    if isinstance(ann, type) and (dataclasses.is_dataclass(ann) or hasattr(ann, "model_fields")):
+        return ann
+    return None
+
+
+def _render(result: Any, *, command: bool, result_type: type | None = None) -> Response:
+    """Handler result -> Response. Commands wrap in {"ok": true, "result": ...}; Response passes through,
+    RawBody and NoContent are sent without the envelope.
+    An instance of result_type is serialized whole; other command results with an id collapse to the id."""
+    if isinstance(result, Response):
+        return result
+    status_code, headers = 200, {}
+    if isinstance(result, Reply):
+        status_code, headers, result = result.status_code, result.headers, result.result
+    if isinstance(result, NoContent):
+        response: Response = Response(status_code=204)
+    elif isinstance(result, RawBody):
+        response = Response(
+            result.body,
+            status_code=status_code if status_code != 200 else result.status_code,
+            media_type=result.content_type,
+        )
+        headers = {**result.headers, **headers}
+    elif isinstance(result, RawJson):
+        content = result.enveloped() if command else result.body
+        response = Response(content, status_code=status_code, media_type="application/json")
+    else:
+        if command:
+            if result is not None and not (result_type is not None and isinstance(result, result_type)):
+                result = getattr(result, "id", result)
+            payload = {"ok": True, "result": _to_json(result)} if result is not None else {"ok": True}
+        else:
+            payload = _to_json(result) if result is not None else {}
+        response = JSONResponse(payload, status_code=status_code)
+    for name, value in headers.items():
+        if name.lower() == "content-type":
+            response.headers["content-type"] = value
+        else:
+            response.headers.append(name, value)
+    return response
+
+
+@dataclasses.dataclass(frozen=True)
+class _HandlerPlan:
+    """How to call a handler, worked out once at registration: the container-resolved parameters
+    after the command/query (name, annotation, has a default) and whether it is async."""
+
+    dependencies: tuple[tuple[str, Any, bool], ...]
+    is_async: bool
+
+    def resolve(self, container: Any) -> dict[str, Any]:
+        """Dependencies for one call; unregistered parameters with a default keep the default."""
+        return {
+            name: container.resolve(ann)
+            for name, ann, has_default in self.dependencies
+            if not (has_default and not container.has(ann))
+        }
+
+
+def _handler_plan(handler: Type[Any] | Callable[..., Any]) -> _HandlerPlan:
+    """Plan for a handler function, callable object or class (its __call__). A parameter without
+    a type annotation or a default raises TypeError."""
+    is_async = _is_async(handler)
+    if isinstance(handler, type):
+        target, skip = handler.__call__, 2  # self, payload
+    else:
+        target, skip = handler, 1
+    try:
+        params = list(inspect.signature(target).parameters.values())[skip:]
+    except (TypeError, ValueError):
+        return _HandlerPlan((), is_async)
+    if not params:
+        return _HandlerPlan((), is_async)
+    hints_target = target if inspect.isroutine(target) else type(target).__call__
+    try:
+        hints = typing.get_type_hints(hints_target)
+    except Exception:
+        hints = {}
+    dependencies: list[tuple[str, Any, bool]] = []
+    for param in params:
+        if param.kind in (param.VAR_POSITIONAL, param.VAR_KEYWORD):
+            continue
+        ann = hints.get(param.name, param.annotation)
+        has_default = param.default is not inspect.Parameter.empty
+        if ann is inspect.Parameter.empty:
+            if has_default:
+                continue
+            raise TypeError(f"Handler {getattr(handler, '__qualname__', handler)!r}: parameter {param.name!r} needs a type annotation")
+        dependencies.append((param.name, ann, has_default))
+    return _HandlerPlan(tuple(dependencies), is_async)
+
+
+def _is_async(handler: Any) -> bool:
+    """Coroutine function, or an object whose __call__ is one."""
+    return inspect.iscoroutinefunction(handler) or inspect.iscoroutinefunction(getattr(handler, "__call__", None))
+
+
+def _build_payload(
+    payload_type: type,
+    data: dict[str, Any],
+    *,
+    strict: bool = False,
+    path_params: Collection[str] = (),
+    validation: RouteValidation | None = None,
+    method: str = "POST",
+) -> Any:
+    """Construct the command/query dataclass; unknown, missing or mistyped fields -> 422 with the
+    issues in details.errors (the first one, or all of them when strict). validation: the route's
+    skip / sampling switch, timed when it validates; unvalidated payloads only fail the constructor."""
+    if validation is None or validation.should_validate():
+        start = time.perf_counter()
+        issues = validate_payload(payload_type, data, strict=strict, unchecked=path_params)
+        if validation is not None:
+            validation.record(method, time.perf_counter() - start)
+        if issues:
+            raise SchemaValidationError(issues, payload_type.__name__)
+    try:
+        return payload_type(**data)
+    except TypeError as e:
+        raise HttpError(422, f"Invalid {payload_type.__name__}: {e}")
+
+
+def _response_schema(app: Application, response: Any) -> dict[str, Any]:
+    """Declared response: a JSON schema dict as-is; a pydantic model or dataclass type is registered
+    under its class name (nested pydantic models too) and referenced."""
+    if isinstance(response, dict):
+        return response
+    if hasattr(response, "model_json_schema"):
+        schema, nested = pydantic_schemas(response)
+        for name, nested_schema in nested.items():
+            app.register_schema(name, nested_schema)
+        return app.register_schema(response.__name__, schema)
+    return app.register_schema(response.__name__, schema_from_dataclass(response))
+
+
+def _response_options(app: Application, response: Any, content_type: str | None, *, command: bool) -> dict[str, Any]:
+    """add_route OpenAPI response options: NoContent -> 204 without body; content_type -> that media
+    type (binary string schema unless response gives one); otherwise the JSON schema (command envelope)."""
+    if response is NoContent:
+        return {"openapi_response_status": 204}
+    if content_type is not None:
+        schema = _response_schema(app, response) if response is not None else {"type": "string", "format": "binary"}
+        return {"openapi_response_schema": schema, "openapi_response_media_type": content_type}
+    if response is None:
+        return {}
+    schema = _response_schema(app, response)
+    return {"openapi_response_schema": command_response_schema(schema) if command else schema}
+
+
+def _openapi_meta(
+    response: Any,
+    summary: str | None,
+    description: str | None,
+    operation_id: str | None,
+    content_type: str | None = None,
+) -> dict[str, Any]:
+    """Per-route OpenAPI options: response and content type plus add_route openapi_* keyword arguments."""
+    return {
+        "response": response,
+        "content_type": content_type,
+        "openapi_summary": summary,
+        "openapi_description": description,
+        "openapi_operation_id": operation_id,
+    }
+
+
 class DomainModule(Module):
     """
     One object = full bounded context.
-    .aggregate() .repository() .command() .query() .on_event() .bind()
+    .aggregate() .repository() .command() .query() .on_event() .bind() .secured()
     Register via app.register(module).
     """
 
@@ -35,9 +291,39 @@ class DomainModule(Module):
         self._aggregate_roots: list[Type[Any]] = []
         self._repositories: list[tuple[Type[Repository[Any]], Type[Any]]] = []
         self._bindings: list[tuple[Type[Any], Type[Any]]] = []
-        self._commands: list[tuple[Type[Command], Type[Any]]] = []
-        self._queries: list[tuple[Type[Query], Type[Any]]] = []
+        self._commands: list[tuple[Type[Command], Type[Any], dict[str, Any]]] = []  # (type, handler, openapi)
+        self._queries: list[tuple[Type[Query], Type[Any], dict[str, Any]]] = []
         self._event_handlers: list[tuple[type, Any]] = []
+        self._security: list[dict[str, list[str]]] | None = None  # OpenAPI security of every route
+
+    @classmethod
+    def from_types(
+        cls, handlers: dict[type, Type[Any] | Callable[..., Any]], *, prefix: str | None = None
+    ) -> "DomainModule":
+        """Module built from Command/Query types declared with context= (one context for all):
+        DomainModule.from_types({CreateOrder: CreateOrderHandler, GetOrder: get_order}).
+        Raises ValueError for other types, a missing context or several contexts."""
+        contexts: set[str] = set()
+        for payload_type in handlers:
+            if isinstance(payload_type, type) and issubclass(payload_type, Command):
+                context = payload_type.command_context
+            elif isinstance(payload_type, type) and issubclass(payload_type, Query):
+                context = payload_type.query_context
+            else:
+                raise ValueError(f"{payload_type!r} is not a Command or Query type")
+            if context is None:
+                raise ValueError(f"{payload_type.__name__} has no context (class {payload_type.__name__}(..., context=...))")
+            contexts.add(context)
+        if len(contexts) != 1:
+            found = ", ".join(sorted(contexts)) or "none"
+            raise ValueError(f"from_types needs types of exactly one context, got: {found}")
+        module = cls(contexts.pop(), prefix)
+        for payload_type, handler in handlers.items():
+            if issubclass(payload_type, Command):
+                module.command(payload_type, handler)
+            else:
+                module.query(payload_type, handler)
+        return module
 
     def aggregate(self, root: Type[Any]) -> "DomainModule":
         """Register aggregate root type (optional metadata). Event publishing is done in the handler."""
@@ -53,16 +339,97 @@ class DomainModule(Module):
         self._bindings.append((interface, impl))
         return self
 
-    def command(self, cmd_type: Type[Command], handler: Type[Any] | Callable[..., Any]) -> "DomainModule":
-        self._commands.append((cmd_type, handler))
+    def command(
+        self,
+        cmd_type: Type[Command],
+        handler: Type[Any] | Callable[..., Any],
+        *,
+        response: Any = None,
+        summary: str | None = None,
+        description: str | None = None,
+        operation_id: str | None = None,
+        content_type: str | None = None,
+        idempotent: bool = False,
+        strict: bool = False,
+        skip_validation: bool = False,
+        examples: Sequence[Example] = (),
+        max_file_size: int | None = DEFAULT_MAX_FILE_SIZE,
+        max_body_size: Any = APP_LIMIT,
+    ) -> "DomainModule":
+        """response: dataclass type or JSON schema of the result, shown in OpenAPI inside the envelope;
+        NoContent documents a 204. content_type: media type of a RawBody result (e.g. "text/csv").
+        summary / description / operation_id: OpenAPI operation metadata.
+        idempotent: retries with the same Idempotency-Key header replay the first response.
+        strict: a 422 lists every validation issue of the body, not only the first.
+        skip_validation: don't check the body against the command's field types (hot paths); the
+        spec marks the operation x-skip-validation. Missing or unknown fields still fail with 422.
+        examples: request bodies (Example) shown in the spec and run by app.run_example_tests().
+        max_file_size: bytes per uploaded file of a multipart/form-data body (413 beyond; None = no limit).
+        max_body_size: bytes for the whole body (None = unlimited); default uses the app limit.
+        """
+        meta = _openapi_meta(response, summary, description, operation_id, content_type)
+        meta["idempotent"] = idempotent
+        meta["strict"] = strict
+        meta["skip_validation"] = skip_validation
+        meta["examples"] = list(examples)
+        meta["max_file_size"] = max_file_size
+        meta["max_body_size"] = max_body_size
+        self._commands.append((cmd_type, handler, meta))
+        return self
+
+    def query(
+        self,
+        query_type: Type[Query],
+        handler: Type[Any] | Callable[..., Any],
+        *,
+        response: Any = None,
+        summary: str | None = None,
+        description: str | None = None,
+        operation_id: str | None = None,
+        content_type: str | None = None,
+        strict: bool = False,
+        skip_validation: bool = False,
+        examples: Sequence[Example] = (),
+    ) -> "DomainModule":
+        """response: dataclass type or JSON schema of the query result, shown in OpenAPI; NoContent
+        documents a 204. content_type: media type of a RawBody result (e.g. "text/csv").
+        operation_id gets _get / _post suffixes (the query is served on both methods).
+        strict: a 422 lists every validation issue of the payload, not only the first.
+        skip_validation / examples: as for command(); examples are sent with POST.
+        """
+        meta = _openapi_meta(response, summary, description, operation_id, content_type)
+        meta["strict"] = strict
+        meta["skip_validation"] = skip_validation
+        meta["examples"] = list(examples)
+        self._queries.append((query_type, handler, meta))
         return self
 
-    def query(self, query_type: Type[Query], handler: Type[Any] | Callable[..., Any]) -> "DomainModule":
-        self._queries.append((query_type, handler))
+    def secured(self, scheme: str, scopes: list[str] | None = None) -> "DomainModule":
+        """Document every command and query as requiring the security scheme (see
+        app.add_security_scheme); several calls mean any one of them. Spec only: enforcement is the
+        auth middleware's."""
+        if self._security is None:
+            self._security = []
+        self._security.append({scheme: list(scopes or [])})
         return self
 
-    def on_event(self, event_type: type, handler: Any) -> "DomainModule":
-        self._event_handlers.append((event_type, handler))
+    def on_event(
+        self,
+        event_type: type,
+        handler: Any,
+        *,
+        retry: RetryPolicy | int | None = None,
+        dead_letter: DeadLetterSink | None = None,
+    ) -> "DomainModule":
+        """Subscribe handler to event_type on the EventBus.
+        retry: RetryPolicy, or max attempts with the default backoff. A handler still failing after
+        the last attempt doesn't raise into publish; the event goes to dead_letter, else the
+        DeadLetterSink registered in the container, else the log (LoggingDeadLetterSink)."""
+        if retry is not None:
+            policy = RetryPolicy(max_attempts=retry) if isinstance(retry, int) else retry
+            self._event_handlers.append((event_type, (handler, policy, dead_letter)))
+        else:
+            self._event_handlers.append((event_type, handler))
         return self
 
     def register_into(self, app: Application) -> None:
@@ -87,77 +454,174 @@ class DomainModule(Module):
             container.register_instance(EventBus, event_bus)
             container.register_instance(InProcessEventDispatcher, event_bus)
         for event_type, handler in self._event_handlers:
+            if isinstance(handler, tuple):
+                handler, policy, sink = handler
+                handler = with_retry(handler, policy, sink or (lambda c=container: _dead_letter_sink(c)))
             event_bus.subscribe(event_type, handler)
+        if not container.has(UnitOfWork):
+            container.register(UnitOfWork, lambda c=container: UnitOfWork.from_container(c), singleton=False)
 
         # Command/query handlers: register class in container
-        for cmd_type, handler in self._commands:
+        for cmd_type, handler, meta in self._commands:
+            meta = dict(meta)
+            response = meta.pop("response")
+            content_type = meta.pop("content_type")
+            strict = meta.pop("strict")
+            validation = RouteValidation(skip=meta.pop("skip_validation"))
+            max_file_size = meta.pop("max_file_size")
+            result_type = _result_type(handler, response)
+            if response is None:
+                response = result_type
             if isinstance(handler, type):
                 container.register_class(handler)
-            path = f"{self.prefix.rstrip('/')}/commands/{_snake(cmd_type.__name__)}"
+            if meta["openapi_description"] is None:
+                meta["openapi_description"] = getattr(cmd_type, "command_description", None)
+            app._command_handlers[cmd_type] = self._make_dispatcher(handler, container)
+            route_name = _route_name(cmd_type, "command")
+            path = f"{self.prefix.rstrip('/')}/commands/{route_name}"
             app.add_route(
                 path,
-                self._make_command_endpoint(cmd_type, handler, container),
+                self._make_command_endpoint(
+                    cmd_type,
+                    handler,
+                    container,
+                    result_type,
+                    strict=strict,
+                    validation=validation,
+                    max_file_size=max_file_size,
+                ),
                 methods=["POST"],
-                openapi_body_schema=schema_from_dataclass(cmd_type),
+                openapi_body_schema=app.register_schema(cmd_type.__name__, schema_from_dataclass(cmd_type)),
+                openapi_body_media_type=MULTIPART if has_uploads(cmd_type) else "application/json",
                 openapi_tags=[self.name],
+                name=f"{self.name}.commands.{route_name}",
+                openapi_security=self._security,
+                validation=validation,
+                **_response_options(app, response, content_type, command=True),
+                **meta,
             )
 
-        for query_type, handler in self._queries:
+        for query_type, handler, meta in self._queries:
+            meta = dict(meta)
+            response = meta.pop("response")
+            content_type = meta.pop("content_type")
+            strict = meta.pop("strict")
+            validation = RouteValidation(skip=meta.pop("skip_validation"))
+            if response is None:
+                response = _result_type(handler, None)
             if isinstance(handler, type):
                 container.register_class(handler)
-            path = f"{self.prefix.rstrip('/')}/queries/{_snake(query_type.__name__)}"
+            if meta["openapi_description"] is None:
+                meta["openapi_description"] = getattr(query_type, "query_description", None)
+            route_name = _route_name(query_type, "query")
+            path = f"{self.prefix.rstrip('/')}/queries/{route_name}"
             app.add_route(
                 path,
-                self._make_query_endpoint(query_type, handler, container),
+                self._make_query_endpoint(query_type, handler, container, strict=strict, validation=validation),
                 methods=["GET", "POST"],
                 openapi_parameters=parameters_from_dataclass(query_type),
-                openapi_body_schema=schema_from_dataclass(query_type),
+                openapi_body_schema=app.register_schema(query_type.__name__, schema_from_dataclass(query_type)),
                 openapi_tags=[self.name],
+                name=f"{self.name}.queries.{route_name}",
+                openapi_security=self._security,
+                validation=validation,
+                **_response_options(app, response, content_type, command=False),
+                **meta,
             )
 
     def _make_command_endpoint(
-        self, cmd_type: Type[Command], handler: Type[Any] | Callable[..., Any], container: Any
+        self,
+        cmd_type: Type[Command],
+        handler: Type[Any] | Callable[..., Any],
+        container: Any,
+        result_type: type | None = None,
+        *,
+        strict: bool = False,
+        validation: RouteValidation | None = None,
+        max_file_size: int | None = DEFAULT_MAX_FILE_SIZE,
     ) -> Callable:
+        plan = _handler_plan(handler)
+
         async def endpoint(request: Request) -> Response:
+            token = bind_request(request)
             try:
-                body = await request.json()
-            except Exception:
-                body = {}
-            cmd = cmd_type(**body)
-            if isinstance(handler, type):
-                h = container.resolve(handler)
-                result = await self._call_handler(h, cmd)
+                return await handle(request)
+            finally:
+                unbind_request(token)
+
+        async def handle(request: Request) -> Response:
+            if is_multipart(request.headers.get("content-type")):
+                body = await _read_form(request, cmd_type, max_file_size)
             else:
-                result = await self._call_handler(handler, cmd)
-            response_result = getattr(result, "id", result) if result is not None else None
-            return JSONResponse(
-                {"ok": True, "result": response_result} if response_result is not None else {"ok": True}
+                body = await _read_json_object(request)
+            if request.path_params:
+                body = {**body, **request.path_params}
+            cmd = _build_payload(
+                cmd_type,
+                body,
+                strict=strict,
+                path_params=request.path_params,
+                validation=validation,
+                method=request.method,
             )
+            h = container.resolve(handler) if isinstance(handler, type) else handler
+            result = await self._call_handler(h, cmd, container, plan)
+            return _render(result, command=True, result_type=result_type)
         return endpoint
 
     def _make_query_endpoint(
-        self, query_type: Type[Query], handler: Type[Any] | Callable[..., Any], container: Any
+        self,
+        query_type: Type[Query],
+        handler: Type[Any] | Callable[..., Any],
+        container: Any,
+        *,
+        strict: bool = False,
+        validation: RouteValidation | None = None,
     ) -> Callable:
+        plan = _handler_plan(handler)
+
         async def endpoint(request: Request) -> Response:
-            if request.method == "POST":
-                try:
-                    body = await request.json()
-                except Exception:
-                    body = {}
-            else:
-                body = dict(request.query_params)
-            # string coercion for query params
-            query = query_type(**body)
-            if isinstance(handler, type):
-                h = container.resolve(handler)
-                result = await self._call_handler(h, query)
-            else:
-                result = await self._call_handler(handler, query)
-            return JSONResponse(result if result is not None else {})
+            token = bind_request(request)
+            try:
+                return await handle(request)
+            finally:
+                unbind_request(token)
+
+        async def handle(request: Request) -> Response:
+            # Query string, then JSON body (GET may carry one too), then path params; later keys win.
+            body = parse_query(request.query_params.multi_items(), query_type)
+            json_body = await _read_json_object(request)
+            if json_body:
+                body = {**body, **json_body}
+            if request.path_params:
+                body = {**body, **request.path_params}
+            query = _build_payload(
+                query_type,
+                body,
+                strict=strict,
+                path_params=request.path_params,
+                validation=validation,
+                method=request.method,
+            )
+            h = container.resolve(handler) if isinstance(handler, type) else handler
+            result = await self._call_handler(h, query, container, plan)
+            return _render(result, command=False)
         return endpoint
 
-    async def _call_handler(self, handler: Any, payload: Any) -> Any:
-        result = handler(payload)
+    def _make_dispatcher(self, handler: Type[Any] | Callable[..., Any], container: Any) -> Callable:
+        plan = _handler_plan(handler)
+
+        async def dispatch(cmd: Any) -> Any:
+            h = container.resolve(handler) if isinstance(handler, type) else handler
+            return await self._call_handler(h, cmd, container, plan)
+        return dispatch
+
+    async def _call_handler(self, handler: Any, payload: Any, container: Any, plan: _HandlerPlan) -> Any:
+        kwargs = plan.resolve(container)
+        if plan.is_async:
+            return await handler(payload, **kwargs)
+        # Sync handlers run in the threadpool so blocking I/O doesn't stall the event loop.
+        result = await run_in_threadpool(handler, payload, **kwargs)
         if hasattr(result, "__await__"):
             return await result
         return result
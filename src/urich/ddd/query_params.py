@@ -0,0 +1,99 @@
+"""Query string -> query payload: repeated keys and a[]= become lists, values coerced to field types."""
+from __future__ import annotations
+
+import dataclasses
+import re
+import types
+import typing
+from typing import Annotated, Any, Iterable
+
+from urich.core.errors import HttpError
+
+_TRUE = {"true", "1", "yes", "on"}
+_FALSE = {"false", "0", "no", "off"}
+# JSON number grammar: the same literals a JSON body accepts (no "1_000", " 12", "nan", "inf").
+_JSON_INT = re.compile(r"-?(?:0|[1-9][0-9]*)")
+_JSON_NUMBER = re.compile(r"-?(?:0|[1-9][0-9]*)(?:\.[0-9]+)?(?:[eE][+-]?[0-9]+)?")
+
+
+def _field_types(payload_type: type) -> dict[str, Any]:
+    if not dataclasses.is_dataclass(payload_type):
+        return {}
+    try:
+        return typing.get_type_hints(payload_type, include_extras=True)
+    except Exception:
+        return {f.name: f.type for f in dataclasses.fields(payload_type)}
+
+
+def _strip_optional(tp: Any) -> Any:
+    """Optional[X] -> X and Annotated[X, ...] -> X (bounds are checked by validate_payload)."""
+    if typing.get_origin(tp) is Annotated:
+        return _strip_optional(typing.get_args(tp)[0])
+    if typing.get_origin(tp) in (typing.Union, types.UnionType):
+        args = [a for a in typing.get_args(tp) if a is not type(None)]
+        if len(args) == 1:
+            return _strip_optional(args[0])
+    return tp
+
+
+def _is_list(tp: Any) -> bool:
+    return tp in (list, tuple, set) or typing.get_origin(tp) in (list, tuple, set)
+
+
+def _coerce_scalar(name: str, value: str, tp: Any, label: str = "Query parameter") -> Any:
+    tp = _strip_optional(tp)
+    try:
+        if tp is bool:
+            lowered = value.lower()
+            if lowered in _TRUE:
+                return True
+            if lowered in _FALSE:
+                return False
+            raise ValueError(value)
+        if tp is int:
+            if not _JSON_INT.fullmatch(value):
+                raise ValueError(value)
+            return int(value)
+        if tp is float:
+            if not _JSON_NUMBER.fullmatch(value):
+                raise ValueError(value)
+            return float(value)
+    except ValueError:
+        expected = getattr(tp, "__name__", str(tp))
+        if tp is int and _JSON_NUMBER.fullmatch(value):
+            expected = "int (no fraction or exponent)"
+        raise HttpError(422, f"{label} {name!r}: expected {expected}, got {value!r}")
+    return value
+
+
+def parse_query(
+    items: Iterable[tuple[str, str]], payload_type: type, *, label: str = "Query parameter"
+) -> dict[str, Any]:
+    """
+    Decoded (key, value) pairs -> payload dict. `tag=a&tag=b` and `tag[]=a` give lists; a single
+    value for a list-typed field becomes a one-item list. int/float/bool fields (also inside
+    list[...] and Optional[...]) are converted; an invalid value is a 422 naming the label and key.
+    Numbers follow the JSON body rules: int takes integer literals only ("1.0" and "1e3" are 422),
+    float takes JSON number literals; ranges (Int64, ...) are checked when the payload is validated.
+    """
+    collected: dict[str, list[str]] = {}
+    forced_lists: set[str] = set()
+    for key, value in items:
+        if key.endswith("[]"):
+            key = key[:-2]
+            forced_lists.add(key)
+        collected.setdefault(key, []).append(value)
+
+    hints = _field_types(payload_type)
+    payload: dict[str, Any] = {}
+    for key, values in collected.items():
+        tp = _strip_optional(hints.get(key, str))
+        if _is_list(tp):
+            args = typing.get_args(tp)
+            item_type = args[0] if args else str
+            payload[key] = [_coerce_scalar(key, v, item_type, label) for v in values]
+        elif len(values) > 1 or key in forced_lists:
+            payload[key] = [_coerce_scalar(key, v, tp, label) for v in values]
+        else:
+            payload[key] = _coerce_scalar(key, values[0], tp, label)
+    return payload
@@ -0,0 +1,75 @@
+"""Handler return types that control the HTTP response of command/query endpoints."""
+from __future__ import annotations
+
+import json
+from typing import Any, Mapping
+
+
+class RawJson:
+    """
+    Pre-serialized JSON returned by a handler (e.g. from a cache): bytes are sent as-is,
+    without parsing and re-serializing. Well-formedness is checked only when Python runs
+    without -O (debug); command endpoints splice the bytes into {"ok": true, "result": ...}.
+    """
+
+    __slots__ = ("body",)
+
+    def __init__(self, body: bytes | str) -> None:
+        self.body = body.encode() if isinstance(body, str) else bytes(body)
+        if __debug__:
+            try:
+                json.loads(self.body)
+            except ValueError as e:
+                raise ValueError(f"RawJson body is not valid JSON: {e}") from e
+
+    def enveloped(self) -> bytes:
+        """Command response envelope around the raw bytes."""
+        return b'{"ok":true,"result":' + self.body + b"}"
+
+
+class Reply:
+    """
+    Handler result with an explicit status and response headers, e.g.
+    Reply(order.id, status_code=201, headers={"Location": f"/orders/{order.id}"}).
+    result is rendered as usual (RawJson included). An explicit Content-Type header wins
+    over the default application/json. Returning a Starlette Response also works and is sent as-is.
+    """
+
+    __slots__ = ("result", "status_code", "headers")
+
+    def __init__(self, result: Any = None, *, status_code: int = 200, headers: Mapping[str, str] | None = None) -> None:
+        self.result = result
+        self.status_code = status_code
+        self.headers = dict(headers or {})
+
+
+class RawBody:
+    """
+    Non-JSON body returned by a handler (CSV export, image, protobuf): bytes are sent as-is with
+    content_type, without the command envelope. Declare content_type on .command() / .query()
+    so OpenAPI shows it instead of application/json.
+    """
+
+    __slots__ = ("body", "content_type", "status_code", "headers")
+
+    def __init__(
+        self,
+        body: bytes | str,
+        content_type: str,
+        *,
+        status_code: int = 200,
+        headers: Mapping[str, str] | None = None,
+    ) -> None:
+        self.body = body.encode() if isinstance(body, str) else bytes(body)
+        self.content_type = content_type
+        self.status_code = status_code
+        self.headers = dict(headers or {})
+
+
+class NoContent:
+    """
+    Handler result for 204 No Content (empty body, no envelope). Return NoContent(); pass
+    response=NoContent to .command() / .query() to document the 204 in OpenAPI.
+    """
+
+    __slots__ = ()
@@ -1,4 +1,7 @@
+from urich.discovery.cached import CachedDiscovery
+from urich.discovery.consul import ConsulDiscovery
 from urich.discovery.discovery_module import DiscoveryModule
+from urich.discovery.dns import DnsDiscovery
 from urich.discovery.protocol import ServiceDiscovery, static_discovery
 
-__all__ = ["DiscoveryModule", "ServiceDiscovery", "static_discovery"]
+__all__ = ["CachedDiscovery", "ConsulDiscovery", "DiscoveryModule", "DnsDiscovery", "ServiceDiscovery", "static_discovery"]
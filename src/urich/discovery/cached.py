@@ -0,0 +1,83 @@
+"""
+Base for discovery backed by a remote source (Consul, DNS): resolve() stays synchronous and reads a
+cached snapshot that a background task refreshes every ttl seconds.
+"""
+from __future__ import annotations
+
+import asyncio
+import contextlib
+import logging
+from typing import Iterable
+
+logger = logging.getLogger("urich.discovery")
+
+
+class CachedDiscovery:
+    """
+    Snapshot of service name -> URLs. Subclasses implement async fetch(service_name).
+    services: names fetched at start(); any other name passed to resolve() is added and fetched in
+    the background (the first call returns []). A failed fetch logs a warning and keeps the last
+    good URLs; a service never fetched successfully resolves to [].
+    start() / stop() run the refresher (DiscoveryModule hooks them to app startup and shutdown).
+    """
+
+    def __init__(self, services: Iterable[str] = (), *, ttl: float = 10.0) -> None:
+        if ttl <= 0:
+            raise ValueError("ttl must be positive")
+        self.ttl = ttl
+        self._watched: list[str] = list(dict.fromkeys(services))
+        self._snapshot: dict[str, list[str]] = {}
+        self._task: asyncio.Task[None] | None = None
+        self._pending: set[asyncio.Task[None]] = set()
+
+    async def fetch(self, service_name: str) -> list[str]:
+        raise NotImplementedError
+
+    def resolve(self, service_name: str) -> list[str]:
+        if service_name not in self._watched:
+            self._watched.append(service_name)
+            self._refresh_soon(service_name)
+        return list(self._snapshot.get(service_name, []))
+
+    async def refresh_now(self, service_name: str | None = None) -> None:
+        """Fetch one service (or every watched one) right away and update the snapshot."""
+        names = [service_name] if service_name is not None else list(self._watched)
+        if service_name is not None and service_name not in self._watched:
+            self._watched.append(service_name)
+        await asyncio.gather(*(self._refresh(name) for name in names))
+
+    async def start(self) -> None:
+        if self._task is None:
+            await self.refresh_now()
+            self._task = asyncio.ensure_future(self._loop())
+
+    async def stop(self) -> None:
+        tasks = [t for t in [self._task, *self._pending] if t is not None]
+        self._task = None
+        for task in tasks:
+            task.cancel()
+        for task in tasks:
+            with contextlib.suppress(asyncio.CancelledError):
+                await task
+
+    async def _loop(self) -> None:
+        while True:
+            await asyncio.sleep(self.ttl)
+            await self.refresh_now()
+
+    async def _refresh(self, service_name: str) -> None:
+        try:
+            urls = await self.fetch(service_name)
+        except Exception as e:
+            logger.warning("discovery: resolving %r failed: %s", service_name, e)
+            return
+        self._snapshot[service_name] = urls
+
+    def _refresh_soon(self, service_name: str) -> None:
+        try:
+            loop = asyncio.get_running_loop()
+        except RuntimeError:
+            return  # no loop yet: fetched by start() or the next refresh
+        task = loop.create_task(self._refresh(service_name))
+        self._pending.add(task)
+        task.add_done_callback(self._pending.discard)
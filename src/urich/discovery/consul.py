@@ -0,0 +1,63 @@
+"""ConsulDiscovery — passing instances from the Consul health API (requires httpx: pip install 'urich[consul]')."""
+from __future__ import annotations
+
+from typing import Any, Iterable
+
+from urich.discovery.cached import CachedDiscovery
+
+
+class ConsulDiscovery(CachedDiscovery):
+    """
+    Resolves a service to the instances passing their health checks:
+    GET {address}/v1/health/service/{name}?passing=true, one URL per instance as
+    {scheme}://{Service.Address or Node.Address}:{Service.Port}.
+    token: sent as X-Consul-Token; datacenter / tag: Consul query filters.
+    Cached for ttl seconds and refreshed in the background (see CachedDiscovery).
+    """
+
+    def __init__(
+        self,
+        address: str = "http://127.0.0.1:8500",
+        services: Iterable[str] = (),
+        *,
+        ttl: float = 10.0,
+        scheme: str = "http",
+        token: str | None = None,
+        datacenter: str | None = None,
+        tag: str | None = None,
+        timeout: float = 5.0,
+    ) -> None:
+        super().__init__(services, ttl=ttl)
+        self._address = address.rstrip("/")
+        self._scheme = scheme
+        self._headers = {"X-Consul-Token": token} if token else {}
+        self._params: dict[str, str] = {"passing": "true"}
+        if datacenter:
+            self._params["dc"] = datacenter
+        if tag:
+            self._params["tag"] = tag
+        self._timeout = timeout
+
+    async def fetch(self, service_name: str) -> list[str]:
+        try:
+            import httpx
+        except ImportError:
+            raise RuntimeError("ConsulDiscovery requires httpx; pip install 'urich[consul]'")
+        url = f"{self._address}/v1/health/service/{service_name}"
+        async with httpx.AsyncClient(timeout=self._timeout, headers=self._headers) as client:
+            r = await client.get(url, params=self._params)
+        r.raise_for_status()
+        return self.urls(r.json())
+
+    def urls(self, entries: list[dict[str, Any]]) -> list[str]:
+        """Instance URLs from a /v1/health/service response body."""
+        urls = []
+        for entry in entries:
+            service = entry.get("Service") or {}
+            host = service.get("Address") or (entry.get("Node") or {}).get("Address")
+            port = service.get("Port")
+            if host and port:
+                if ":" in host:  # IPv6
+                    host = f"[{host}]"
+                urls.append(f"{self._scheme}://{host}:{port}")
+        return urls
@@ -1,6 +1,6 @@
 """
 DiscoveryModule — building block for service discovery.
-Configure via .static(...) or .adapter(...); register with app.register(discovery).
+Configure via .static(...), .consul(...), .dns(...) or .adapter(...); register with app.register(discovery).
 """
 from __future__ import annotations
 
@@ -8,6 +8,7 @@ from typing import Any
 
 from urich.core.app import Application
 from urich.core.module import Module
+from urich.discovery.cached import CachedDiscovery
 from urich.discovery.protocol import ServiceDiscovery, StaticDiscovery
 
 
@@ -20,11 +21,29 @@ class DiscoveryModule(Module):
     def __init__(self) -> None:
         self._adapter: Any = None
 
-    def static(self, services: dict[str, str]) -> DiscoveryModule:
-        """Static config: service name -> URL."""
+    def static(self, services: dict[str, str | list[str]]) -> DiscoveryModule:
+        """Static config: service name -> URL, or a list of URLs for several instances."""
         self._adapter = StaticDiscovery(services)
         return self
 
+    def consul(
+        self, address: str = "http://127.0.0.1:8500", services: list[str] | None = None, **options: Any
+    ) -> DiscoveryModule:
+        """ConsulDiscovery: passing instances from the Consul health API; services are fetched at
+        startup. options: ttl, scheme, token, datacenter, tag, timeout."""
+        from urich.discovery.consul import ConsulDiscovery
+
+        self._adapter = ConsulDiscovery(address, services or (), **options)
+        return self
+
+    def dns(self, suffix: str = "", services: list[str] | None = None, **options: Any) -> DiscoveryModule:
+        """DnsDiscovery: A/AAAA (or SRV with srv=True) records of {service}.{suffix}.
+        options: port, scheme, srv, srv_prefix, ttl."""
+        from urich.discovery.dns import DnsDiscovery
+
+        self._adapter = DnsDiscovery(suffix, services or (), **options)
+        return self
+
     def adapter(self, impl: ServiceDiscovery) -> DiscoveryModule:
         """Use custom implementation (protocol: resolve(name) -> list[url])."""
         self._adapter = impl
@@ -34,3 +53,6 @@ class DiscoveryModule(Module):
         if self._adapter is None:
             self._adapter = StaticDiscovery({})
         app.container.register_instance(ServiceDiscovery, self._adapter)
+        if isinstance(self._adapter, CachedDiscovery):
+            app.starlette.router.on_startup.append(self._adapter.start)
+            app.starlette.router.on_shutdown.append(self._adapter.stop)
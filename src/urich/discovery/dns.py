@@ -0,0 +1,66 @@
+"""DnsDiscovery — instances from DNS A/AAAA or SRV records (SRV requires dnspython: pip install 'urich[dns]')."""
+from __future__ import annotations
+
+import asyncio
+import socket
+from typing import Iterable
+
+from urich.discovery.cached import CachedDiscovery
+
+
+class DnsDiscovery(CachedDiscovery):
+    """
+    Resolves a service by looking up {service}.{suffix} (e.g. "orders.service.consul",
+    "orders.default.svc.cluster.local").
+    srv=False: A/AAAA records, one URL per address with the configured port.
+    srv=True: SRV records ("_{service}._tcp.{suffix}" unless srv_prefix is changed), URLs ordered by
+    priority then weight and using the port from each record.
+    Cached for ttl seconds and refreshed in the background (see CachedDiscovery).
+    """
+
+    def __init__(
+        self,
+        suffix: str = "",
+        services: Iterable[str] = (),
+        *,
+        port: int = 80,
+        scheme: str = "http",
+        srv: bool = False,
+        srv_prefix: str = "_{service}._tcp",
+        ttl: float = 10.0,
+    ) -> None:
+        super().__init__(services, ttl=ttl)
+        if srv:
+            try:
+                import dns.asyncresolver  # noqa: F401
+            except ImportError:
+                raise RuntimeError("DnsDiscovery(srv=True) requires dnspython; pip install 'urich[dns]'")
+        self._suffix = suffix.strip(".")
+        self._port = port
+        self._scheme = scheme
+        self._srv = srv
+        self._srv_prefix = srv_prefix
+
+    def hostname(self, service_name: str) -> str:
+        name = self._srv_prefix.format(service=service_name) if self._srv else service_name
+        return f"{name}.{self._suffix}" if self._suffix else name
+
+    async def fetch(self, service_name: str) -> list[str]:
+        hostname = self.hostname(service_name)
+        if self._srv:
+            return await self._fetch_srv(hostname)
+        infos = await asyncio.get_running_loop().getaddrinfo(hostname, self._port, type=socket.SOCK_STREAM)
+        urls = []
+        for family, _, _, _, sockaddr in infos:
+            host = f"[{sockaddr[0]}]" if family == socket.AF_INET6 else sockaddr[0]
+            url = f"{self._scheme}://{host}:{self._port}"
+            if url not in urls:
+                urls.append(url)
+        return urls
+
+    async def _fetch_srv(self, hostname: str) -> list[str]:
+        import dns.asyncresolver
+
+        answer = await dns.asyncresolver.resolve(hostname, "SRV")
+        records = sorted(answer, key=lambda r: (r.priority, -r.weight))
+        return [f"{self._scheme}://{r.target.to_text(omit_final_dot=True)}:{r.port}" for r in records]
@@ -13,17 +13,16 @@ class ServiceDiscovery(Protocol):
         ...
 
 
-def static_discovery(services: dict[str, str]) -> ServiceDiscovery:
-    """Minimal out-of-the-box implementation: name -> URL map."""
+def static_discovery(services: dict[str, str | list[str]]) -> ServiceDiscovery:
+    """Minimal out-of-the-box implementation: name -> URL (or list of instance URLs) map."""
     return StaticDiscovery(services)
 
 
 class StaticDiscovery:
-    """Discovery from static config (env/map)."""
+    """Discovery from static config (env/map). A value may list several instances of the service."""
 
-    def __init__(self, services: dict[str, str]) -> None:
-        self._services = dict(services)
+    def __init__(self, services: dict[str, str | list[str]]) -> None:
+        self._services = {name: [urls] if isinstance(urls, str) else list(urls) for name, urls in services.items()}
 
     def resolve(self, service_name: str) -> list[str]:
-        url = self._services.get(service_name)
-        return [url] if url else []
+        return [url for url in self._services.get(service_name, []) if url]
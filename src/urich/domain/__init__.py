@@ -1,14 +1,38 @@
-"""Domain layer base classes: Entity, ValueObject, DomainEvent, Repository."""
+"""Domain layer base classes: Entity, AggregateRoot, ValueObject, DomainEvent, Repository."""
+from urich.domain.aggregate import AggregateRoot, aggregate_name
 from urich.domain.entity import Entity
 from urich.domain.value_object import ValueObject
-from urich.domain.events import DomainEvent, EventBus, InProcessEventDispatcher
-from urich.domain.repository import Repository
+from urich.domain.events import (
+    DomainEvent,
+    EventBus,
+    EventDeliveryError,
+    EventEnvelope,
+    EventPayloadTooLarge,
+    InProcessEventDispatcher,
+    bind_envelope_metadata,
+    event_name,
+    reset_envelope_metadata,
+)
+from urich.domain.errors import AppError
+from urich.domain.repository import ConcurrencyError, InMemoryRepository, Repository, Versioned
 
 __all__ = [
     "Entity",
+    "AggregateRoot",
+    "aggregate_name",
     "ValueObject",
     "DomainEvent",
     "EventBus",
+    "EventDeliveryError",
+    "EventEnvelope",
+    "EventPayloadTooLarge",
     "InProcessEventDispatcher",
+    "bind_envelope_metadata",
+    "event_name",
+    "reset_envelope_metadata",
     "Repository",
+    "InMemoryRepository",
+    "Versioned",
+    "ConcurrencyError",
+    "AppError",
 ]
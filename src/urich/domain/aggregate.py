@@ -0,0 +1,42 @@
+"""AggregateRoot — entity that records domain events until they are saved (see UnitOfWork)."""
+from __future__ import annotations
+
+from typing import Any, ClassVar
+
+from urich.domain.entity import Entity
+from urich.domain.events import DomainEvent, _snake
+
+
+class AggregateRoot(Entity):
+    """Entity with recorded events: record_event() in domain methods, take_events() after save.
+    name: stable aggregate type name (default: snake_case class name, e.g. order_line); override
+    with class Order(AggregateRoot, name="sales_order")."""
+
+    aggregate_name: ClassVar[str] = "aggregate_root"
+
+    def __init_subclass__(cls, name: str | None = None, **kwargs: Any) -> None:
+        super().__init_subclass__(**kwargs)
+        cls.aggregate_name = name or _snake(cls.__name__)
+
+    def record_event(self, event: DomainEvent) -> None:
+        self.pending_events.append(event)
+
+    @property
+    def pending_events(self) -> list[DomainEvent]:
+        """Recorded events not taken yet (in recording order)."""
+        events = self.__dict__.get("_pending_events")
+        if events is None:
+            events = self.__dict__["_pending_events"] = []
+        return events
+
+    def take_events(self) -> list[DomainEvent]:
+        """Return recorded events and clear them."""
+        events = list(self.pending_events)
+        self.pending_events.clear()
+        return events
+
+
+def aggregate_name(aggregate_or_type: Any) -> str:
+    """Type name of an aggregate (instance or type): AggregateRoot.aggregate_name, else snake_case class name."""
+    cls = aggregate_or_type if isinstance(aggregate_or_type, type) else type(aggregate_or_type)
+    return getattr(cls, "aggregate_name", None) or _snake(cls.__name__)
@@ -0,0 +1,50 @@
+"""AppError — application/domain error with a machine-readable code and the HTTP status it maps to."""
+from __future__ import annotations
+
+from typing import Any
+
+
+class AppError(Exception):
+    """
+    Raise from domain code or handlers for an expected failure, e.g.
+    raise AppError.conflict("INSUFFICIENT_STOCK", "Only 2 left", details={"available": 2}).
+    HTTP answers {"error": {"code", "message", "details"}} with status; RPC error envelopes keep
+    code and details; dead letters keep the error (DeadLetter.code).
+    """
+
+    def __init__(self, code: str, message: str, *, status: int = 400, details: Any = None) -> None:
+        self.code = code
+        self.message = message
+        self.status = status
+        self.details = details
+        super().__init__(message)
+
+    @classmethod
+    def bad_request(cls, code: str, message: str, *, details: Any = None) -> AppError:
+        return cls(code, message, status=400, details=details)
+
+    @classmethod
+    def forbidden(cls, code: str, message: str, *, details: Any = None) -> AppError:
+        return cls(code, message, status=403, details=details)
+
+    @classmethod
+    def not_found(cls, code: str, message: str, *, details: Any = None) -> AppError:
+        return cls(code, message, status=404, details=details)
+
+    @classmethod
+    def conflict(cls, code: str, message: str, *, details: Any = None) -> AppError:
+        return cls(code, message, status=409, details=details)
+
+    @classmethod
+    def unprocessable(cls, code: str, message: str, *, details: Any = None) -> AppError:
+        return cls(code, message, status=422, details=details)
+
+    def to_dict(self) -> dict[str, Any]:
+        """The standard error body: {"error": {"code", "message"[, "details"]}}."""
+        err: dict[str, Any] = {"code": self.code, "message": self.message}
+        if self.details is not None:
+            err["details"] = self.details
+        return {"error": err}
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}({self.code!r}, {self.message!r}, status={self.status})"
@@ -1,8 +1,16 @@
 """Domain events: base type and pending list when raised from aggregate."""
 from __future__ import annotations
 
+import asyncio
+import contextvars
+import dataclasses
+import json
+import re
+import typing
+import uuid
 from dataclasses import dataclass
-from typing import Any, Callable, Protocol, runtime_checkable
+from datetime import datetime, timezone
+from typing import Any, Callable, ClassVar, Protocol, runtime_checkable
 
 
 @runtime_checkable
@@ -16,32 +24,264 @@ class EventBus(Protocol):
         ...
 
 
+_EVENT_TYPES: dict[str, type] = {}  # event name -> DomainEvent subclass (for EventEnvelope.unwrap)
+
+
+def _snake(name: str) -> str:
+    return re.sub(r"(?<!^)(?=[A-Z])", "_", name).lower()
+
+
 @dataclass
 class DomainEvent:
-    """Base domain event type. Subclasses are dataclasses with fields."""
-    pass
+    """Base domain event type. Subclasses are dataclasses with fields.
+    name: stable wire name (default: snake_case class name, e.g. order_created); override with
+    class OrderCreated(DomainEvent, name="orders.order_created"). Wire names are unique: a second
+    class with the same name raises TypeError (redefining the same module.qualname replaces it)."""
+
+    event_name: ClassVar[str] = "domain_event"
+
+    def __init_subclass__(cls, name: str | None = None, **kwargs: Any) -> None:
+        super().__init_subclass__(**kwargs)
+        cls.event_name = name or _snake(cls.__name__)
+        existing = _EVENT_TYPES.get(cls.event_name)
+        if existing is not None and _qualified(existing) != _qualified(cls):
+            raise TypeError(
+                f"Event name {cls.event_name!r} of {_qualified(cls)} is already used by {_qualified(existing)}; "
+                f"give one of them an explicit name: class {cls.__name__}(DomainEvent, name=...)"
+            )
+        _EVENT_TYPES[cls.event_name] = cls
+
+
+def _qualified(cls: type) -> str:
+    return f"{cls.__module__}.{cls.__qualname__}"
+
+
+def event_name(event_or_type: Any) -> str:
+    """Wire name of an event (instance or type): DomainEvent.event_name, else snake_case class name."""
+    cls = event_or_type if isinstance(event_or_type, type) else type(event_or_type)
+    return getattr(cls, "event_name", None) or _snake(cls.__name__)
+
+
+def _from_json(tp: Any, value: Any) -> Any:
+    """JSON value -> tp, rebuilding nested dataclasses (also inside list[...] and Optional[...])."""
+    if value is None:
+        return None
+    origin, args = typing.get_origin(tp), typing.get_args(tp)
+    if args and type(None) in args:
+        rest = [a for a in args if a is not type(None)]
+        return _from_json(rest[0], value) if len(rest) == 1 else value
+    if origin in (list, tuple, set) and args and isinstance(value, list):
+        return origin(_from_json(args[0], v) for v in value)
+    if dataclasses.is_dataclass(tp) and isinstance(value, dict):
+        hints = typing.get_type_hints(tp)
+        fields = {f.name for f in dataclasses.fields(tp) if f.init}
+        return tp(**{k: _from_json(hints.get(k, Any), v) for k, v in value.items() if k in fields})
+    return value
+
+
+_metadata: contextvars.ContextVar[dict[str, Any]] = contextvars.ContextVar("urich_envelope_metadata", default={})
+
+
+def bind_envelope_metadata(values: dict[str, Any]) -> contextvars.Token[dict[str, Any]]:
+    """Add values (e.g. {"tenant_id": ...}) to the metadata of envelopes wrapped in this task;
+    pass the token to reset_envelope_metadata when done."""
+    return _metadata.set({**_metadata.get(), **values})
+
+
+def reset_envelope_metadata(token: contextvars.Token[dict[str, Any]]) -> None:
+    _metadata.reset(token)
+
+
+@dataclass(frozen=True)
+class EventEnvelope:
+    """
+    Event on the wire (adapters, outbox): id for deduplication, type name for routing,
+    UTC occurred_at (ISO 8601), the event fields as payload and metadata for consumers
+    (tenant, correlation ids). EventEnvelope.wrap(event) builds one; envelope.unwrap() gives
+    the typed event back.
+    """
+
+    event_id: str
+    event_type: str
+    occurred_at: str
+    payload: dict[str, Any]
+    metadata: dict[str, Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def wrap(cls, event: object, *, metadata: dict[str, Any] | None = None) -> EventEnvelope:
+        """metadata is merged over values bound with bind_envelope_metadata."""
+        data = dataclasses.asdict(event) if dataclasses.is_dataclass(event) else dict(getattr(event, "__dict__", {}))
+        return cls(
+            event_id=uuid.uuid4().hex,
+            event_type=event_name(event),
+            occurred_at=datetime.now(timezone.utc).isoformat(),
+            payload=json.loads(json.dumps(data, default=str)),
+            metadata={**_metadata.get(), **(metadata or {})},
+        )
+
+    def unwrap(self, event_type: type | None = None) -> Any:
+        """Typed event: event_type, or the DomainEvent subclass registered under self.event_type.
+        ValueError names the event type when it is unknown or the payload doesn't fit."""
+        cls = event_type or _EVENT_TYPES.get(self.event_type)
+        if cls is None:
+            raise ValueError(f"Unknown event type {self.event_type!r}")
+        try:
+            return _from_json(cls, self.payload)
+        except TypeError as e:
+            raise ValueError(f"Invalid {self.event_type} payload: {e}") from e
+
+    def to_dict(self) -> dict[str, Any]:
+        return dataclasses.asdict(self)
+
+    def to_json(self) -> str:
+        return json.dumps(self.to_dict())
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> EventEnvelope:
+        try:
+            return cls(
+                event_id=data["event_id"],
+                event_type=data["event_type"],
+                occurred_at=data["occurred_at"],
+                payload=data["payload"],
+                metadata=data.get("metadata") or {},
+            )
+        except (KeyError, TypeError) as e:
+            raise ValueError(f"Invalid event envelope: {e}") from e
+
+    @classmethod
+    def from_json(cls, raw: str | bytes) -> EventEnvelope:
+        data = json.loads(raw)
+        if not isinstance(data, dict):
+            raise ValueError("Invalid event envelope: not a JSON object")
+        return cls.from_dict(data)
+
+
+class EventPayloadTooLarge(ValueError):
+    """Event rejected at publish: serialized payload exceeds the configured limit."""
+
+    def __init__(self, event_type: str, size: int, limit: int) -> None:
+        self.event_type = event_type
+        self.size = size
+        self.limit = limit
+        super().__init__(f"Event {event_type} payload is {size} bytes, limit is {limit}")
+
+
+FAIL_FAST = "fail_fast"
+DELIVER_ALL = "deliver_all"
+CONCURRENT = "concurrent"
+DELIVERY_POLICIES = (FAIL_FAST, DELIVER_ALL, CONCURRENT)
+
+
+class EventDeliveryError(Exception):
+    """One or more handlers failed under deliver_all / concurrent; .errors is [(handler, exception)]."""
+
+    def __init__(self, event_type: str, errors: list[tuple[Callable[..., Any], BaseException]]) -> None:
+        self.event_type = event_type
+        self.errors = errors
+        names = ", ".join(getattr(h, "__qualname__", repr(h)) for h, _ in errors)
+        super().__init__(f"{len(errors)} handler(s) failed for {event_type}: {names}")
+
+
+def event_payload_size(event: object) -> int:
+    """Size in bytes of the event serialized as JSON (dataclass fields or __dict__)."""
+    data = dataclasses.asdict(event) if dataclasses.is_dataclass(event) else getattr(event, "__dict__", {})
+    return len(json.dumps(data, default=str).encode())
+
+
+def check_event_payload_size(event: object, max_bytes: int | None) -> None:
+    """Raise EventPayloadTooLarge if max_bytes is set and the event is larger."""
+    if max_bytes is None:
+        return
+    size = event_payload_size(event)
+    if size > max_bytes:
+        raise EventPayloadTooLarge(type(event).__name__, size, max_bytes)
 
 
 def in_process_dispatcher() -> "InProcessEventDispatcher":
-    """Factory for in-process event dispatcher."""
+    """Factory for in-process event dispatcher. Deprecated: use EventBusModule().in_memory()."""
+    from urich.core.deprecation import warn
+
+    warn(
+        "events.in_process_dispatcher",
+        "in_process_dispatcher() is deprecated.",
+        replacement="EventBusModule().in_memory() or InProcessEventDispatcher()",
+    )
     return InProcessEventDispatcher()
 
 
+async def _invoke(handler: Callable[..., Any], event: object) -> None:
+    try:
+        result = handler(event)
+        if hasattr(result, "__await__"):
+            await result
+    except Exception as e:
+        # Keep the exception type for callers; the note names the failing subscriber in tracebacks.
+        e.add_note(f"in event handler {getattr(handler, '__qualname__', repr(handler))} for {type(event).__name__}")
+        raise
+
+
 class InProcessEventDispatcher:
-    """Dispatcher: subscribe by event type, publish invokes handlers."""
+    """Dispatcher: subscribe by event type, publish invokes handlers.
+    max_payload_bytes: reject oversized events before any handler runs (None = no limit).
+    delivery: "fail_fast" (default: in order, first error stops delivery), "deliver_all" (in order,
+    every handler runs, failures raised together as EventDeliveryError) or "concurrent" (handlers
+    run together, at most `concurrency` at a time, failures raised as EventDeliveryError;
+    a cancelled handler cancels publish).
+    """
 
-    def __init__(self) -> None:
+    def __init__(
+        self,
+        max_payload_bytes: int | None = None,
+        *,
+        delivery: str = FAIL_FAST,
+        concurrency: int | None = None,
+    ) -> None:
+        if delivery not in DELIVERY_POLICIES:
+            raise ValueError(f"delivery must be one of {', '.join(DELIVERY_POLICIES)}")
         self._handlers: dict[type, list[Callable[..., Any]]] = {}
+        self._max_payload_bytes = max_payload_bytes
+        self._delivery = delivery
+        self._concurrency = concurrency
 
     def subscribe(self, event_type: type, handler: Callable[..., Any]) -> None:
         if event_type not in self._handlers:
             self._handlers[event_type] = []
         self._handlers[event_type].append(handler)
 
-    async def publish(self, event: object) -> None:
-        event_type = type(event)
-        for handler in self._handlers.get(event_type, []):
-            if callable(handler):
-                result = handler(event)
-                if hasattr(result, "__await__"):
-                    await result
+    async def publish(self, event: object, *, delivery: str | None = None) -> None:
+        """Invoke handlers of type(event). delivery overrides the dispatcher's policy for this call."""
+        check_event_payload_size(event, self._max_payload_bytes)
+        policy = delivery or self._delivery
+        if policy not in DELIVERY_POLICIES:
+            raise ValueError(f"delivery must be one of {', '.join(DELIVERY_POLICIES)}")
+        handlers = [h for h in self._handlers.get(type(event), []) if callable(h)]
+        if policy == FAIL_FAST:
+            for handler in handlers:
+                await _invoke(handler, event)
+            return
+        errors: list[tuple[Callable[..., Any], BaseException]] = []
+        if policy == DELIVER_ALL:
+            for handler in handlers:
+                try:
+                    await _invoke(handler, event)
+                except Exception as e:
+                    errors.append((handler, e))
+        else:
+            semaphore = asyncio.Semaphore(self._concurrency) if self._concurrency else None
+
+            async def run(handler: Callable[..., Any]) -> None:
+                if semaphore is None:
+                    await _invoke(handler, event)
+                    return
+                async with semaphore:
+                    await _invoke(handler, event)
+
+            results = await asyncio.gather(*(run(h) for h in handlers), return_exceptions=True)
+            for result in results:
+                # Cancellation (and KeyboardInterrupt, SystemExit) is not a handler failure: propagate it.
+                if isinstance(result, BaseException) and not isinstance(result, Exception):
+                    raise result
+            errors = [(h, r) for h, r in zip(handlers, results) if isinstance(r, Exception)]
+        if errors:
+            raise EventDeliveryError(type(event).__name__, errors)
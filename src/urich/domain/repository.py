@@ -1,10 +1,34 @@
-"""Repository — interface for aggregate persistence."""
+"""Repository — interface for aggregate persistence, with optional optimistic concurrency."""
+import copy
 from abc import ABC, abstractmethod
-from typing import Generic, Optional, TypeVar
+from typing import Any, Generic, Optional, Protocol, TypeVar, runtime_checkable
 
 T = TypeVar("T")
 
 
+@runtime_checkable
+class Versioned(Protocol):
+    """Aggregate with a version: the number of saves it has seen (0 before the first save_versioned).
+    A dataclass field `version: int = 0` is enough."""
+
+    version: int
+
+
+class ConcurrencyError(Exception):
+    """The aggregate changed since it was loaded: stored version differs from expected_version.
+    The HTTP layer answers 409 CONFLICT."""
+
+    def __init__(self, aggregate_type: str, id: Any, expected_version: int, actual_version: int | None) -> None:
+        self.aggregate_type = aggregate_type
+        self.id = id
+        self.expected_version = expected_version
+        self.actual_version = actual_version
+        super().__init__(
+            f"{aggregate_type} {id!r} was modified concurrently "
+            f"(expected version {expected_version}, found {actual_version})"
+        )
+
+
 class Repository(ABC, Generic[T]):
     """Repository interface: get by id, add new, save existing."""
 
@@ -19,3 +43,60 @@ class Repository(ABC, Generic[T]):
     @abstractmethod
     async def save(self, aggregate: T) -> None:
         ...
+
+    async def save_versioned(self, aggregate: T, expected_version: int) -> None:
+        """Save only if the stored version is still expected_version, then aggregate.version is
+        expected_version + 1; otherwise ConcurrencyError. This default can't check the store: it
+        sets the version and calls save(). Stores that can check override it."""
+        previous = getattr(aggregate, "version", None)
+        setattr(aggregate, "version", expected_version + 1)
+        try:
+            await self.save(aggregate)
+        except BaseException:
+            setattr(aggregate, "version", previous)
+            raise
+
+
+def _snapshot(aggregate: T) -> T:
+    """Deep copy without recorded events (those belong to the caller's instance, see UnitOfWork)."""
+    snapshot = copy.deepcopy(aggregate)
+    if "_pending_events" in getattr(snapshot, "__dict__", {}):
+        snapshot.__dict__["_pending_events"] = []
+    return snapshot
+
+
+class InMemoryRepository(Repository[T], Generic[T]):
+    """
+    Repository in a dict keyed by aggregate.id (tests, prototypes). Stores and returns copies, so
+    two handlers loading the same id get separate objects, as with a database; save_versioned
+    checks the stored version.
+    """
+
+    def __init__(self) -> None:
+        self._items: dict[str, T] = {}
+
+    async def get(self, id: str) -> Optional[T]:
+        item = self._items.get(str(id))
+        return _snapshot(item) if item is not None else None
+
+    async def add(self, aggregate: T) -> None:
+        key = str(getattr(aggregate, "id"))
+        if key in self._items:
+            raise ValueError(f"{type(aggregate).__name__} {key!r} already exists")
+        self._items[key] = _snapshot(aggregate)
+
+    async def save(self, aggregate: T) -> None:
+        key = str(getattr(aggregate, "id"))
+        if key not in self._items:
+            raise LookupError(f"{type(aggregate).__name__} {key!r} not found")
+        self._items[key] = _snapshot(aggregate)
+
+    async def save_versioned(self, aggregate: T, expected_version: int) -> None:
+        key = str(getattr(aggregate, "id"))
+        stored = self._items.get(key)
+        if stored is None:
+            raise LookupError(f"{type(aggregate).__name__} {key!r} not found")
+        actual = getattr(stored, "version", 0)
+        if actual != expected_version:
+            raise ConcurrencyError(type(aggregate).__name__, key, expected_version, actual)
+        await super().save_versioned(aggregate, expected_version)
@@ -1,11 +1,39 @@
 from urich.events.event_bus_module import EventBusModule
-from urich.events.outbox import OutboxModule, OutboxPublisher, OutboxStorage
+from urich.events.outbox import CallOutbox, OutboundCall, OutboxModule, OutboxPublisher, OutboxStorage
+from urich.events.outbox_stores import InMemoryOutbox, JsonFileOutbox, relay_outbox
 from urich.events.protocol import EventBusAdapter
+from urich.events.redis_bus import RedisEventBus
+from urich.events.retry import (
+    DeadLetter,
+    DeadLetterSink,
+    InMemoryDeadLetterSink,
+    LoggingDeadLetterSink,
+    RetryPolicy,
+    with_retry,
+)
+from urich.events.topic_hub import Lagged, Subscription, TopicHub
+from urich.events.unit_of_work import UnitOfWork
 
 __all__ = [
     "EventBusModule",
     "EventBusAdapter",
+    "RedisEventBus",
     "OutboxModule",
     "OutboxStorage",
     "OutboxPublisher",
+    "CallOutbox",
+    "OutboundCall",
+    "InMemoryOutbox",
+    "JsonFileOutbox",
+    "relay_outbox",
+    "RetryPolicy",
+    "with_retry",
+    "DeadLetter",
+    "DeadLetterSink",
+    "InMemoryDeadLetterSink",
+    "LoggingDeadLetterSink",
+    "UnitOfWork",
+    "TopicHub",
+    "Subscription",
+    "Lagged",
 ]
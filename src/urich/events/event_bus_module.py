@@ -10,6 +10,7 @@ from urich.core.app import Application
 from urich.core.module import Module
 from urich.domain.events import EventBus, InProcessEventDispatcher
 from urich.events.protocol import EventBusAdapter
+from urich.events.retry import DeadLetterSink
 
 
 class EventBusModule(Module):
@@ -20,21 +21,58 @@ class EventBusModule(Module):
 
     def __init__(self) -> None:
         self._adapter: EventBusAdapter | None = None
+        self._dead_letter: DeadLetterSink | None = None
 
     def adapter(self, impl: EventBusAdapter) -> EventBusModule:
-        """Use custom implementation (protocol: publish, subscribe)."""
+        """Use custom implementation (protocol: publish, subscribe). Optional async start() / close()
+        run on app startup / shutdown."""
         self._adapter = impl
         return self
 
-    def in_memory(self) -> EventBusModule:
-        """In-memory adapter out of the box for prototypes."""
-        self._adapter = InProcessEventDispatcher()
+    def in_memory(
+        self,
+        *,
+        max_payload_bytes: int | None = None,
+        delivery: str = "fail_fast",
+        concurrency: int | None = None,
+    ) -> EventBusModule:
+        """In-memory adapter out of the box for prototypes.
+        max_payload_bytes: publish fails fast with EventPayloadTooLarge for larger events.
+        delivery: "fail_fast", "deliver_all" or "concurrent" (see InProcessEventDispatcher).
+        """
+        self._adapter = InProcessEventDispatcher(
+            max_payload_bytes=max_payload_bytes, delivery=delivery, concurrency=concurrency
+        )
+        return self
+
+    def dead_letter(self, sink: DeadLetterSink) -> EventBusModule:
+        """Where events go when an on_event(..., retry=...) handler fails its last attempt
+        (default: logged). Registered in the container as DeadLetterSink."""
+        self._dead_letter = sink
         return self
 
     def register_into(self, app: Application) -> None:
+        if self._dead_letter is not None:
+            app.container.register_instance(DeadLetterSink, self._dead_letter)
         if self._adapter is None:
             self._adapter = InProcessEventDispatcher()
+        # A DomainModule registered earlier created a default in-process bus; carry its
+        # on_event subscriptions over so events published through the adapter reach them.
+        try:
+            previous = app.container.resolve(EventBus)
+        except KeyError:
+            previous = None
+        if isinstance(previous, InProcessEventDispatcher) and previous is not self._adapter:
+            for event_type, handlers in previous._handlers.items():
+                for handler in handlers:
+                    self._adapter.subscribe(event_type, handler)
         app.container.register_instance(EventBus, self._adapter)
         # backward compat: also register by InProcessEventDispatcher type when in-memory
         if isinstance(self._adapter, InProcessEventDispatcher):
             app.container.register_instance(InProcessEventDispatcher, self._adapter)
+        # Adapters with background work (e.g. RedisEventBus) follow the app lifespan.
+        router = app.starlette.router
+        if callable(getattr(self._adapter, "start", None)):
+            router.on_startup.append(self._adapter.start)
+        if callable(getattr(self._adapter, "close", None)):
+            router.on_shutdown.append(self._adapter.close)
@@ -4,6 +4,10 @@ Implementations (DB schema, transport) — user or separate package.
 """
 from __future__ import annotations
 
+import dataclasses
+import uuid
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
 from typing import TYPE_CHECKING, Any, Protocol, runtime_checkable
 
 from urich.domain.events import DomainEvent
@@ -37,10 +41,68 @@ class OutboxPublisher(Protocol):
         ...
 
 
+@dataclass
+class OutboundCall:
+    """
+    An RPC call waiting in the outbox (record kind "rpc_call", next to event envelopes).
+    attempts counts failed deliveries; next_attempt_at is a time.time() timestamp.
+    """
+
+    service: str
+    method: str
+    params: dict[str, Any]
+    call_id: str = field(default_factory=lambda: uuid.uuid4().hex)
+    created_at: str = field(default_factory=lambda: datetime.now(timezone.utc).isoformat())
+    attempts: int = 0
+    next_attempt_at: float = 0.0
+    last_error: str | None = None
+
+    kind = "rpc_call"
+
+    def to_dict(self) -> dict[str, Any]:
+        return {"kind": self.kind, **dataclasses.asdict(self)}
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> OutboundCall:
+        try:
+            return cls(
+                service=data["service"],
+                method=data["method"],
+                params=data["params"],
+                call_id=data["call_id"],
+                created_at=data["created_at"],
+                attempts=int(data.get("attempts", 0)),
+                next_attempt_at=float(data.get("next_attempt_at", 0.0)),
+                last_error=data.get("last_error"),
+            )
+        except (KeyError, TypeError, ValueError) as e:
+            raise ValueError(f"Invalid outbox call record: {e}") from e
+
+
+@runtime_checkable
+class CallOutbox(Protocol):
+    """
+    Outbox that also stores outbound RPC calls (ReliableRpc). InMemoryOutbox and JsonFileOutbox
+    implement it; a call stays pending until finish_calls, whatever the outcome.
+    """
+
+    async def append_calls(self, calls: list[OutboundCall]) -> None:
+        ...
+
+    async def fetch_pending_calls(self) -> list[OutboundCall]:
+        ...
+
+    async def update_call(self, call: OutboundCall) -> None:
+        ...
+
+    async def finish_calls(self, ids: list[str]) -> None:
+        ...
+
+
 class OutboxModule:
     """
-    Outbox building block: configure via .storage(...) and .publisher(...).
-    Register via app.register(outbox). Implementations by user.
+    Outbox building block: configure via .storage(...) and .publisher(...), or .in_memory() /
+    .json_file(path) for the bundled implementations. Register via app.register(outbox).
     """
 
     def __init__(self) -> None:
@@ -55,8 +117,25 @@ class OutboxModule:
         self._publisher = impl
         return self
 
+    def in_memory(self) -> OutboxModule:
+        """InMemoryOutbox as storage and publisher (tests, single-process apps)."""
+        from urich.events.outbox_stores import InMemoryOutbox
+
+        outbox = InMemoryOutbox()
+        return self.storage(outbox).publisher(outbox)
+
+    def json_file(self, path: Any, *, compact_after: int = 1000) -> OutboxModule:
+        """JsonFileOutbox at path as storage and publisher (survives restarts)."""
+        from urich.events.outbox_stores import JsonFileOutbox
+
+        outbox = JsonFileOutbox(path, compact_after=compact_after)
+        return self.storage(outbox).publisher(outbox)
+
     def register_into(self, app: Application) -> None:
         if self._storage is not None:
             app.container.register_instance(OutboxStorage, self._storage)
         if self._publisher is not None:
             app.container.register_instance(OutboxPublisher, self._publisher)
+        from urich.events.unit_of_work import UnitOfWork
+
+        app.container.register(UnitOfWork, lambda c=app.container: UnitOfWork.from_container(c), singleton=False)
@@ -0,0 +1,277 @@
+"""
+Ready outbox implementations: InMemoryOutbox (tests, single process) and JsonFileOutbox
+(JSON lines on disk, survives restarts). Both are OutboxStorage and OutboxPublisher at once;
+records are EventEnvelopes and mark_published takes their event_ids. Both are CallOutbox too:
+outbound RPC calls (OutboundCall) are kept apart from events.
+"""
+from __future__ import annotations
+
+import asyncio
+import dataclasses
+import json
+import os
+from pathlib import Path
+from typing import Any
+
+from urich.domain.events import EventBus, EventEnvelope
+from urich.events.outbox import OutboundCall
+from urich.events.retry import DeadLetter, DeadLetterSink, RetryPolicy, call_with_retry
+
+
+def _envelope(event: Any) -> EventEnvelope:
+    return event if isinstance(event, EventEnvelope) else EventEnvelope.wrap(event)
+
+
+class InMemoryOutbox:
+    """Outbox in a list. Appending an envelope whose event_id is already stored is a no-op."""
+
+    def __init__(self) -> None:
+        self._records: list[EventEnvelope] = []
+        self._ids: set[str] = set()
+        self._published: set[str] = set()
+        self._calls: dict[str, OutboundCall] = {}
+        self._finished_calls: set[str] = set()
+
+    async def append(self, events: list[Any], *, connection: Any = None) -> None:
+        for event in events:
+            envelope = _envelope(event)
+            if envelope.event_id in self._ids:
+                continue
+            self._ids.add(envelope.event_id)
+            self._records.append(envelope)
+
+    async def fetch_pending(self) -> list[EventEnvelope]:
+        """Unpublished envelopes in append order."""
+        return [r for r in self._records if r.event_id not in self._published]
+
+    async def mark_published(self, ids: list[Any]) -> None:
+        self._published.update(i for i in ids if i in self._ids)
+
+    @property
+    def records(self) -> list[EventEnvelope]:
+        """Everything appended, published or not."""
+        return list(self._records)
+
+    async def append_calls(self, calls: list[OutboundCall]) -> None:
+        for call in calls:
+            if call.call_id not in self._calls and call.call_id not in self._finished_calls:
+                self._calls[call.call_id] = dataclasses.replace(call)
+
+    async def fetch_pending_calls(self) -> list[OutboundCall]:
+        """Copies of the unfinished calls in append order; changes are stored by update_call."""
+        return [dataclasses.replace(c) for c in self._calls.values()]
+
+    async def update_call(self, call: OutboundCall) -> None:
+        if call.call_id in self._calls:
+            self._calls[call.call_id] = dataclasses.replace(call)
+
+    async def finish_calls(self, ids: list[str]) -> None:
+        for i in ids:
+            if self._calls.pop(i, None) is not None:
+                self._finished_calls.add(i)
+
+    @property
+    def finished_calls(self) -> set[str]:
+        """call_ids delivered or dead-lettered."""
+        return set(self._finished_calls)
+
+
+class JsonFileOutbox:
+    """
+    Outbox in a JSON lines file (one envelope per line) plus a sidecar "<path>.published" with
+    one published event_id per line. A torn trailing line (crash mid-write) is truncated on load;
+    any other unreadable line raises ValueError. File I/O runs in a worker thread, so the event loop
+    is not blocked by writes and fsyncs. Only pending envelopes stay in memory: once compact_after
+    lines are obsolete, the file is rewritten with the pending ones and the sidecar emptied.
+    Duplicate appends (same event_id) are detected among records not yet compacted.
+    Outbound calls are lines with "kind": "rpc_call"; update_call appends the new state (the last
+    line of a call_id wins) and finish_calls writes the call_id to the sidecar.
+    """
+
+    def __init__(self, path: str | os.PathLike[str], *, compact_after: int = 1000) -> None:
+        self.path = Path(path)
+        self.published_path = self.path.with_name(self.path.name + ".published")
+        self.compact_after = compact_after
+        self._records: dict[str, EventEnvelope] | None = None  # event_id -> envelope, pending only
+        self._calls: dict[str, OutboundCall] = {}  # call_id -> latest state, pending only
+        self._published: set[str] = set()  # published event ids / finished call ids still in the file
+        self._superseded = 0  # call lines replaced by a later update
+        self._lock = asyncio.Lock()
+
+    async def _load(self) -> dict[str, EventEnvelope]:
+        if self._records is None:
+            self._records = await asyncio.to_thread(self._read)
+        return self._records
+
+    def _parse(self, line: bytes) -> EventEnvelope | OutboundCall:
+        data = json.loads(line)
+        if not isinstance(data, dict):
+            raise ValueError("not a JSON object")
+        if data.get("kind") == OutboundCall.kind:
+            return OutboundCall.from_dict(data)
+        return EventEnvelope.from_dict(data)
+
+    def _read(self) -> dict[str, EventEnvelope]:
+        records: list[EventEnvelope] = []
+        calls: dict[str, OutboundCall] = {}
+        superseded = 0
+        if self.path.exists():
+            raw = self.path.read_bytes()
+            lines = raw.split(b"\n")
+            good_end = 0
+            for index, line in enumerate(lines):
+                if not line.strip():
+                    good_end += len(line) + 1
+                    continue
+                last = index == len(lines) - 1
+                try:
+                    record = self._parse(line)
+                except ValueError:
+                    if last:
+                        # Torn write: no newline after a partial record. Drop it.
+                        with self.path.open("r+b") as f:
+                            f.truncate(good_end)
+                        break
+                    raise ValueError(f"{self.path}: unreadable outbox record on line {index + 1}")
+                if isinstance(record, OutboundCall):
+                    superseded += record.call_id in calls
+                    calls[record.call_id] = record
+                else:
+                    records.append(record)
+                good_end += len(line) + 1
+            if raw and not raw.endswith(b"\n") and self.path.stat().st_size == len(raw):
+                with self.path.open("ab") as f:
+                    f.write(b"\n")  # complete last record without newline: keep the next append separate
+        ids = {r.event_id for r in records} | set(calls)
+        published: set[str] = set()
+        if self.published_path.exists():
+            published = {
+                line.strip() for line in self.published_path.read_text().splitlines() if line.strip() in ids
+            }
+        self._published = published
+        self._superseded = superseded
+        self._calls = {i: c for i, c in calls.items() if i not in published}
+        return {r.event_id: r for r in records if r.event_id not in published}
+
+    def _append_lines(self, path: Path, text: str) -> None:
+        with path.open("a", encoding="utf-8") as f:
+            f.write(text)
+            f.flush()
+            os.fsync(f.fileno())
+
+    def _compact(self, pending: list[EventEnvelope | OutboundCall]) -> None:
+        """Rewrite the file with pending records only, then empty the sidecar. A crash in between
+        leaves sidecar ids that are no longer in the file; _read ignores them."""
+        tmp = self.path.with_name(self.path.name + ".tmp")
+        with tmp.open("w", encoding="utf-8") as f:
+            f.write("".join(json.dumps(e.to_dict()) + "\n" for e in pending))
+            f.flush()
+            os.fsync(f.fileno())
+        os.replace(tmp, self.path)
+        with self.published_path.open("w", encoding="utf-8") as f:
+            f.flush()
+            os.fsync(f.fileno())
+
+    async def append(self, events: list[Any], *, connection: Any = None) -> None:
+        async with self._lock:
+            records = await self._load()
+            new: dict[str, EventEnvelope] = {}
+            for envelope in (_envelope(event) for event in events):
+                if envelope.event_id not in records and envelope.event_id not in self._published:
+                    new.setdefault(envelope.event_id, envelope)
+            if not new:
+                return
+            text = "".join(json.dumps(e.to_dict()) + "\n" for e in new.values())
+            await asyncio.to_thread(self._append_lines, self.path, text)
+            records.update(new)
+
+    async def fetch_pending(self) -> list[EventEnvelope]:
+        """Unpublished envelopes in append order."""
+        async with self._lock:
+            return list((await self._load()).values())
+
+    async def mark_published(self, ids: list[Any]) -> None:
+        async with self._lock:
+            records = await self._load()
+            fresh = list(dict.fromkeys(i for i in ids if i in records))
+            if not fresh:
+                return
+            await asyncio.to_thread(self._append_lines, self.published_path, "".join(f"{i}\n" for i in fresh))
+            for i in fresh:
+                del records[i]
+            self._published.update(fresh)
+            await self._maybe_compact(records)
+
+    async def _maybe_compact(self, records: dict[str, EventEnvelope]) -> None:
+        if len(self._published) + self._superseded >= self.compact_after:
+            await asyncio.to_thread(self._compact, [*records.values(), *self._calls.values()])
+            self._published = set()
+            self._superseded = 0
+
+    async def append_calls(self, calls: list[OutboundCall]) -> None:
+        async with self._lock:
+            await self._load()
+            new = {
+                c.call_id: c for c in calls if c.call_id not in self._calls and c.call_id not in self._published
+            }
+            if not new:
+                return
+            text = "".join(json.dumps(c.to_dict()) + "\n" for c in new.values())
+            await asyncio.to_thread(self._append_lines, self.path, text)
+            self._calls.update((i, dataclasses.replace(c)) for i, c in new.items())
+
+    async def fetch_pending_calls(self) -> list[OutboundCall]:
+        """Copies of the unfinished calls in append order; changes are stored by update_call."""
+        async with self._lock:
+            await self._load()
+            return [dataclasses.replace(c) for c in self._calls.values()]
+
+    async def update_call(self, call: OutboundCall) -> None:
+        async with self._lock:
+            records = await self._load()
+            if call.call_id not in self._calls:
+                return
+            await asyncio.to_thread(self._append_lines, self.path, json.dumps(call.to_dict()) + "\n")
+            self._calls[call.call_id] = dataclasses.replace(call)
+            self._superseded += 1
+            await self._maybe_compact(records)
+
+    async def finish_calls(self, ids: list[str]) -> None:
+        async with self._lock:
+            records = await self._load()
+            fresh = list(dict.fromkeys(i for i in ids if i in self._calls))
+            if not fresh:
+                return
+            await asyncio.to_thread(self._append_lines, self.published_path, "".join(f"{i}\n" for i in fresh))
+            for i in fresh:
+                del self._calls[i]
+            self._published.update(fresh)
+            await self._maybe_compact(records)
+
+
+async def relay_outbox(
+    outbox: Any,
+    event_bus: EventBus,
+    *,
+    retry: RetryPolicy | None = None,
+    dead_letter: DeadLetterSink | None = None,
+) -> int:
+    """Publish pending envelopes as typed events on event_bus, marking each one published right
+    after it went out (a failure leaves it and the rest pending). Returns how many were sent.
+    retry: attempts per envelope. With dead_letter, an envelope still failing after the last attempt
+    is put there and marked published, and the relay goes on with the next one."""
+    sent = 0
+    for envelope in await outbox.fetch_pending():
+        event = envelope.unwrap()
+        error, attempts = await call_with_retry(lambda: event_bus.publish(event), retry or RetryPolicy(max_attempts=1))
+        if error is not None:
+            if dead_letter is None:
+                raise error
+            await dead_letter.put(DeadLetter(
+                envelope.event_type, event, "outbox", error, attempts,
+                metadata={"event_id": envelope.event_id, **envelope.metadata},
+            ))
+        else:
+            sent += 1
+        await outbox.mark_published([envelope.event_id])
+    return sent
@@ -0,0 +1,123 @@
+"""
+RedisEventBus — EventBusAdapter over Redis pub/sub (optional, needs redis: pip install "urich[redis]").
+Events travel as EventEnvelope JSON on one channel per event type; subscribers get typed events.
+"""
+from __future__ import annotations
+
+import asyncio
+import logging
+from typing import Any, Callable
+
+from urich.domain.events import EventEnvelope, _invoke, event_name
+
+logger = logging.getLogger("urich.events.redis")
+
+
+class RedisEventBus:
+    """
+    publish(event) sends the envelope to "<channel_prefix><event_name>"; a failed connection
+    raises (redis.ConnectionError) instead of dropping the event. subscribe() handlers are fed
+    by a background listener started with start() (EventBusModule does it on app startup),
+    which reconnects with exponential backoff between reconnect_min and reconnect_max seconds.
+    Handler errors are logged to "urich.events.redis" and don't stop the listener.
+    """
+
+    def __init__(
+        self,
+        url: str = "redis://localhost:6379/0",
+        *,
+        channel_prefix: str = "urich.events.",
+        reconnect_min: float = 0.5,
+        reconnect_max: float = 30.0,
+        client: Any = None,
+    ) -> None:
+        self.url = url
+        self.channel_prefix = channel_prefix
+        self._reconnect_min = reconnect_min
+        self._reconnect_max = reconnect_max
+        self._client = client
+        self._handlers: dict[str, list[Callable[..., Any]]] = {}
+        self._types: dict[str, type] = {}
+        self._listener: asyncio.Task[None] | None = None
+        self._pubsub: Any = None
+        self.connected = False
+
+    def _redis(self) -> Any:
+        if self._client is None:
+            try:
+                import redis.asyncio as redis
+            except ImportError as e:
+                raise ImportError('RedisEventBus needs redis: pip install "urich[redis]"') from e
+            self._client = redis.Redis.from_url(self.url)
+        return self._client
+
+    def channel(self, event_type: type | str) -> str:
+        return self.channel_prefix + (event_type if isinstance(event_type, str) else event_name(event_type))
+
+    async def publish(self, event: object) -> None:
+        envelope = EventEnvelope.wrap(event)
+        await self._redis().publish(self.channel(envelope.event_type), envelope.to_json())
+
+    def subscribe(self, event_type: type, handler: Callable[..., Any]) -> None:
+        channel = self.channel(event_type)
+        self._types[channel] = event_type
+        self._handlers.setdefault(channel, []).append(handler)
+        if self._pubsub is not None and self.connected:
+            asyncio.get_running_loop().create_task(self._pubsub.subscribe(channel))
+
+    async def start(self) -> None:
+        """Start the listener task (no-op without subscriptions or when already running)."""
+        if self._listener is None and self._handlers:
+            self._listener = asyncio.create_task(self._listen())
+
+    async def close(self) -> None:
+        if self._listener is not None:
+            self._listener.cancel()
+            try:
+                await self._listener
+            except asyncio.CancelledError:
+                pass
+            self._listener = None
+        if self._client is not None:
+            await self._client.aclose()
+            self._client = None
+
+    async def _listen(self) -> None:
+        delay = self._reconnect_min
+        while True:
+            try:
+                self._pubsub = self._redis().pubsub()
+                await self._pubsub.subscribe(*self._handlers)
+                self.connected = True
+                delay = self._reconnect_min
+                async for message in self._pubsub.listen():
+                    if message.get("type") == "message":
+                        await self._dispatch(message)
+            except asyncio.CancelledError:
+                raise
+            except Exception as e:
+                logger.warning("Redis event listener disconnected (%s); reconnecting in %.1fs", e, delay)
+            finally:
+                self.connected = False
+                if self._pubsub is not None:
+                    try:
+                        await self._pubsub.aclose()
+                    except Exception:
+                        pass
+                    self._pubsub = None
+            await asyncio.sleep(delay)
+            delay = min(delay * 2, self._reconnect_max)
+
+    async def _dispatch(self, message: dict[str, Any]) -> None:
+        channel = message["channel"]
+        channel = channel.decode() if isinstance(channel, bytes) else channel
+        try:
+            event = EventEnvelope.from_json(message["data"]).unwrap(self._types.get(channel))
+        except ValueError as e:
+            logger.error("Dropping malformed event on %s: %s", channel, e)
+            return
+        for handler in self._handlers.get(channel, []):
+            try:
+                await _invoke(handler, event)
+            except Exception:
+                logger.exception("Event handler %s failed for %s", getattr(handler, "__qualname__", handler), channel)
@@ -0,0 +1,133 @@
+"""
+Retries for event handlers and the outbox relay, with a dead-letter sink for events whose
+delivery still fails after the last attempt.
+"""
+from __future__ import annotations
+
+import asyncio
+import functools
+import logging
+from dataclasses import dataclass, field
+from typing import Any, Awaitable, Callable, Protocol, runtime_checkable
+
+from urich.domain.events import event_name
+
+logger = logging.getLogger("urich.events.dead_letter")
+
+Sleep = Callable[[float], Awaitable[Any]]
+
+
+@dataclass(frozen=True)
+class RetryPolicy:
+    """max_attempts: total tries including the first; backoff: seconds before the second try,
+    multiplied by multiplier after each failure and capped at max_backoff."""
+
+    max_attempts: int = 3
+    backoff: float = 0.1
+    multiplier: float = 2.0
+    max_backoff: float = 30.0
+
+    def __post_init__(self) -> None:
+        if self.max_attempts < 1:
+            raise ValueError("max_attempts must be at least 1")
+
+    def delay(self, attempt: int) -> float:
+        """Seconds to wait after failed attempt number attempt (1-based)."""
+        return min(self.backoff * self.multiplier ** (attempt - 1), self.max_backoff)
+
+
+@dataclass
+class DeadLetter:
+    """An event that could not be delivered: its wire name, the event as published, the handler
+    (or "outbox") that failed, the last error and how many attempts were made."""
+
+    event_type: str
+    event: Any
+    handler: str
+    error: BaseException
+    attempts: int
+    metadata: dict[str, Any] = field(default_factory=dict)
+
+    @property
+    def code(self) -> str | None:
+        """The error's code when it is an AppError (or HttpError, RpcError), else None."""
+        code = getattr(self.error, "code", None)
+        return code if isinstance(code, str) else None
+
+
+@runtime_checkable
+class DeadLetterSink(Protocol):
+    """Receives dead letters (a table, a queue, an alert). Register one in the container or pass
+    it to on_event / relay_outbox."""
+
+    async def put(self, letter: DeadLetter) -> None:
+        ...
+
+
+class InMemoryDeadLetterSink:
+    """Keeps dead letters in .letters (tests, inspection endpoints)."""
+
+    def __init__(self) -> None:
+        self.letters: list[DeadLetter] = []
+
+    async def put(self, letter: DeadLetter) -> None:
+        self.letters.append(letter)
+
+
+class LoggingDeadLetterSink:
+    """Logs each dead letter with its traceback to the urich.events.dead_letter logger (the default)."""
+
+    async def put(self, letter: DeadLetter) -> None:
+        logger.error(
+            "Event %s dead-lettered after %s attempt(s) in %s%s",
+            letter.event_type, letter.attempts, letter.handler, f" [{letter.code}]" if letter.code else "",
+            exc_info=(type(letter.error), letter.error, letter.error.__traceback__),
+        )
+
+
+def _name(handler: Any) -> str:
+    return getattr(handler, "__qualname__", None) or type(handler).__qualname__
+
+
+async def call_with_retry(
+    fn: Callable[[], Any], policy: RetryPolicy, *, sleep: Sleep = asyncio.sleep
+) -> tuple[BaseException | None, int]:
+    """Call fn (sync or async) until it succeeds or policy.max_attempts is reached.
+    Returns (None, attempts) on success, else (last error, attempts)."""
+    error: BaseException | None = None
+    for attempt in range(1, policy.max_attempts + 1):
+        try:
+            result = fn()
+            if hasattr(result, "__await__"):
+                await result
+            return None, attempt
+        except Exception as e:
+            error = e
+            if attempt < policy.max_attempts:
+                await sleep(policy.delay(attempt))
+    return error, policy.max_attempts
+
+
+def with_retry(
+    handler: Callable[..., Any],
+    policy: RetryPolicy,
+    dead_letter: DeadLetterSink | Callable[[], DeadLetterSink] | None = None,
+    *,
+    sleep: Sleep = asyncio.sleep,
+) -> Callable[..., Any]:
+    """
+    Event handler that retries handler under policy; after the last failed attempt the event goes to
+    dead_letter (a sink, or a function returning one; LoggingDeadLetterSink if None) and the handler
+    returns normally, so publish goes on with the other subscribers.
+    """
+
+    @functools.wraps(handler)
+    async def retrying(event: object) -> None:
+        error, attempts = await call_with_retry(lambda: handler(event), policy, sleep=sleep)
+        if error is None:
+            return
+        sink = dead_letter() if callable(dead_letter) and not isinstance(dead_letter, DeadLetterSink) else dead_letter
+        letter = DeadLetter(event_name(event), event, _name(handler), error, attempts)
+        await (sink or LoggingDeadLetterSink()).put(letter)
+
+    return retrying
@@ -0,0 +1,136 @@
+"""
+TopicHub — bounded in-memory pub/sub by topic name, shared by push features (SSE, long-poll, WS).
+Registered in the container of every Application; resolve with container.resolve(TopicHub).
+"""
+from __future__ import annotations
+
+import asyncio
+from collections import deque
+from fnmatch import fnmatchcase
+from typing import Any
+
+DROP_OLDEST = "drop_oldest"
+TERMINATE = "terminate"
+
+
+class Lagged(Exception):
+    """Subscription terminated: the consumer fell behind its buffer (lag policy "terminate")."""
+
+    def __init__(self, topic: str, capacity: int) -> None:
+        self.topic = topic
+        self.capacity = capacity
+        super().__init__(f"Subscriber of {topic!r} lagged behind buffer of {capacity}")
+
+
+class Subscription:
+    """
+    Async iterator of payloads for one topic or pattern. Buffer holds at most capacity items;
+    on overflow drop_oldest discards the oldest item (counted in .lagged), terminate ends
+    the subscription with Lagged. close() (or leaving `async with`) unsubscribes.
+    """
+
+    def __init__(self, hub: TopicHub, topic: str, capacity: int, lag_policy: str) -> None:
+        self.topic = topic
+        self.capacity = capacity
+        self.lag_policy = lag_policy
+        self.lagged = 0
+        self._hub = hub
+        self._buffer: deque[tuple[str, Any]] = deque()
+        self._ready = asyncio.Event()
+        self._closed = False
+        self._error: Lagged | None = None
+
+    @property
+    def is_pattern(self) -> bool:
+        return any(c in self.topic for c in "*?[")
+
+    def _push(self, topic: str, payload: Any) -> None:
+        if self._closed:
+            return
+        if len(self._buffer) >= self.capacity:
+            if self.lag_policy == TERMINATE:
+                self._error = Lagged(self.topic, self.capacity)
+                self.close()
+                return
+            self._buffer.popleft()
+            self.lagged += 1
+        self._buffer.append((topic, payload))
+        self._ready.set()
+
+    async def next_message(self) -> tuple[str, Any]:
+        """Next (topic, payload); topic is useful for pattern subscriptions."""
+        while not self._buffer:
+            if self._error is not None:
+                raise self._error
+            if self._closed:
+                raise StopAsyncIteration
+            self._ready.clear()
+            await self._ready.wait()
+        return self._buffer.popleft()
+
+    def __aiter__(self) -> Subscription:
+        return self
+
+    async def __anext__(self) -> Any:
+        _, payload = await self.next_message()
+        return payload
+
+    def close(self) -> None:
+        if self._closed:
+            return
+        self._closed = True
+        self._ready.set()
+        self._hub._remove(self)
+
+    async def __aenter__(self) -> Subscription:
+        return self
+
+    async def __aexit__(self, *exc: Any) -> None:
+        self.close()
+
+
+class TopicHub:
+    """
+    hub.subscribe("orders.created") or pattern hub.subscribe("orders.*") -> Subscription;
+    hub.publish(topic, payload) fans out without blocking. Topics disappear when their
+    last subscriber closes; topics() lists (name, subscriber_count) for admin/metrics.
+    """
+
+    def __init__(self, *, capacity: int = 100, lag_policy: str = DROP_OLDEST) -> None:
+        if lag_policy not in (DROP_OLDEST, TERMINATE):
+            raise ValueError(f"lag_policy must be {DROP_OLDEST!r} or {TERMINATE!r}")
+        self._capacity = capacity
+        self._lag_policy = lag_policy
+        self._topics: dict[str, list[Subscription]] = {}
+        self._patterns: dict[str, list[Subscription]] = {}
+
+    def subscribe(self, topic: str, *, capacity: int | None = None, lag_policy: str | None = None) -> Subscription:
+        sub = Subscription(self, topic, capacity or self._capacity, lag_policy or self._lag_policy)
+        target = self._patterns if sub.is_pattern else self._topics
+        target.setdefault(topic, []).append(sub)
+        return sub
+
+    def publish(self, topic: str, payload: Any) -> int:
+        """Deliver payload to current subscribers of topic and matching patterns. Returns receiver count."""
+        receivers = list(self._topics.get(topic, []))
+        for pattern, subs in self._patterns.items():
+            if fnmatchcase(topic, pattern):
+                receivers.extend(subs)
+        for sub in receivers:
+            sub._push(topic, payload)
+        return len(receivers)
+
+    def topics(self) -> list[tuple[str, int]]:
+        """(topic or pattern, subscriber count), sorted by name."""
+        items = {**self._topics, **self._patterns}
+        return sorted((name, len(subs)) for name, subs in items.items())
+
+    def _remove(self, sub: Subscription) -> None:
+        target = self._patterns if sub.is_pattern else self._topics
+        subs = target.get(sub.topic)
+        if subs is None:
+            return
+        if sub in subs:
+            subs.remove(sub)
+        if not subs:
+            del target[sub.topic]
@@ -0,0 +1,68 @@
+"""
+UnitOfWork — save an aggregate and hand its recorded events to the outbox in one step.
+Without an OutboxStorage in the container, events are published on the EventBus instead.
+"""
+from __future__ import annotations
+
+from typing import Any
+
+from urich.domain.aggregate import AggregateRoot
+from urich.domain.events import EventBus
+from urich.domain.repository import Repository
+from urich.events.outbox import OutboxStorage
+
+
+class UnitOfWork:
+    """
+    Resolve from the container in a handler: await uow.save(repo, order).
+    Events are taken from the aggregate only after the repository call succeeds, so a failed
+    save leaves them recorded and nothing reaches the outbox or the bus.
+    """
+
+    def __init__(self, event_bus: EventBus | None = None, outbox: OutboxStorage | None = None) -> None:
+        self._event_bus = event_bus
+        self._outbox = outbox
+
+    @classmethod
+    def from_container(cls, container: Any) -> UnitOfWork:
+        """Use the registered OutboxStorage and EventBus, whichever exist."""
+
+        def optional(key: Any) -> Any:
+            try:
+                return container.resolve(key)
+            except KeyError:
+                return None
+
+        return cls(event_bus=optional(EventBus), outbox=optional(OutboxStorage))
+
+    async def save(
+        self,
+        repository: Repository[Any],
+        aggregate: AggregateRoot,
+        *,
+        new: bool = False,
+        connection: Any = None,
+        expected_version: int | None = None,
+    ) -> list[Any]:
+        """repository.add (new=True) or .save, then append events to the outbox (same connection)
+        or publish them. Returns the events handed over.
+        expected_version: repository.save_versioned instead; its ConcurrencyError propagates as is
+        (409 over HTTP) and the events stay recorded.
+        """
+        if new:
+            await repository.add(aggregate)
+        elif expected_version is not None:
+            await repository.save_versioned(aggregate, expected_version)
+        else:
+            await repository.save(aggregate)
+        events = aggregate.take_events()
+        if not events:
+            return events
+        if self._outbox is not None:
+            await self._outbox.append(events, connection=connection)
+        elif self._event_bus is not None:
+            for event in events:
+                await self._event_bus.publish(event)
+        else:
+            raise RuntimeError("UnitOfWork needs an OutboxStorage or an EventBus in the container")
+        return events
@@ -0,0 +1,40 @@
+from urich.http.auth import AuthModule, InvalidToken, current_claims, decode_jwt, encode_jwt
+from urich.http.chaos import ChaosModule, ChaosRule
+from urich.http.compression import CompressionModule
+from urich.http.concurrency import ConcurrencyLimitModule
+from urich.http.cors import CorsModule
+from urich.http.health import HealthModule, HealthProbe
+from urich.http.metrics import MetricsModule, MetricsRegistry
+from urich.http.static import StaticFilesModule
+from urich.http.sse import SseEvent, SseModule, SseResponse
+from urich.http.proxy import ProxyHeadersModule
+from urich.http.tenant import Tenant, TenantModule, current_tenant
+from urich.http.request_id import RequestIdLogFilter, RequestIdModule, current_request_id
+
+__all__ = [
+    "AuthModule",
+    "InvalidToken",
+    "current_claims",
+    "decode_jwt",
+    "encode_jwt",
+    "ChaosModule",
+    "ChaosRule",
+    "CompressionModule",
+    "ConcurrencyLimitModule",
+    "CorsModule",
+    "HealthModule",
+    "HealthProbe",
+    "MetricsModule",
+    "MetricsRegistry",
+    "ProxyHeadersModule",
+    "RequestIdModule",
+    "RequestIdLogFilter",
+    "current_request_id",
+    "StaticFilesModule",
+    "Tenant",
+    "TenantModule",
+    "current_tenant",
+    "SseModule",
+    "SseEvent",
+    "SseResponse",
+]
@@ -0,0 +1,206 @@
+"""
+AuthModule — JWT bearer authentication (HS256/HS384/HS512) for every route except excluded prefixes.
+Verified claims are in request.state.claims and current_claims(). Register with app.register(auth).
+"""
+from __future__ import annotations
+
+import base64
+import contextvars
+import hashlib
+import hmac
+import json
+import math
+import time
+from typing import Any, Sequence
+
+from urich.core.app import Application
+from urich.core.errors import error_response
+from urich.core.module import Module
+from urich.core.urls import under_prefix
+
+_HASHES = {"HS256": hashlib.sha256, "HS384": hashlib.sha384, "HS512": hashlib.sha512}
+
+_claims: contextvars.ContextVar[dict[str, Any] | None] = contextvars.ContextVar("urich_claims", default=None)
+
+
+def current_claims() -> dict[str, Any] | None:
+    """Verified JWT claims of the request being handled (None on excluded or unauthenticated paths)."""
+    return _claims.get()
+
+
+class InvalidToken(Exception):
+    """Token rejected; the message goes to the 401 body and WWW-Authenticate error_description."""
+
+
+def _b64decode(part: str) -> bytes:
+    return base64.urlsafe_b64decode(part + "=" * (-len(part) % 4))
+
+
+def _b64encode(data: bytes) -> str:
+    return base64.urlsafe_b64encode(data).rstrip(b"=").decode("ascii")
+
+
+def encode_jwt(claims: dict[str, Any], secret: str | bytes, *, algorithm: str = "HS256") -> str:
+    """Sign claims as a compact JWT (for tests and internal service tokens)."""
+    key = secret.encode() if isinstance(secret, str) else secret
+    header = _b64encode(json.dumps({"alg": algorithm, "typ": "JWT"}, separators=(",", ":")).encode())
+    payload = _b64encode(json.dumps(claims, separators=(",", ":")).encode())
+    signing_input = f"{header}.{payload}".encode("ascii")
+    signature = hmac.new(key, signing_input, _HASHES[algorithm]).digest()
+    return f"{header}.{payload}.{_b64encode(signature)}"
+
+
+def _numeric_date(claims: dict[str, Any], name: str) -> float | None:
+    """exp / nbf as seconds since the epoch; a JSON number (RFC 7519 NumericDate), else InvalidToken."""
+    if name not in claims:
+        return None
+    value = claims[name]
+    if isinstance(value, bool) or not isinstance(value, (int, float)) or not math.isfinite(value):
+        raise InvalidToken(f"invalid {name} claim: not a number")
+    return float(value)
+
+
+def decode_jwt(
+    token: str,
+    secret: str | bytes,
+    *,
+    algorithms: Sequence[str] = ("HS256",),
+    issuer: str | None = None,
+    audience: str | None = None,
+    leeway: float = 0,
+    now: float | None = None,
+) -> dict[str, Any]:
+    """Verify signature, exp/nbf (with leeway seconds), iss and aud; return the claims or raise InvalidToken."""
+    key = secret.encode() if isinstance(secret, str) else secret
+    try:
+        header_b64, payload_b64, signature_b64 = token.split(".")
+        header = json.loads(_b64decode(header_b64))
+        claims = json.loads(_b64decode(payload_b64))
+        signature = _b64decode(signature_b64)
+    except (ValueError, TypeError) as e:
+        raise InvalidToken("malformed token") from e
+    if not isinstance(header, dict) or not isinstance(claims, dict):
+        raise InvalidToken("malformed token")
+    alg = header.get("alg")
+    if alg not in algorithms or alg not in _HASHES:
+        raise InvalidToken(f"algorithm {alg!r} not allowed")
+    expected = hmac.new(key, f"{header_b64}.{payload_b64}".encode("ascii"), _HASHES[alg]).digest()
+    if not hmac.compare_digest(expected, signature):
+        raise InvalidToken("signature verification failed")
+    now = time.time() if now is None else now
+    exp, nbf = _numeric_date(claims, "exp"), _numeric_date(claims, "nbf")
+    if exp is not None and now > exp + leeway:
+        raise InvalidToken("token expired")
+    if nbf is not None and now < nbf - leeway:
+        raise InvalidToken("token not yet valid")
+    if issuer is not None and claims.get("iss") != issuer:
+        raise InvalidToken("invalid issuer")
+    if audience is not None:
+        aud = claims.get("aud")
+        if audience != aud and not (isinstance(aud, list) and audience in aud):
+            raise InvalidToken("invalid audience")
+    return claims
+
+
+def _error_description(message: str) -> str:
+    """RFC 6750 error_description charset (printable ASCII without " and \\); anything else becomes "?"."""
+    return "".join(c if " " <= c <= "~" and c not in '"\\' else "?" for c in message)
+
+
+class AuthMiddleware:
+    """ASGI middleware: 401 with WWW-Authenticate unless the request carries a valid bearer token."""
+
+    def __init__(self, app: Any, *, module: AuthModule, application: Application) -> None:
+        self.app = app
+        self._module = module
+        self._application = application
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        if scope["type"] != "http" or scope["method"] == "OPTIONS" or self._application.is_public(scope):
+            await self.app(scope, receive, send)
+            return
+        try:
+            claims = self._module.authenticate(scope)
+        except InvalidToken as e:
+            response = error_response(
+                401,
+                str(e),
+                code="UNAUTHORIZED",
+                headers={"WWW-Authenticate": f'Bearer error="invalid_token", error_description="{_error_description(str(e))}"'},
+            )
+            await response(scope, receive, send)
+            return
+        scope.setdefault("state", {})["claims"] = claims
+        token = _claims.set(claims)
+        try:
+            await self.app(scope, receive, send)
+        finally:
+            _claims.reset(token)
+
+
+class AuthModule(Module):
+    """
+    JWT bearer auth as object: AuthModule(secret, issuer=..., audience=...).exclude("/public").
+    OpenAPI docs and /health are excluded by default; OPTIONS (CORS preflight) is never checked.
+    Adds the OpenAPI security scheme scheme_name as default requirement; excluded prefixes and routes
+    added with public=True are public in the spec and let through here, from the same configuration.
+    """
+
+    def __init__(
+        self,
+        secret: str | bytes,
+        *,
+        algorithms: Sequence[str] = ("HS256",),
+        issuer: str | None = None,
+        audience: str | None = None,
+        leeway: float = 0,
+        exclude: Sequence[str] = ("/docs", "/openapi.json", "/health"),
+        scheme_name: str = "bearerAuth",
+    ) -> None:
+        unknown = [a for a in algorithms if a not in _HASHES]
+        if unknown:
+            raise ValueError(f"Unsupported JWT algorithms: {', '.join(unknown)} (supported: {', '.join(_HASHES)})")
+        self._secret = secret
+        self._algorithms = tuple(algorithms)
+        self._issuer = issuer
+        self._audience = audience
+        self._leeway = leeway
+        self._excluded = list(exclude)
+        self._scheme_name = scheme_name
+        self._app: Application | None = None
+
+    def exclude(self, prefix: str) -> AuthModule:
+        """Let requests to paths starting with prefix through without a token (public in the spec too)."""
+        self._excluded.append(prefix)
+        if self._app is not None:
+            self._app.public_path(prefix)
+        return self
+
+    def is_excluded(self, path: str) -> bool:
+        return any(under_prefix(path, p) for p in self._excluded)
+
+    def authenticate(self, scope: dict[str, Any]) -> dict[str, Any]:
+        """Claims from the Authorization: Bearer header, or InvalidToken."""
+        for name, value in scope.get("headers", []):
+            if name.lower() == b"authorization":
+                scheme, _, token = value.decode("latin-1").partition(" ")
+                if scheme.lower() != "bearer" or not token.strip():
+                    raise InvalidToken("expected Authorization: Bearer <token>")
+                return decode_jwt(
+                    token.strip(),
+                    self._secret,
+                    algorithms=self._algorithms,
+                    issuer=self._issuer,
+                    audience=self._audience,
+                    leeway=self._leeway,
+                )
+        raise InvalidToken("missing bearer token")
+
+    def register_into(self, app: Application) -> None:
+        self._app = app
+        app.add_security_scheme(
+            self._scheme_name, {"type": "http", "scheme": "bearer", "bearerFormat": "JWT"}, default=True
+        )
+        for prefix in self._excluded:
+            app.public_path(prefix)
+        app.starlette.add_middleware(AuthMiddleware, module=self, application=app)
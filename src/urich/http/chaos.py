@@ -0,0 +1,177 @@
+"""
+ChaosModule — failure injection for staging: latency, error responses, aborted connections.
+Configure via .rule(...) and .admin(...); register with app.register(chaos).
+Activates only when URICH_CHAOS=1 is set in the environment (never in production by accident).
+"""
+from __future__ import annotations
+
+import asyncio
+import hmac
+import logging
+import os
+import random
+from dataclasses import asdict, dataclass
+from typing import Any
+
+from starlette.requests import Request
+from starlette.responses import JSONResponse, Response
+
+from urich.core.app import Application
+from urich.core.module import Module
+from urich.core.urls import under_prefix
+
+logger = logging.getLogger("urich.chaos")
+
+ENV_FLAG = "URICH_CHAOS"
+BYPASS_HEADER = "x-chaos-bypass"
+INJECTED_HEADER = "x-chaos-injected"
+
+
+@dataclass
+class ChaosRule:
+    """Faults for requests under prefix (whole path segments). Rates are probabilities 0..1."""
+
+    prefix: str
+    latency_ms: float = 0
+    jitter_ms: float = 0
+    error_rate: float = 0
+    error_status: int = 503
+    abort_rate: float = 0
+
+
+class ChaosMiddleware:
+    """ASGI middleware applying the longest-prefix ChaosRule to each HTTP request."""
+
+    def __init__(self, app: Any, *, module: ChaosModule) -> None:
+        self.app = app
+        self._module = module
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        module = self._module
+        if scope["type"] != "http" or module.is_bypassed(scope):
+            await self.app(scope, receive, send)
+            return
+        path = scope["path"]
+        if module.admin_path is not None and path == module.admin_path:
+            await self.app(scope, receive, send)
+            return
+        rule = module.rule_for(path)
+        if rule is None:
+            await self.app(scope, receive, send)
+            return
+
+        rnd = module.random
+        injected: list[str] = []
+        delay_ms = rule.latency_ms + (rnd.uniform(0, rule.jitter_ms) if rule.jitter_ms else 0)
+        if delay_ms > 0:
+            await asyncio.sleep(delay_ms / 1000)
+            injected.append("latency")
+        if rule.abort_rate and rnd.random() < rule.abort_rate:
+            # No response at all: the handler never runs and the server ends the exchange,
+            # as when the connection drops (an exception would become a 500 instead).
+            logger.info("chaos: abort %s %s (rule %s)", scope["method"], path, rule.prefix)
+            return
+        if rule.error_rate and rnd.random() < rule.error_rate:
+            injected.append("error")
+            logger.info("chaos: %s for %s %s (rule %s)", rule.error_status, scope["method"], path, rule.prefix)
+            response = JSONResponse(
+                {"error": {"code": "CHAOS_INJECTED", "message": "Injected failure"}},
+                status_code=rule.error_status,
+                headers={INJECTED_HEADER: ",".join(injected)},
+            )
+            await response(scope, receive, send)
+            return
+        if not injected:
+            await self.app(scope, receive, send)
+            return
+
+        logger.info("chaos: %s for %s %s (rule %s)", ",".join(injected), scope["method"], path, rule.prefix)
+
+        async def send_tagged(message: dict[str, Any]) -> None:
+            if message["type"] == "http.response.start":
+                headers = list(message.get("headers", []))
+                headers.append((INJECTED_HEADER.encode(), ",".join(injected).encode()))
+                message = {**message, "headers": headers}
+            await send(message)
+
+        await self.app(scope, receive, send_tagged)
+
+
+class ChaosModule(Module):
+    """
+    Failure injection as object: per-prefix rules, header bypass, runtime admin endpoint.
+    Injected faults carry X-Chaos-Injected and are logged to "urich.chaos" so they can be told
+    apart from real failures. Without URICH_CHAOS=1 register_into does nothing.
+    """
+
+    def __init__(self, *, bypass_token: str | None = None, seed: int | None = None) -> None:
+        self._rules: list[ChaosRule] = []
+        self._bypass_token = bypass_token
+        self.admin_path: str | None = None
+        self.random = random.Random(seed)
+
+    def rule(
+        self,
+        prefix: str,
+        *,
+        latency_ms: float = 0,
+        jitter_ms: float = 0,
+        error_rate: float = 0,
+        error_status: int = 503,
+        abort_rate: float = 0,
+    ) -> ChaosModule:
+        """Add faults for paths under prefix: /api matches /api and /api/orders, not /apiv2
+        (longest prefix wins)."""
+        self._rules.append(ChaosRule(prefix, latency_ms, jitter_ms, error_rate, error_status, abort_rate))
+        return self
+
+    def admin(self, path: str = "/_chaos") -> ChaosModule:
+        """GET path returns rules; PUT path with a JSON list of rules replaces them. Every request
+        needs X-Chaos-Bypass with the bypass token, so the module must have one."""
+        if not self._bypass_token:
+            raise ValueError("ChaosModule.admin() requires a bypass_token to authenticate the endpoint")
+        self.admin_path = path
+        return self
+
+    @property
+    def rules(self) -> list[ChaosRule]:
+        return list(self._rules)
+
+    def set_rules(self, rules: list[ChaosRule]) -> None:
+        self._rules = list(rules)
+
+    def rule_for(self, path: str) -> ChaosRule | None:
+        matching = [r for r in self._rules if under_prefix(path, r.prefix)]
+        return max(matching, key=lambda r: len(r.prefix)) if matching else None
+
+    def is_bypassed(self, scope: dict[str, Any]) -> bool:
+        for name, value in scope.get("headers", []):
+            if name.decode("latin-1").lower() == BYPASS_HEADER:
+                return self._token_matches(value.decode("latin-1"))
+        return False
+
+    def _token_matches(self, token: str | None) -> bool:
+        if not self._bypass_token or token is None:
+            return False
+        return hmac.compare_digest(token.encode("utf-8"), self._bypass_token.encode("utf-8"))
+
+    def register_into(self, app: Application) -> None:
+        if os.environ.get(ENV_FLAG) != "1":
+            logger.warning("ChaosModule registered but %s=1 is not set; fault injection disabled", ENV_FLAG)
+            return
+        if self.admin_path is not None:
+            app.add_route(self.admin_path, self._make_admin_endpoint(), methods=["GET", "PUT"])
+        app.starlette.add_middleware(ChaosMiddleware, module=self)
+
+    def _make_admin_endpoint(self) -> Any:
+        async def endpoint(request: Request) -> Response:
+            if not self._token_matches(request.headers.get(BYPASS_HEADER)):
+                return JSONResponse({"error": {"code": "FORBIDDEN", "message": "Bypass token required"}}, status_code=403)
+            if request.method == "PUT":
+                try:
+                    body = await request.json()
+                    self.set_rules([ChaosRule(**item) for item in body])
+                except Exception as e:
+                    return JSONResponse({"error": {"code": "BAD_REQUEST", "message": str(e)}}, status_code=400)
+            return JSONResponse([asdict(r) for r in self._rules])
+        return endpoint
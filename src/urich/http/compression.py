@@ -0,0 +1,52 @@
+"""
+CompressionModule — gzip for responses when the client accepts it and the body is large enough.
+Register with app.register(CompressionModule(...)); exclude paths with .exclude(prefix).
+"""
+from __future__ import annotations
+
+from typing import Any
+
+from starlette.middleware.gzip import GZipMiddleware
+
+from urich.core.app import Application
+from urich.core.module import Module
+from urich.core.urls import under_prefix
+
+
+class CompressionMiddleware:
+    """GZipMiddleware for every HTTP request except paths under an excluded prefix."""
+
+    def __init__(self, app: Any, *, module: CompressionModule) -> None:
+        self.app = app
+        self._module = module
+        self._gzip = GZipMiddleware(app, minimum_size=module.minimum_size, compresslevel=module.level)
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        if scope["type"] == "http" and not self._module.is_excluded(scope["path"]):
+            await self._gzip(scope, receive, send)
+            return
+        await self.app(scope, receive, send)
+
+
+class CompressionModule(Module):
+    """
+    Compresses responses with Content-Encoding: gzip when the request sends Accept-Encoding: gzip
+    and the body is at least minimum_size bytes. Responses that already set Content-Encoding are
+    left alone; .exclude(prefix) turns compression off for paths (streams, pre-compressed files).
+    """
+
+    def __init__(self, *, minimum_size: int = 1024, level: int = 6) -> None:
+        self.minimum_size = minimum_size
+        self.level = level
+        self._excluded: list[str] = []
+
+    def exclude(self, prefix: str) -> CompressionModule:
+        """Never compress responses for prefix and the paths below it ("/files" covers "/files/a", not "/filesystem")."""
+        self._excluded.append(prefix)
+        return self
+
+    def is_excluded(self, path: str) -> bool:
+        return any(under_prefix(path, prefix) for prefix in self._excluded)
+
+    def register_into(self, app: Application) -> None:
+        app.starlette.add_middleware(CompressionMiddleware, module=self)
@@ -0,0 +1,73 @@
+"""
+ConcurrencyLimitModule — caps requests handled at once; the excess gets 503 with Retry-After
+instead of queueing until memory runs out. Register with app.register(...).
+Connection limits and accept backpressure belong to the ASGI server (uvicorn --limit-concurrency).
+"""
+from __future__ import annotations
+
+from typing import Any
+
+from urich.core.app import Application
+from urich.core.errors import error_response
+from urich.core.module import Module
+from urich.core.urls import under_prefix
+
+
+class ConcurrencyLimitMiddleware:
+    """ASGI middleware: counts in-flight HTTP requests, rejects above the module's limit."""
+
+    def __init__(self, app: Any, *, module: ConcurrencyLimitModule) -> None:
+        self.app = app
+        self._module = module
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        module = self._module
+        if scope["type"] != "http" or module.is_excluded(scope["path"]):
+            await self.app(scope, receive, send)
+            return
+        if module.in_flight >= module.max_in_flight:
+            module.rejected += 1
+            response = error_response(
+                503,
+                "Server is busy, retry later",
+                code="OVERLOADED",
+                headers={"Retry-After": str(module.retry_after)},
+            )
+            await response(scope, receive, send)
+            return
+        module.in_flight += 1
+        try:
+            await self.app(scope, receive, send)
+        finally:
+            module.in_flight -= 1
+
+
+class ConcurrencyLimitModule(Module):
+    """
+    In-flight request limit as object. Counters: .in_flight (now) and .rejected (total 503s).
+    Health routes and paths given to .exclude() are never limited.
+    """
+
+    def __init__(self, max_in_flight: int, *, retry_after: int = 1) -> None:
+        if max_in_flight < 1:
+            raise ValueError("max_in_flight must be at least 1")
+        self.max_in_flight = max_in_flight
+        self.retry_after = retry_after
+        self.in_flight = 0
+        self.rejected = 0
+        self._exclude: list[str] = []
+        self._app: Application | None = None
+
+    def exclude(self, prefix: str) -> ConcurrencyLimitModule:
+        """Never limit prefix and the paths below it (e.g. "/metrics")."""
+        self._exclude.append(prefix)
+        return self
+
+    def is_excluded(self, path: str) -> bool:
+        if self._app is not None and self._app.is_middleware_exempt(path):
+            return True
+        return any(under_prefix(path, p) for p in self._exclude)
+
+    def register_into(self, app: Application) -> None:
+        self._app = app
+        app.starlette.add_middleware(ConcurrencyLimitMiddleware, module=self)
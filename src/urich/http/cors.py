@@ -0,0 +1,51 @@
+"""
+CorsModule — CORS for every route: answers OPTIONS preflight before routing and adds
+Access-Control-Allow-* headers to normal responses. Register with app.register(CorsModule(...)).
+"""
+from __future__ import annotations
+
+from typing import Sequence
+
+from starlette.middleware.cors import CORSMiddleware
+
+from urich.core.app import Application
+from urich.core.module import Module
+
+
+class CorsModule(Module):
+    """
+    allow_origins: ["*"] (any origin) or an exact allow-list. With allow_credentials=True the
+    request Origin is reflected instead of "*" (browsers reject "*" with credentials).
+    allow_origin_regex: alternative pattern match, e.g. r"https://.*\\.example\\.com".
+    """
+
+    def __init__(
+        self,
+        *,
+        allow_origins: Sequence[str] = ("*",),
+        allow_methods: Sequence[str] = ("GET", "POST", "PUT", "PATCH", "DELETE"),
+        allow_headers: Sequence[str] = (),
+        expose_headers: Sequence[str] = (),
+        allow_credentials: bool = False,
+        max_age: int = 600,
+        allow_origin_regex: str | None = None,
+    ) -> None:
+        self.allow_origins = list(allow_origins)
+        self.allow_methods = [m.upper() for m in allow_methods]
+        self.allow_headers = list(allow_headers)
+        self.expose_headers = list(expose_headers)
+        self.allow_credentials = allow_credentials
+        self.max_age = max_age
+        self.allow_origin_regex = allow_origin_regex
+
+    def register_into(self, app: Application) -> None:
+        app.starlette.add_middleware(
+            CORSMiddleware,
+            allow_origins=self.allow_origins,
+            allow_methods=self.allow_methods,
+            allow_headers=self.allow_headers,
+            expose_headers=self.expose_headers,
+            allow_credentials=self.allow_credentials,
+            max_age=self.max_age,
+            allow_origin_regex=self.allow_origin_regex,
+        )
@@ -0,0 +1,99 @@
+"""
+HealthModule — liveness and readiness endpoints for orchestrators (Kubernetes probes).
+Configure probes via .probe(...); register with app.register(health).
+"""
+from __future__ import annotations
+
+import asyncio
+import logging
+from typing import Any, Callable, Protocol, runtime_checkable
+
+from starlette.requests import Request
+from starlette.responses import JSONResponse, Response
+
+from urich.core.app import Application
+from urich.core.module import Module
+
+logger = logging.getLogger("urich.health")
+
+
+@runtime_checkable
+class HealthProbe(Protocol):
+    """Readiness check: return None/True when healthy; return False or an error string, or raise, when not.
+    check may be sync or async."""
+
+    def check(self) -> Any:
+        ...
+
+
+async def _run_probe(probe: Callable[[], Any], timeout: float) -> str | None:
+    """None if healthy, otherwise the failure message."""
+    try:
+        result = probe()
+        if hasattr(result, "__await__"):
+            result = await asyncio.wait_for(result, timeout)
+    except asyncio.TimeoutError:
+        return f"timed out after {timeout}s"
+    except Exception as e:
+        return str(e) or type(e).__name__
+    if result is None or result is True:
+        return None
+    return "unhealthy" if result is False else str(result)
+
+
+class HealthModule(Module):
+    """
+    GET {prefix}/live: 200 while the process serves requests (no probes run).
+    GET {prefix}/ready: runs every probe; 200 with {"status": "ok", "probes": {...}} when all pass,
+    503 with the failures otherwise. Probes: callables, HealthProbe instances, or HealthProbe classes
+    resolved from the container at check time. Function middlewares (app.add_middleware, e.g. auth)
+    skip these routes unless include_in_middleware=True.
+    """
+
+    def __init__(self, prefix: str = "/health", *, timeout: float = 5.0, include_in_middleware: bool = False) -> None:
+        self.prefix = prefix.rstrip("/")
+        self._timeout = timeout
+        self._include_in_middleware = include_in_middleware
+        self._probes: dict[str, Any] = {}
+
+    def probe(self, name: str, probe: Callable[[], Any] | HealthProbe | type) -> HealthModule:
+        """Add a readiness probe under name (shown in the /ready body)."""
+        self._probes[name] = probe
+        return self
+
+    async def check(self, app: Application) -> dict[str, str | None]:
+        """Run all probes concurrently: name -> None (ok) or failure message."""
+        names = list(self._probes)
+        results = await asyncio.gather(*(self._run(app, self._probes[n]) for n in names))
+        return dict(zip(names, results))
+
+    async def _run(self, app: Application, probe: Any) -> str | None:
+        if isinstance(probe, type):
+            try:
+                probe = app.container.resolve(probe)
+            except KeyError as e:
+                return str(e)
+        return await _run_probe(probe.check if hasattr(probe, "check") else probe, self._timeout)
+
+    def register_into(self, app: Application) -> None:
+        live, ready = f"{self.prefix}/live", f"{self.prefix}/ready"
+        app.add_route(live, self._make_live_endpoint(), methods=["GET"], openapi_tags=["health"])
+        app.add_route(ready, self._make_ready_endpoint(app), methods=["GET"], openapi_tags=["health"])
+        if not self._include_in_middleware:
+            app.exempt_from_middleware(live, ready)
+
+    def _make_live_endpoint(self) -> Any:
+        async def endpoint(request: Request) -> Response:
+            return JSONResponse({"status": "ok"})
+        return endpoint
+
+    def _make_ready_endpoint(self, app: Application) -> Any:
+        async def endpoint(request: Request) -> Response:
+            results = await self.check(app)
+            failures = {name: msg for name, msg in results.items() if msg is not None}
+            probes = {name: ("ok" if msg is None else msg) for name, msg in results.items()}
+            if failures:
+                logger.warning("readiness failed: %s", failures)
+                return JSONResponse({"status": "unavailable", "probes": probes}, status_code=503)
+            return JSONResponse({"status": "ok", "probes": probes})
+        return endpoint
@@ -0,0 +1,268 @@
+"""
+MetricsModule — Prometheus metrics: GET /metrics in the text exposition format, request counters,
+latency histogram and in-flight gauge per route template, plus published domain events and
+outgoing RPC calls. No client library needed. Register with app.register(MetricsModule()).
+"""
+from __future__ import annotations
+
+import math
+import time
+from typing import Any, Iterable
+
+from starlette.requests import Request
+from starlette.responses import Response
+from starlette.routing import Match
+
+from urich.core.app import Application
+from urich.core.module import Module
+
+DEFAULT_BUCKETS = (0.005, 0.01, 0.025, 0.05, 0.075, 0.1, 0.25, 0.5, 0.75, 1.0, 2.5, 5.0, 7.5, 10.0)
+CONTENT_TYPE = "text/plain; version=0.0.4; charset=utf-8"
+UNMATCHED = "unmatched"
+
+Labels = tuple[tuple[str, str], ...]
+
+
+def _escape(value: str) -> str:
+    return value.replace("\\", "\\\\").replace("\n", "\\n").replace('"', '\\"')
+
+
+def _format_labels(labels: Labels) -> str:
+    if not labels:
+        return ""
+    return "{" + ",".join(f'{k}="{_escape(v)}"' for k, v in labels) + "}"
+
+
+def _format_value(value: float) -> str:
+    if math.isinf(value):
+        return "+Inf" if value > 0 else "-Inf"
+    return repr(float(value)) if not float(value).is_integer() else str(int(value))
+
+
+class Counter:
+    """Monotonic counter per label set."""
+
+    kind = "counter"
+
+    def __init__(self, name: str, help: str) -> None:
+        self.name = name
+        self.help = help
+        self.values: dict[Labels, float] = {}
+
+    def inc(self, amount: float = 1.0, **labels: str) -> None:
+        key = tuple(sorted(labels.items()))
+        self.values[key] = self.values.get(key, 0.0) + amount
+
+    def get(self, **labels: str) -> float:
+        return self.values.get(tuple(sorted(labels.items())), 0.0)
+
+    def samples(self) -> Iterable[str]:
+        for labels, value in sorted(self.values.items()):
+            yield f"{self.name}{_format_labels(labels)} {_format_value(value)}"
+
+
+class Gauge(Counter):
+    """Value that goes up and down."""
+
+    kind = "gauge"
+
+    def dec(self, amount: float = 1.0, **labels: str) -> None:
+        self.inc(-amount, **labels)
+
+
+class Histogram:
+    """Observations counted into cumulative buckets, with _sum and _count."""
+
+    kind = "histogram"
+
+    def __init__(self, name: str, help: str, buckets: Iterable[float] = DEFAULT_BUCKETS) -> None:
+        self.name = name
+        self.help = help
+        self.buckets = tuple(sorted(buckets)) + (math.inf,)
+        self.values: dict[Labels, tuple[list[int], list[float]]] = {}  # labels -> (bucket counts, [sum])
+
+    def observe(self, value: float, **labels: str) -> None:
+        key = tuple(sorted(labels.items()))
+        counts, total = self.values.setdefault(key, ([0] * len(self.buckets), [0.0]))
+        for i, bound in enumerate(self.buckets):
+            if value <= bound:
+                counts[i] += 1
+        total[0] += value
+
+    def count(self, **labels: str) -> int:
+        entry = self.values.get(tuple(sorted(labels.items())))
+        return entry[0][-1] if entry else 0
+
+    def samples(self) -> Iterable[str]:
+        for labels, (counts, total) in sorted(self.values.items()):
+            for bound, count in zip(self.buckets, counts):
+                bucket_labels = labels + (("le", _format_value(bound)),)
+                yield f"{self.name}_bucket{_format_labels(bucket_labels)} {count}"
+            yield f"{self.name}_sum{_format_labels(labels)} {_format_value(total[0])}"
+            yield f"{self.name}_count{_format_labels(labels)} {counts[-1]}"
+
+
+class MetricsRegistry:
+    """Metrics of one application; render() gives the exposition text served at /metrics."""
+
+    def __init__(self) -> None:
+        self.metrics: list[Counter | Histogram] = []
+
+    def counter(self, name: str, help: str) -> Counter:
+        return self._add(Counter(name, help))
+
+    def gauge(self, name: str, help: str) -> Gauge:
+        return self._add(Gauge(name, help))
+
+    def histogram(self, name: str, help: str, buckets: Iterable[float] = DEFAULT_BUCKETS) -> Histogram:
+        return self._add(Histogram(name, help, buckets))
+
+    def _add(self, metric: Any) -> Any:
+        if any(m.name == metric.name for m in self.metrics):
+            raise ValueError(f"Metric {metric.name!r} already registered")
+        self.metrics.append(metric)
+        return metric
+
+    def render(self) -> str:
+        lines: list[str] = []
+        for metric in self.metrics:
+            lines.append(f"# HELP {metric.name} {metric.help}")
+            lines.append(f"# TYPE {metric.name} {metric.kind}")
+            lines.extend(metric.samples())
+        return "\n".join(lines) + "\n"
+
+
+class MetricsMiddleware:
+    """ASGI middleware: counts and times HTTP requests by method, route template and status."""
+
+    def __init__(self, app: Any, *, module: MetricsModule, application: Application) -> None:
+        self.app = app
+        self._module = module
+        self._application = application
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        if scope["type"] != "http":
+            await self.app(scope, receive, send)
+            return
+        module = self._module
+        method, path = scope["method"], self._route_template(scope)
+        status = 500
+        start = time.perf_counter()
+
+        async def send_wrapper(message: dict[str, Any]) -> None:
+            nonlocal status
+            if message["type"] == "http.response.start":
+                status = message["status"]
+            await send(message)
+
+        module.in_flight.inc()
+        try:
+            await self.app(scope, receive, send_wrapper)
+        finally:
+            module.in_flight.dec()
+            module.requests.inc(method=method, path=path, status=str(status))
+            module.duration.observe(time.perf_counter() - start, method=method, path=path)
+
+    def _route_template(self, scope: dict[str, Any]) -> str:
+        """Registered route path (/orders/{order_id}), never the raw path, to bound label cardinality."""
+        for route in self._application.starlette.routes:
+            match, _ = route.matches(scope)
+            if match == Match.FULL:
+                return getattr(route, "path", UNMATCHED) or "/"
+        return UNMATCHED
+
+
+class MetricsModule(Module):
+    """
+    Prometheus metrics as object: MetricsModule(path="/metrics", namespace="urich", buckets=...).
+    Built in: <namespace>_requests_total{method,path,status}, <namespace>_request_duration_seconds
+    {method,path}, <namespace>_requests_in_flight, <namespace>_events_published_total{event} and
+    <namespace>_rpc_client_calls_total{service,method,outcome} and
+    <namespace>_rpc_outbox_calls_total{service,outcome} (ReliableRpc) and
+    <namespace>_validation_duration_seconds{method,path} (DomainModule payload validation). path is the route template;
+    requests matching no route count as "unmatched". Add your own with .registry.counter(...).
+    """
+
+    def __init__(
+        self,
+        path: str = "/metrics",
+        *,
+        namespace: str = "urich",
+        buckets: Iterable[float] = DEFAULT_BUCKETS,
+    ) -> None:
+        self.path = path
+        self.registry = MetricsRegistry()
+        ns = f"{namespace}_" if namespace else ""
+        self.requests = self.registry.counter(f"{ns}requests_total", "HTTP requests by method, route and status.")
+        self.duration = self.registry.histogram(
+            f"{ns}request_duration_seconds", "HTTP request duration in seconds by method and route.", buckets
+        )
+        self.validation = self.registry.histogram(
+            f"{ns}validation_duration_seconds",
+            "Payload validation time in seconds by method and route (validated requests only).",
+            buckets,
+        )
+        self.in_flight = self.registry.gauge(f"{ns}requests_in_flight", "HTTP requests being handled.")
+        self.in_flight.inc(0)  # exported as 0 before the first request
+        self.events = self.registry.counter(f"{ns}events_published_total", "Domain events published by type.")
+        self.rpc_calls = self.registry.counter(
+            f"{ns}rpc_client_calls_total", "Outgoing RPC calls by service, method and outcome (ok or error code)."
+        )
+        self.rpc_outbox = self.registry.counter(
+            f"{ns}rpc_outbox_calls_total",
+            "Outbox RPC calls by service and outcome (queued, delivered, dead_lettered).",
+        )
+
+    def register_into(self, app: Application) -> None:
+        app.container.register_instance(MetricsRegistry, self.registry)
+
+        async def metrics_endpoint(request: Request) -> Response:
+            return Response(self.registry.render(), media_type=CONTENT_TYPE)
+
+        app.add_route(self.path, metrics_endpoint, methods=["GET"], openapi_tags=["metrics"])
+        app.starlette.add_middleware(MetricsMiddleware, module=self, application=app)
+        app.on_validation(lambda method, path, seconds: self.validation.observe(seconds, method=method, path=path))
+        # EventBus, RpcClient and ReliableRpc may be registered after this module: instrument them on startup.
+        app.starlette.router.on_startup.append(lambda: self._instrument(app))
+
+    def _instrument(self, app: Application) -> None:
+        from urich.domain.events import EventBus
+        from urich.rpc.reliable import ReliableRpc
+        from urich.rpc.rpc_module import RpcClient
+
+        try:
+            bus = app.container.resolve(EventBus)
+        except KeyError:
+            bus = None
+        if bus is not None and not getattr(bus, "_urich_metrics", False):
+            publish = bus.publish
+
+            async def counted_publish(event: object, *args: Any, **kwargs: Any) -> Any:
+                self.events.inc(event=type(event).__name__)
+                return await publish(event, *args, **kwargs)
+
+            bus.publish = counted_publish
+            bus._urich_metrics = True
+        try:
+            client = app.container.resolve(RpcClient)
+        except KeyError:
+            client = None
+        if client is not None:
+            client.add_interceptor(self._count_rpc_call)
+        try:
+            reliable = app.container.resolve(ReliableRpc)
+        except KeyError:
+            reliable = None
+        if reliable is not None:
+            reliable.add_observer(lambda service, outcome: self.rpc_outbox.inc(service=service, outcome=outcome))
+
+    async def _count_rpc_call(self, call: Any, call_next: Any) -> Any:
+        from urich.rpc.protocol import RpcError
+
+        try:
+            result = await call_next(call)
+        except RpcError as e:
+            self.rpc_calls.inc(service=call.service, method=call.method, outcome=e.code)
+            raise
+        self.rpc_calls.inc(service=call.service, method=call.method, outcome="ok")
+        return result
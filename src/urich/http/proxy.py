@@ -0,0 +1,95 @@
+"""
+ProxyHeadersModule — behind a load balancer, take the client address and scheme from
+Forwarded / X-Forwarded-For / X-Forwarded-Proto, but only when the direct peer is a trusted proxy.
+The direct peer is always in request.client (ASGI scope["client"]). Register with app.register(...).
+"""
+from __future__ import annotations
+
+import ipaddress
+from typing import Any
+
+from urich.core.app import Application
+from urich.core.module import Module
+
+
+def _header(scope: dict[str, Any], name: bytes) -> str | None:
+    values = [v.decode("latin-1") for k, v in scope.get("headers", []) if k.lower() == name]
+    return ", ".join(values) if values else None
+
+
+def _forwarded_chain(scope: dict[str, Any]) -> tuple[list[str], str | None]:
+    """Forwarded hop addresses (client first, nearest proxy last) and the forwarded scheme."""
+    forwarded = _header(scope, b"forwarded")
+    if forwarded is not None:
+        hops: list[str] = []
+        proto = None
+        for element in forwarded.split(","):
+            for pair in element.split(";"):
+                key, _, value = pair.strip().partition("=")
+                value = value.strip().strip('"')
+                if key.lower() == "for" and value:
+                    if value.startswith("["):  # [2001:db8::1]:port
+                        value = value[1:].split("]")[0]
+                    elif value.count(":") == 1:  # host:port
+                        value = value.split(":")[0]
+                    hops.append(value)
+                elif key.lower() == "proto" and value and proto is None:
+                    proto = value.lower()
+        return hops, proto
+    xff = _header(scope, b"x-forwarded-for")
+    proto_header = _header(scope, b"x-forwarded-proto")
+    hops = [h.strip() for h in xff.split(",") if h.strip()] if xff else []
+    proto = proto_header.split(",")[0].strip().lower() if proto_header else None
+    return hops, proto
+
+
+class ProxyHeadersMiddleware:
+    """ASGI middleware rewriting scope["client"] / scope["scheme"] for requests from trusted proxies."""
+
+    def __init__(self, app: Any, *, module: ProxyHeadersModule) -> None:
+        self.app = app
+        self._module = module
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        if scope["type"] in ("http", "websocket"):
+            peer = scope.get("client")
+            if peer is not None and self._module.is_trusted(peer[0]):
+                hops, proto = _forwarded_chain(scope)
+                client = self._module.client_from(hops)
+                if client is not None or proto is not None:
+                    scope = dict(scope)
+                    if client is not None:
+                        scope["client"] = (client, 0)
+                    if proto in ("http", "https"):
+                        scope["scheme"] = proto if scope["type"] == "http" else {"http": "ws", "https": "wss"}[proto]
+        await self.app(scope, receive, send)
+
+
+class ProxyHeadersModule(Module):
+    """
+    trusted: proxy addresses or networks ("10.0.0.0/8", "127.0.0.1"); "*" trusts any peer.
+    The client is the right-most forwarded address that isn't itself a trusted proxy, so a
+    client can't spoof its address by sending its own X-Forwarded-For.
+    """
+
+    def __init__(self, trusted: list[str] | tuple[str, ...] = ("127.0.0.1", "::1")) -> None:
+        self._trust_all = "*" in trusted
+        self._networks = [ipaddress.ip_network(t, strict=False) for t in trusted if t != "*"]
+
+    def is_trusted(self, host: str) -> bool:
+        if self._trust_all:
+            return True
+        try:
+            address = ipaddress.ip_address(host)
+        except ValueError:
+            return False
+        return any(address in network for network in self._networks)
+
+    def client_from(self, hops: list[str]) -> str | None:
+        for hop in reversed(hops):
+            if not self.is_trusted(hop):
+                return hop
+        return hops[0] if hops else None
+
+    def register_into(self, app: Application) -> None:
+        app.starlette.add_middleware(ProxyHeadersMiddleware, module=self)
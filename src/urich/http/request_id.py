@@ -0,0 +1,87 @@
+"""
+RequestIdModule — one id per request for log correlation: taken from X-Request-Id or generated,
+available to middlewares and handlers, echoed in the response. Register with app.register(...).
+"""
+from __future__ import annotations
+
+import contextvars
+import logging
+import re
+import uuid
+from typing import Any
+
+from urich.core.app import Application
+from urich.core.module import Module
+
+HEADER = "x-request-id"
+
+_current: contextvars.ContextVar[str | None] = contextvars.ContextVar("urich_request_id", default=None)
+
+
+def current_request_id() -> str | None:
+    """Id of the request being handled in this task (also inside event handlers it publishes to)."""
+    return _current.get()
+
+
+class RequestIdLogFilter(logging.Filter):
+    """Adds record.request_id ("-" outside requests) for format strings like "%(request_id)s"."""
+
+    def filter(self, record: logging.LogRecord) -> bool:
+        record.request_id = _current.get() or "-"
+        return True
+
+
+class RequestIdMiddleware:
+    """ASGI middleware: sets scope["state"]["request_id"] (request.state.request_id), the context
+    variable behind current_request_id(), and the response header."""
+
+    def __init__(self, app: Any, *, module: RequestIdModule) -> None:
+        self.app = app
+        self._module = module
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        if scope["type"] != "http":
+            await self.app(scope, receive, send)
+            return
+        request_id = self._module.incoming_id(scope) or uuid.uuid4().hex
+        scope.setdefault("state", {})["request_id"] = request_id
+        header = self._module.header.encode("latin-1")
+
+        async def send_with_id(message: dict[str, Any]) -> None:
+            if message["type"] == "http.response.start":
+                headers = [(k, v) for k, v in message.get("headers", []) if k.lower() != header]
+                headers.append((header, request_id.encode("latin-1")))
+                message = {**message, "headers": headers}
+            await send(message)
+
+        token = _current.set(request_id)
+        try:
+            await self.app(scope, receive, send_with_id)
+        finally:
+            _current.reset(token)
+
+
+class RequestIdModule(Module):
+    """
+    trust_incoming: reuse the client's X-Request-Id when it is a sane token (max 200 chars of
+    letters, digits and -_.:); otherwise a uuid4 hex is generated. header: header name to use.
+    """
+
+    _SAFE = re.compile(r"^[A-Za-z0-9\-_.:]{1,200}$")
+
+    def __init__(self, *, header: str = HEADER, trust_incoming: bool = True) -> None:
+        self.header = header.lower()
+        self._trust_incoming = trust_incoming
+
+    def incoming_id(self, scope: dict[str, Any]) -> str | None:
+        if not self._trust_incoming:
+            return None
+        wanted = self.header.encode("latin-1")
+        for name, value in scope.get("headers", []):
+            if name.lower() == wanted:
+                text = value.decode("latin-1")
+                return text if self._SAFE.match(text) else None
+        return None
+
+    def register_into(self, app: Application) -> None:
+        app.starlette.add_middleware(RequestIdMiddleware, module=self)
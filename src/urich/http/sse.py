@@ -0,0 +1,200 @@
+"""
+SseModule — Server-Sent Events routes: a handler's async stream, a TopicHub topic or domain
+events from the EventBus pushed to browsers as text/event-stream. Register with app.register(...).
+"""
+from __future__ import annotations
+
+import asyncio
+import contextlib
+import json
+from dataclasses import dataclass
+from typing import Any, AsyncIterable, Callable
+
+from starlette.requests import Request
+
+from urich.core.app import Application
+from urich.core.module import Module
+
+KEEPALIVE = b": keep-alive\n\n"
+
+
+@dataclass
+class SseEvent:
+    """One event on the stream. data: str as-is, anything else as JSON. retry: client reconnect delay (ms)."""
+
+    data: Any
+    event: str | None = None
+    id: str | None = None
+    retry: int | None = None
+
+    def encode(self) -> bytes:
+        lines: list[str] = []
+        if self.event is not None:
+            lines.append(f"event: {_one_line(self.event)}")
+        if self.id is not None:
+            lines.append(f"id: {_one_line(self.id)}")
+        if self.retry is not None:
+            lines.append(f"retry: {int(self.retry)}")
+        data = self.data if isinstance(self.data, str) else json.dumps(self.data, default=str)
+        lines.extend(f"data: {line}" for line in data.splitlines() or [""])
+        return ("\n".join(lines) + "\n\n").encode()
+
+
+def _one_line(value: str) -> str:
+    return str(value).replace("\r", " ").replace("\n", " ")
+
+
+class SseResponse:
+    """
+    ASGI response streaming an async iterable of SseEvent (other items become SseEvent(data=item)).
+    Sends a keep-alive comment after keepalive idle seconds; stops and closes the stream as soon
+    as the client disconnects.
+    """
+
+    def __init__(self, stream: AsyncIterable[Any], *, keepalive: float = 15.0, headers: dict[str, str] | None = None) -> None:
+        self._stream = stream
+        self._keepalive = keepalive
+        self._headers = headers or {}
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        headers = {
+            "content-type": "text/event-stream; charset=utf-8",
+            "cache-control": "no-cache",
+            "x-accel-buffering": "no",
+            **{k.lower(): v for k, v in self._headers.items()},
+        }
+        await send({
+            "type": "http.response.start",
+            "status": 200,
+            "headers": [(k.encode("latin-1"), v.encode("latin-1")) for k, v in headers.items()],
+        })
+        disconnected = asyncio.Event()
+
+        async def watch_disconnect() -> None:
+            while True:
+                message = await receive()
+                if message["type"] == "http.disconnect":
+                    disconnected.set()
+                    return
+
+        watcher = asyncio.create_task(watch_disconnect())
+        iterator = self._stream.__aiter__()
+        next_item: asyncio.Future[Any] | None = None
+        try:
+            while not disconnected.is_set():
+                if next_item is None:
+                    next_item = asyncio.ensure_future(iterator.__anext__())
+                gone = asyncio.ensure_future(disconnected.wait())
+                done, _ = await asyncio.wait(
+                    {next_item, gone}, timeout=self._keepalive, return_when=asyncio.FIRST_COMPLETED
+                )
+                gone.cancel()
+                if next_item in done:
+                    try:
+                        item = next_item.result()
+                    except StopAsyncIteration:
+                        next_item = None
+                        break
+                    next_item = None
+                    event = item if isinstance(item, SseEvent) else SseEvent(data=item)
+                    await send({"type": "http.response.body", "body": event.encode(), "more_body": True})
+                elif not disconnected.is_set():
+                    await send({"type": "http.response.body", "body": KEEPALIVE, "more_body": True})
+            if not disconnected.is_set():
+                await send({"type": "http.response.body", "body": b"", "more_body": False})
+        finally:
+            watcher.cancel()
+            if next_item is not None:
+                next_item.cancel()
+                with contextlib.suppress(BaseException):
+                    await next_item
+            aclose = getattr(iterator, "aclose", None)
+            if aclose is not None:
+                with contextlib.suppress(Exception):
+                    await aclose()
+
+
+class SseModule(Module):
+    """
+    SSE routes as object:
+    .stream(path, handler) — handler(request) returns an async iterable of SseEvent or payloads;
+    .topic(path, topic) — TopicHub messages of topic (or pattern) as events named after the topic;
+    .events(path, *event_types) — domain events as EventEnvelope JSON (event name, id = event_id).
+    """
+
+    def __init__(self, *, keepalive: float = 15.0) -> None:
+        self.keepalive = keepalive
+        self._streams: list[tuple[str, Callable[[Request], AsyncIterable[Any]]]] = []
+        self._topics: list[tuple[str, str]] = []
+        self._events: list[tuple[str, tuple[type, ...]]] = []
+
+    def stream(self, path: str, handler: Callable[[Request], AsyncIterable[Any]]) -> SseModule:
+        self._streams.append((path, handler))
+        return self
+
+    def topic(self, path: str, topic: str) -> SseModule:
+        self._topics.append((path, topic))
+        return self
+
+    def events(self, path: str, *event_types: type) -> SseModule:
+        self._events.append((path, event_types))
+        return self
+
+    def register_into(self, app: Application) -> None:
+        from urich.events.topic_hub import TopicHub
+
+        hub = app.container.resolve(TopicHub)
+        for path, handler in self._streams:
+            app.add_route(path, self._endpoint(handler), methods=["GET"])
+        for path, topic in self._topics:
+            app.add_route(path, self._endpoint(_topic_stream(hub, topic)), methods=["GET"])
+        if self._events:
+            bus = _event_bus(app)
+            for path, event_types in self._events:
+                topic = f"urich.sse:{path}"
+                for event_type in event_types:
+                    bus.subscribe(event_type, _forward_to(hub, topic))
+                app.add_route(path, self._endpoint(_envelope_stream(hub, topic)), methods=["GET"])
+
+    def _endpoint(self, handler: Callable[[Request], AsyncIterable[Any]]) -> Callable:
+        async def endpoint(request: Request) -> SseResponse:
+            return SseResponse(handler(request), keepalive=self.keepalive)
+        return endpoint
+
+
+def _topic_stream(hub: Any, topic: str) -> Callable[[Request], AsyncIterable[Any]]:
+    async def stream(request: Request) -> AsyncIterable[Any]:
+        async with hub.subscribe(topic) as sub:
+            while True:
+                name, payload = await sub.next_message()
+                yield SseEvent(data=payload, event=name)
+    return stream
+
+
+def _envelope_stream(hub: Any, topic: str) -> Callable[[Request], AsyncIterable[Any]]:
+    async def stream(request: Request) -> AsyncIterable[Any]:
+        async with hub.subscribe(topic) as sub:
+            async for envelope in sub:
+                yield SseEvent(data=envelope.to_dict(), event=envelope.event_type, id=envelope.event_id)
+    return stream
+
+
+def _forward_to(hub: Any, topic: str) -> Callable[[Any], None]:
+    from urich.domain.events import EventEnvelope
+
+    def forward(event: Any) -> None:
+        hub.publish(topic, EventEnvelope.wrap(event))
+    return forward
+
+
+def _event_bus(app: Application) -> Any:
+    """Registered EventBus, or a default in-process one (as DomainModule does)."""
+    from urich.domain.events import EventBus, InProcessEventDispatcher
+
+    try:
+        return app.container.resolve(EventBus)
+    except KeyError:
+        bus = InProcessEventDispatcher()
+        app.container.register_instance(EventBus, bus)
+        app.container.register_instance(InProcessEventDispatcher, bus)
+        return bus
@@ -0,0 +1,55 @@
+"""
+StaticFilesModule — serve a directory under a URL prefix (admin UI, generated docs, SPA bundles).
+Register with app.register(StaticFilesModule("/static", "web/dist")).
+"""
+from __future__ import annotations
+
+import os
+import stat
+from typing import Any
+
+from starlette.exceptions import HTTPException
+from starlette.staticfiles import StaticFiles
+
+from urich.core.app import Application
+from urich.core.module import Module
+
+
+class _StaticFiles(StaticFiles):
+    """StaticFiles with 403 for directories and, in SPA mode, index.html for unknown routes."""
+
+    def __init__(self, *, directory: str, spa: bool) -> None:
+        super().__init__(directory=directory, html=spa)
+        self._spa = spa
+
+    async def get_response(self, path: str, scope: dict[str, Any]) -> Any:
+        if not self._spa:
+            _, stat_result = self.lookup_path(path)
+            if stat_result is not None and stat.S_ISDIR(stat_result.st_mode):
+                raise HTTPException(403, "Directory listing is not allowed")
+            return await super().get_response(path, scope)
+        try:
+            return await super().get_response(path, scope)
+        except HTTPException as e:
+            # Client-side routes (/app/orders/42) get the app shell; missing assets (app.js) stay 404.
+            if e.status_code != 404 or "." in os.path.basename(path):
+                raise
+            return await super().get_response("index.html", scope)
+
+
+class StaticFilesModule(Module):
+    """
+    Files under directory at GET/HEAD {url_prefix}/... with Content-Type from the extension,
+    ETag / Last-Modified and 304 for If-None-Match / If-Modified-Since. Paths escaping the
+    directory (.., symlinks pointing outside) are 404, directories 403.
+    spa=True: directories serve their index.html and unknown extensionless paths fall back to
+    the root index.html, for client-side routing.
+    """
+
+    def __init__(self, url_prefix: str, directory: str | os.PathLike[str], *, spa: bool = False) -> None:
+        self.url_prefix = "/" + url_prefix.strip("/")
+        self.directory = os.fspath(directory)
+        self.spa = spa
+
+    def register_into(self, app: Application) -> None:
+        app.mount(self.url_prefix, _StaticFiles(directory=self.directory, spa=self.spa))
@@ -0,0 +1,151 @@
+"""
+TenantModule — take the tenant id from a header, a JWT claim or the Host subdomain, reject
+requests without one (400) and expose it as request.state.tenant_id, current_tenant() and a
+Tenant resolvable from the Container. Register with app.register(TenantModule.from_header()).
+"""
+from __future__ import annotations
+
+import contextvars
+from dataclasses import dataclass
+from typing import Any, Callable
+
+from urich.core.app import Application
+from urich.core.errors import error_response
+from urich.core.module import Module
+from urich.core.urls import under_prefix
+
+_tenant: contextvars.ContextVar[str | None] = contextvars.ContextVar("urich_tenant", default=None)
+
+
+def current_tenant() -> str | None:
+    """Tenant id of the request being handled (None on excluded paths or outside a request)."""
+    return _tenant.get()
+
+
+@dataclass(frozen=True)
+class Tenant:
+    """Tenant of the current request; inject it into handlers like any other dependency."""
+
+    id: str
+
+
+def _header(scope: dict[str, Any], name: bytes) -> str | None:
+    for key, value in scope.get("headers", []):
+        if key.lower() == name:
+            return value.decode("latin-1")
+    return None
+
+
+class TenantMiddleware:
+    """ASGI middleware: 400 TENANT_REQUIRED unless the module's extractor finds a tenant id."""
+
+    def __init__(self, app: Any, *, module: TenantModule) -> None:
+        self.app = app
+        self._module = module
+
+    async def __call__(self, scope: dict[str, Any], receive: Any, send: Any) -> None:
+        module = self._module
+        if scope["type"] != "http" or module.is_excluded(scope["path"]) or scope["method"] == "OPTIONS":
+            await self.app(scope, receive, send)
+            return
+        tenant_id = module.extract(scope)
+        if not tenant_id:
+            response = error_response(400, module.missing_message, code="TENANT_REQUIRED")
+            await response(scope, receive, send)
+            return
+        scope.setdefault("state", {})["tenant_id"] = tenant_id
+        token = _tenant.set(tenant_id)
+        metadata_token = None
+        if module.stamp_events:
+            from urich.domain.events import bind_envelope_metadata
+
+            metadata_token = bind_envelope_metadata({"tenant_id": tenant_id})
+        try:
+            await self.app(scope, receive, send)
+        finally:
+            if metadata_token is not None:
+                from urich.domain.events import reset_envelope_metadata
+
+                reset_envelope_metadata(metadata_token)
+            _tenant.reset(token)
+
+
+class TenantModule(Module):
+    """
+    Tenant extraction as object: TenantModule.from_header("X-Tenant-Id").exclude("/public").
+    extractor(scope) returns the tenant id or None. OpenAPI docs and health routes are excluded
+    by default; OPTIONS is never checked. stamp_events=True puts tenant_id into the metadata of
+    EventEnvelopes wrapped while handling the request (outbox, Redis bus, SSE).
+    from_claim reads the claims AuthModule verified, so register TenantModule before AuthModule
+    (middlewares registered later run first).
+    """
+
+    def __init__(
+        self,
+        extractor: Callable[[dict[str, Any]], str | None],
+        *,
+        stamp_events: bool = True,
+        missing_message: str = "Tenant is required",
+    ) -> None:
+        self._extractor = extractor
+        self.stamp_events = stamp_events
+        self.missing_message = missing_message
+        self._exclude: list[str] = ["/docs", "/openapi.json"]
+        self._app: Application | None = None
+
+    @classmethod
+    def from_header(cls, name: str = "X-Tenant-Id", **options: Any) -> TenantModule:
+        key = name.lower().encode("latin-1")
+
+        def extract(scope: dict[str, Any]) -> str | None:
+            value = _header(scope, key)
+            return value.strip() if value else None
+
+        return cls(extract, missing_message=f"Missing {name} header", **options)
+
+    @classmethod
+    def from_claim(cls, name: str = "tenant_id", **options: Any) -> TenantModule:
+        def extract(scope: dict[str, Any]) -> str | None:
+            value = (scope.get("state") or {}).get("claims", {}).get(name)
+            return str(value) if value not in (None, "") else None
+
+        return cls(extract, missing_message=f"Token has no {name} claim", **options)
+
+    @classmethod
+    def from_subdomain(cls, base_domain: str, **options: Any) -> TenantModule:
+        """acme.example.com with base_domain "example.com" gives tenant "acme"."""
+        suffix = "." + base_domain.strip(".").lower()
+
+        def extract(scope: dict[str, Any]) -> str | None:
+            host = (_header(scope, b"host") or "").lower().split(":")[0]
+            if not host.endswith(suffix):
+                return None
+            sub = host[: -len(suffix)]
+            return sub if sub and "." not in sub else None
+
+        return cls(extract, missing_message=f"Host is not a tenant subdomain of {base_domain}", **options)
+
+    def exclude(self, prefix: str) -> TenantModule:
+        """Don't require a tenant on paths under prefix (e.g. "/public")."""
+        self._exclude.append(prefix)
+        return self
+
+    def is_excluded(self, path: str) -> bool:
+        if self._app is not None and self._app.is_middleware_exempt(path):
+            return True
+        return any(under_prefix(path, p) for p in self._exclude)
+
+    def extract(self, scope: dict[str, Any]) -> str | None:
+        return self._extractor(scope)
+
+    def register_into(self, app: Application) -> None:
+        self._app = app
+
+        def tenant() -> Tenant:
+            tenant_id = _tenant.get()
+            if tenant_id is None:
+                raise LookupError("No tenant for this request (path excluded from TenantModule?)")
+            return Tenant(tenant_id)
+
+        app.container.register(Tenant, tenant, singleton=False)
+        app.starlette.add_middleware(TenantMiddleware, module=self)
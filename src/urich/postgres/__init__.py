@@ -0,0 +1,14 @@
+"""Postgres persistence (optional, needs asyncpg: pip install "urich[postgres]")."""
+from urich.postgres.database import AGGREGATES_SCHEMA, OUTBOX_SCHEMA, PgDatabase
+from urich.postgres.module import PostgresModule
+from urich.postgres.outbox import PgOutbox
+from urich.postgres.repository import PgRepository
+
+__all__ = [
+    "PgDatabase",
+    "PgOutbox",
+    "PgRepository",
+    "PostgresModule",
+    "OUTBOX_SCHEMA",
+    "AGGREGATES_SCHEMA",
+]
@@ -0,0 +1,98 @@
+"""
+PgDatabase — asyncpg pool plus the connection of the current transaction, shared by
+PgRepository and PgOutbox so an aggregate and its events are written together.
+"""
+from __future__ import annotations
+
+import contextvars
+from contextlib import asynccontextmanager
+from typing import Any, AsyncIterator
+
+OUTBOX_SCHEMA = """
+CREATE TABLE IF NOT EXISTS urich_outbox (
+    event_id     TEXT PRIMARY KEY,
+    event_type   TEXT NOT NULL,
+    occurred_at  TIMESTAMPTZ NOT NULL,
+    payload      JSONB NOT NULL,
+    metadata     JSONB NOT NULL DEFAULT '{}',
+    published_at TIMESTAMPTZ
+);
+CREATE INDEX IF NOT EXISTS urich_outbox_pending ON urich_outbox (occurred_at) WHERE published_at IS NULL;
+"""
+
+AGGREGATES_SCHEMA = """
+CREATE TABLE IF NOT EXISTS urich_aggregates (
+    aggregate_type TEXT NOT NULL,
+    id             TEXT NOT NULL,
+    data           JSONB NOT NULL,
+    version        BIGINT NOT NULL DEFAULT 0,
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (aggregate_type, id)
+);
+ALTER TABLE urich_aggregates ADD COLUMN IF NOT EXISTS version BIGINT NOT NULL DEFAULT 0;
+"""
+
+_transaction: contextvars.ContextVar[Any] = contextvars.ContextVar("urich_pg_transaction", default=None)
+
+
+class PgDatabase:
+    """
+    asyncpg pool created by start() (PostgresModule does it on app startup).
+    Inside `async with db.transaction() as conn:` every connection() call in the same task gets
+    conn, so repositories and the outbox join the transaction without passing it around.
+    """
+
+    def __init__(self, dsn: str, **pool_options: Any) -> None:
+        self.dsn = dsn
+        self._pool_options = pool_options
+        self._pool: Any = None
+
+    async def start(self) -> None:
+        if self._pool is not None:
+            return
+        try:
+            import asyncpg
+        except ImportError as e:
+            raise ImportError('urich.postgres needs asyncpg: pip install "urich[postgres]"') from e
+        self._pool = await asyncpg.create_pool(self.dsn, **self._pool_options)
+
+    async def close(self) -> None:
+        if self._pool is not None:
+            await self._pool.close()
+            self._pool = None
+
+    @property
+    def pool(self) -> Any:
+        if self._pool is None:
+            raise RuntimeError("PgDatabase is not started (register PostgresModule or await db.start())")
+        return self._pool
+
+    @asynccontextmanager
+    async def connection(self, connection: Any = None) -> AsyncIterator[Any]:
+        """connection if given, else the current transaction's, else one from the pool."""
+        conn = connection or _transaction.get()
+        if conn is not None:
+            yield conn
+            return
+        async with self.pool.acquire() as conn:
+            yield conn
+
+    @asynccontextmanager
+    async def transaction(self) -> AsyncIterator[Any]:
+        """Open a transaction (nested calls reuse the outer one); commits on success."""
+        if _transaction.get() is not None:
+            yield _transaction.get()
+            return
+        async with self.pool.acquire() as conn:
+            async with conn.transaction():
+                token = _transaction.set(conn)
+                try:
+                    yield conn
+                finally:
+                    _transaction.reset(token)
+
+    async def ensure_schema(self) -> None:
+        """Create the outbox and aggregate tables if they don't exist."""
+        async with self.connection() as conn:
+            await conn.execute(OUTBOX_SCHEMA)
+            await conn.execute(AGGREGATES_SCHEMA)
@@ -0,0 +1,52 @@
+"""
+PostgresModule — asyncpg pool in the container, opened on startup and closed on shutdown;
+optionally the Postgres outbox and schema creation. Register with app.register(...).
+"""
+from __future__ import annotations
+
+from typing import Any
+
+from urich.core.app import Application
+from urich.core.module import Module
+from urich.postgres.database import PgDatabase
+
+
+class PostgresModule(Module):
+    """
+    Postgres as object: PostgresModule(dsn, min_size=..., max_size=...).outbox().ensure_schema().
+    PgDatabase is available in the container, so PgRepository subclasses resolve with it.
+    """
+
+    def __init__(self, dsn: str, **pool_options: Any) -> None:
+        self.db = PgDatabase(dsn, **pool_options)
+        self._outbox_batch_size: int | None = None
+        self._ensure_schema = False
+
+    def outbox(self, *, batch_size: int = 100) -> PostgresModule:
+        """Register PgOutbox as OutboxStorage and OutboxPublisher (UnitOfWork writes events there)."""
+        self._outbox_batch_size = batch_size
+        return self
+
+    def ensure_schema(self) -> PostgresModule:
+        """Create urich_outbox and urich_aggregates on startup if missing."""
+        self._ensure_schema = True
+        return self
+
+    def register_into(self, app: Application) -> None:
+        app.container.register_instance(PgDatabase, self.db)
+        if self._outbox_batch_size is not None:
+            from urich.events.outbox import OutboxModule
+            from urich.postgres.outbox import PgOutbox
+
+            outbox = PgOutbox(self.db, batch_size=self._outbox_batch_size)
+            app.container.register_instance(PgOutbox, outbox)
+            OutboxModule().storage(outbox).publisher(outbox).register_into(app)
+
+        async def startup() -> None:
+            await self.db.start()
+            if self._ensure_schema:
+                await self.db.ensure_schema()
+
+        router = app.starlette.router
+        router.on_startup.append(startup)
+        router.on_shutdown.append(self.db.close)
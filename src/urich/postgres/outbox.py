@@ -0,0 +1,68 @@
+"""PgOutbox — OutboxStorage and OutboxPublisher over the urich_outbox table."""
+from __future__ import annotations
+
+import json
+from datetime import datetime
+from typing import Any
+
+from urich.domain.events import EventEnvelope
+from urich.postgres.database import PgDatabase
+
+
+class PgOutbox:
+    """
+    append() inserts envelopes with the given connection or inside the current
+    db.transaction(); an event_id already stored is skipped. fetch_pending() returns up to
+    batch_size unpublished envelopes, oldest first; mark_published() stamps published_at.
+    """
+
+    def __init__(self, db: PgDatabase, *, batch_size: int = 100) -> None:
+        self._db = db
+        self._batch_size = batch_size
+
+    async def append(self, events: list[Any], *, connection: Any = None) -> None:
+        envelopes = [e if isinstance(e, EventEnvelope) else EventEnvelope.wrap(e) for e in events]
+        if not envelopes:
+            return
+        async with self._db.connection(connection) as conn:
+            await conn.executemany(
+                "INSERT INTO urich_outbox (event_id, event_type, occurred_at, payload, metadata) "
+                "VALUES ($1, $2, $3, $4::jsonb, $5::jsonb) ON CONFLICT (event_id) DO NOTHING",
+                [
+                    (
+                        e.event_id,
+                        e.event_type,
+                        datetime.fromisoformat(e.occurred_at),
+                        json.dumps(e.payload),
+                        json.dumps(e.metadata),
+                    )
+                    for e in envelopes
+                ],
+            )
+
+    async def fetch_pending(self) -> list[EventEnvelope]:
+        async with self._db.connection() as conn:
+            rows = await conn.fetch(
+                "SELECT event_id, event_type, occurred_at, payload, metadata FROM urich_outbox "
+                "WHERE published_at IS NULL ORDER BY occurred_at, event_id LIMIT $1",
+                self._batch_size,
+            )
+        return [
+            EventEnvelope(
+                event_id=row["event_id"],
+                event_type=row["event_type"],
+                occurred_at=row["occurred_at"].isoformat(),
+                payload=json.loads(row["payload"]),
+                metadata=json.loads(row["metadata"]),
+            )
+            for row in rows
+        ]
+
+    async def mark_published(self, ids: list[Any]) -> None:
+        if not ids:
+            return
+        async with self._db.connection() as conn:
+            await conn.execute(
+                "UPDATE urich_outbox SET published_at = now() WHERE event_id = ANY($1::text[]) AND published_at IS NULL",
+                list(ids),
+            )
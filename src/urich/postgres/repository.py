@@ -0,0 +1,121 @@
+"""PgRepository — Repository[A] storing aggregates as JSONB in urich_aggregates, keyed by id."""
+from __future__ import annotations
+
+import dataclasses
+import json
+from typing import Any, ClassVar, Generic, Optional, TypeVar
+
+from urich.domain.aggregate import aggregate_name
+from urich.domain.events import _from_json
+from urich.domain.repository import ConcurrencyError, Repository
+from urich.postgres.database import PgDatabase
+
+A = TypeVar("A")
+
+
+def _state(aggregate: Any) -> dict[str, Any]:
+    """Public fields: dataclass fields, else instance attributes; underscore names are skipped."""
+    if dataclasses.is_dataclass(aggregate):
+        data = {f.name: getattr(aggregate, f.name) for f in dataclasses.fields(aggregate)}
+    else:
+        data = dict(vars(aggregate))
+    return {k: v for k, v in data.items() if not k.startswith("_")}
+
+
+class PgRepository(Repository[A], Generic[A]):
+    """
+    Subclass per aggregate: class OrderRepository(PgRepository[Order]): aggregate = Order.
+    State is the aggregate's public fields as JSON; loading calls aggregate(**data), rebuilding
+    nested dataclasses. Writes join the current db.transaction().
+    The row's version column counts writes; it is loaded into aggregate.version when the aggregate
+    has one, and save_versioned updates only while it still equals expected_version.
+    """
+
+    aggregate: ClassVar[type]
+
+    def __init__(self, db: PgDatabase) -> None:
+        self._db = db
+
+    @classmethod
+    def aggregate_type(cls) -> str:
+        return aggregate_name(cls.aggregate)
+
+    def to_data(self, aggregate: A) -> dict[str, Any]:
+        """Aggregate -> JSON object stored in data. Override for custom mapping."""
+        return json.loads(json.dumps(_state(aggregate), default=str))
+
+    def from_data(self, data: dict[str, Any]) -> A:
+        """Stored JSON object -> aggregate. Override for custom mapping."""
+        if dataclasses.is_dataclass(self.aggregate):
+            return _from_json(self.aggregate, data)
+        return self.aggregate(**data)
+
+    async def get(self, id: str) -> Optional[A]:
+        async with self._db.connection() as conn:
+            row = await conn.fetchrow(
+                "SELECT data, version FROM urich_aggregates WHERE aggregate_type = $1 AND id = $2",
+                self.aggregate_type(),
+                id,
+            )
+        if row is None:
+            return None
+        aggregate = self.from_data(json.loads(row["data"]))
+        if hasattr(aggregate, "version"):
+            aggregate.version = row["version"]
+        return aggregate
+
+    async def add(self, aggregate: A) -> None:
+        """Insert; an existing id raises asyncpg.UniqueViolationError."""
+        async with self._db.connection() as conn:
+            await conn.execute(
+                "INSERT INTO urich_aggregates (aggregate_type, id, data, version) VALUES ($1, $2, $3::jsonb, $4)",
+                self.aggregate_type(),
+                str(getattr(aggregate, "id")),
+                json.dumps(self.to_data(aggregate)),
+                getattr(aggregate, "version", 0),
+            )
+
+    async def save(self, aggregate: A) -> None:
+        """Update without a version check (the version still counts up); LookupError if the
+        aggregate was never added."""
+        async with self._db.connection() as conn:
+            status = await conn.execute(
+                "UPDATE urich_aggregates SET data = $3::jsonb, version = version + 1, updated_at = now() "
+                "WHERE aggregate_type = $1 AND id = $2",
+                self.aggregate_type(),
+                str(getattr(aggregate, "id")),
+                json.dumps(self.to_data(aggregate)),
+            )
+        if status.endswith(" 0"):
+            raise LookupError(f"{self.aggregate.__name__} {getattr(aggregate, 'id')!r} not found")
+
+    async def save_versioned(self, aggregate: A, expected_version: int) -> None:
+        """Update if the stored version is expected_version; ConcurrencyError if it moved on,
+        LookupError if the aggregate was never added."""
+        id = str(getattr(aggregate, "id"))
+        previous = getattr(aggregate, "version", None)
+        if previous is not None:
+            aggregate.version = expected_version + 1  # type: ignore[attr-defined]
+        try:
+            async with self._db.connection() as conn:
+                status = await conn.execute(
+                    "UPDATE urich_aggregates SET data = $3::jsonb, version = $4 + 1, updated_at = now() "
+                    "WHERE aggregate_type = $1 AND id = $2 AND version = $4",
+                    self.aggregate_type(),
+                    id,
+                    json.dumps(self.to_data(aggregate)),
+                    expected_version,
+                )
+                if status.endswith(" 0"):
+                    actual = await conn.fetchval(
+                        "SELECT version FROM urich_aggregates WHERE aggregate_type = $1 AND id = $2",
+                        self.aggregate_type(),
+                        id,
+                    )
+                    if actual is None:
+                        raise LookupError(f"{self.aggregate.__name__} {id!r} not found")
+                    raise ConcurrencyError(self.aggregate_type(), id, expected_version, actual)
+        except BaseException:
+            if previous is not None:
+                aggregate.version = previous  # type: ignore[attr-defined]
+            raise
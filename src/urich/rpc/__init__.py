@@ -1,5 +1,8 @@
+from urich.rpc.interceptors import BearerTokenInterceptor, RetryInterceptor, RpcCall, rpc_call_headers
 from urich.rpc.protocol import RpcError, RpcServerHandler, RpcTransport
+from urich.rpc.reliable import CircuitBreaker, ReliableRpc
 from urich.rpc.rpc_module import JsonHttpRpcTransport, RpcClient, RpcModule, RpcServer
+from urich.rpc.selectors import FirstHealthySelector, InstanceSelector, RandomSelector, RoundRobinSelector
 
 __all__ = [
     "RpcClient",
@@ -8,5 +11,15 @@ __all__ = [
     "RpcServer",
     "RpcServerHandler",
     "RpcTransport",
+    "RpcCall",
+    "BearerTokenInterceptor",
+    "RetryInterceptor",
+    "rpc_call_headers",
     "JsonHttpRpcTransport",
+    "ReliableRpc",
+    "CircuitBreaker",
+    "InstanceSelector",
+    "RoundRobinSelector",
+    "RandomSelector",
+    "FirstHealthySelector",
 ]
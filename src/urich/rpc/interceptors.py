@@ -0,0 +1,82 @@
+"""
+RPC client interceptors: cross-cutting behavior around RpcClient.call (auth headers, retries,
+timing). An interceptor is async def (call, call_next) -> result; call_next(call) runs the rest of
+the chain and finally discovery + instance failover + transport, so it may be awaited again.
+"""
+from __future__ import annotations
+
+import asyncio
+import contextvars
+import inspect
+from dataclasses import dataclass, field
+from typing import Any, Awaitable, Callable
+
+from urich.rpc.protocol import RpcError
+
+_headers: contextvars.ContextVar[dict[str, str]] = contextvars.ContextVar("urich_rpc_headers", default={})
+
+
+def rpc_call_headers() -> dict[str, str]:
+    """Headers interceptors set for the outgoing call; transports send them (JsonHttpRpcTransport does)."""
+    return _headers.get()
+
+
+@dataclass
+class RpcCall:
+    """Outgoing call as seen by interceptors; params and headers may be changed before call_next."""
+
+    service: str
+    method: str
+    params: dict[str, Any]
+    headers: dict[str, str] = field(default_factory=dict)
+
+
+RpcInterceptor = Callable[[RpcCall, Callable[[RpcCall], Awaitable[Any]]], Awaitable[Any]]
+
+
+class BearerTokenInterceptor:
+    """Adds Authorization: Bearer <token>. token: string, or (async) callable returning a fresh one."""
+
+    def __init__(self, token: str | Callable[[], Any]) -> None:
+        self._token = token
+
+    async def __call__(self, call: RpcCall, call_next: Callable[[RpcCall], Awaitable[Any]]) -> Any:
+        token = self._token() if callable(self._token) else self._token
+        if inspect.isawaitable(token):
+            token = await token
+        call.headers["Authorization"] = f"Bearer {token}"
+        return await call_next(call)
+
+
+class RetryInterceptor:
+    """
+    Re-runs the call (with its own instance failover) when it fails with an RpcError whose code is
+    in codes (default: transport failures), up to attempts runs in total, sleeping backoff seconds
+    before the first retry and doubling up to max_backoff. Error envelopes from a server are final.
+    """
+
+    def __init__(
+        self,
+        attempts: int = 3,
+        *,
+        backoff: float = 0.1,
+        max_backoff: float = 2.0,
+        codes: set[str] | None = None,
+    ) -> None:
+        from urich.rpc.rpc_module import RETRYABLE_CODES
+
+        self._attempts = max(attempts, 1)
+        self._backoff = backoff
+        self._max_backoff = max_backoff
+        self._codes = set(codes) if codes is not None else set(RETRYABLE_CODES)
+
+    async def __call__(self, call: RpcCall, call_next: Callable[[RpcCall], Awaitable[Any]]) -> Any:
+        delay = self._backoff
+        for attempt in range(1, self._attempts + 1):
+            try:
+                return await call_next(call)
+            except RpcError as e:
+                if e.code not in self._codes or attempt == self._attempts:
+                    raise
+            await asyncio.sleep(delay)
+            delay = min(delay * 2, self._max_backoff)
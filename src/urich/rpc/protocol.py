@@ -3,11 +3,13 @@ from typing import Any, Protocol, runtime_checkable
 
 
 class RpcError(Exception):
-    """RPC call failed: server returned error envelope or transport failed."""
+    """RPC call failed: server returned error envelope or transport failed.
+    details: the envelope's details (e.g. from an AppError raised by the server), else None."""
 
-    def __init__(self, code: str, message: str) -> None:
+    def __init__(self, code: str, message: str, *, details: Any = None) -> None:
         self.code = code
         self.message = message
+        self.details = details
         super().__init__(f"[{code}] {message}")
 
 
@@ -0,0 +1,188 @@
+"""
+Reliable RPC: calls that need no answer but must not be lost. enqueue() stores the call in the
+outbox (a CallOutbox) and returns its call_id; relay() delivers due calls through RpcClient,
+retrying with backoff, and sends permanent failures to a dead-letter sink.
+"""
+from __future__ import annotations
+
+import asyncio
+import logging
+import time
+from dataclasses import dataclass
+from typing import Any, Callable
+
+from urich.events.outbox import CallOutbox, OutboundCall
+from urich.events.retry import DeadLetter, DeadLetterSink, LoggingDeadLetterSink, RetryPolicy
+from urich.rpc.protocol import RpcError
+from urich.rpc.rpc_module import ERROR_STATUS, RETRYABLE_CODES, RpcClient
+
+logger = logging.getLogger("urich.rpc")
+
+Observer = Callable[[str, str], None]
+
+OUTCOMES = ("queued", "delivered", "dead_lettered")
+
+
+def is_retryable(error: Exception) -> bool:
+    """Transport failures and 5xx error envelopes are retried; other envelopes (4xx) are final."""
+    if not isinstance(error, RpcError):
+        return True
+    return error.code in RETRYABLE_CODES or ERROR_STATUS.get(error.code, 400) in (500, 502, 503, 504)
+
+
+@dataclass
+class CircuitBreaker:
+    """
+    Per-service breaker: after failure_threshold consecutive retryable failures the service is
+    skipped for reset_after seconds; then one call is let through (half-open), and its outcome
+    closes the breaker or opens it again.
+    """
+
+    failure_threshold: int = 5
+    reset_after: float = 30.0
+
+    def __post_init__(self) -> None:
+        if self.failure_threshold < 1:
+            raise ValueError("failure_threshold must be at least 1")
+        self._failures: dict[str, int] = {}
+        self._open_until: dict[str, float] = {}
+
+    def allow(self, service: str, now: float) -> bool:
+        until = self._open_until.get(service)
+        if until is None:
+            return True
+        if now < until:
+            return False
+        self._open_until[service] = now + self.reset_after  # half-open: this call only
+        return True
+
+    def record_success(self, service: str) -> None:
+        self._failures.pop(service, None)
+        self._open_until.pop(service, None)
+
+    def record_failure(self, service: str, now: float) -> None:
+        failures = self._failures.get(service, 0) + 1
+        self._failures[service] = failures
+        if failures >= self.failure_threshold:
+            self._open_until[service] = now + self.reset_after
+
+    def is_open(self, service: str, now: float) -> bool:
+        return now < self._open_until.get(service, 0.0)
+
+
+class ReliableRpc:
+    """
+    Outbox-backed calls: await rpc.enqueue("billing", "order_completed", {...}) -> call_id.
+    relay() (run it periodically, e.g. from SchedulerModule) delivers the calls that are due:
+    - success: the call is finished in the outbox and never sent again;
+    - retryable failure (is_retryable): attempts + 1, next try after retry.delay(attempts);
+    - non-retryable error envelope, or retry.max_attempts reached: DeadLetter (event_type
+      "rpc:<service>.<method>", metadata with call_id, service, method, params) to dead_letter,
+      then finished.
+    max_concurrency: deliveries in flight per service (None: one at a time per service).
+    breaker: CircuitBreaker; while open, a service's calls stay pending without using attempts.
+    stats(service) and add_observer(fn(service, outcome)) report queued / delivered / dead_lettered.
+    """
+
+    def __init__(
+        self,
+        client: RpcClient,
+        outbox: CallOutbox,
+        *,
+        retry: RetryPolicy | None = None,
+        dead_letter: DeadLetterSink | None = None,
+        max_concurrency: int | None = None,
+        breaker: CircuitBreaker | None = None,
+        retryable: Callable[[Exception], bool] = is_retryable,
+        clock: Callable[[], float] = time.time,
+    ) -> None:
+        if not isinstance(outbox, CallOutbox):
+            raise TypeError(f"{type(outbox).__name__} cannot store RPC calls (needs the CallOutbox methods)")
+        if max_concurrency is not None and max_concurrency < 1:
+            raise ValueError("max_concurrency must be at least 1")
+        self._client = client
+        self._outbox = outbox
+        self._retry = retry or RetryPolicy(max_attempts=5, backoff=1.0, max_backoff=300.0)
+        self._dead_letter = dead_letter or LoggingDeadLetterSink()
+        self._max_concurrency = max_concurrency or 1
+        self._breaker = breaker
+        self._retryable = retryable
+        self._clock = clock
+        self._semaphores: dict[str, asyncio.Semaphore] = {}
+        self._relay_lock = asyncio.Lock()
+        self._stats: dict[str, dict[str, int]] = {}
+        self._observers: list[Observer] = []
+
+    def add_observer(self, observer: Observer) -> ReliableRpc:
+        """observer(service, outcome) for each queued / delivered / dead_lettered call. Returns self."""
+        self._observers.append(observer)
+        return self
+
+    def stats(self, service: str) -> dict[str, int]:
+        """Counts for service since start: {"queued": n, "delivered": n, "dead_lettered": n}."""
+        return {outcome: self._stats.get(service, {}).get(outcome, 0) for outcome in OUTCOMES}
+
+    async def enqueue(self, service: str, method: str, params: dict[str, Any]) -> str:
+        """Store the call for delivery by relay(); returns its call_id."""
+        call = OutboundCall(service, method, dict(params), next_attempt_at=self._clock())
+        await self._outbox.append_calls([call])
+        self._count(service, "queued")
+        return call.call_id
+
+    async def relay(self) -> int:
+        """Deliver every due call; returns how many were delivered. Concurrent runs are serialized."""
+        async with self._relay_lock:
+            now = self._clock()
+            due = [c for c in await self._outbox.fetch_pending_calls() if c.next_attempt_at <= now]
+            results = await asyncio.gather(*(self._deliver(call) for call in due))
+            return sum(results)
+
+    async def _deliver(self, call: OutboundCall) -> bool:
+        semaphore = self._semaphores.setdefault(call.service, asyncio.Semaphore(self._max_concurrency))
+        async with semaphore:
+            if self._breaker is not None and not self._breaker.allow(call.service, self._clock()):
+                return False
+            try:
+                await self._client.call(call.service, call.method, call.params, raise_on_error=True)
+            except Exception as e:
+                await self._failed(call, e)
+                return False
+            if self._breaker is not None:
+                self._breaker.record_success(call.service)
+            await self._outbox.finish_calls([call.call_id])
+            self._count(call.service, "delivered")
+            return True
+
+    async def _failed(self, call: OutboundCall, error: Exception) -> None:
+        now = self._clock()
+        retryable = self._retryable(error)
+        if retryable and self._breaker is not None:
+            self._breaker.record_failure(call.service, now)
+        call.attempts += 1
+        call.last_error = str(error) if isinstance(error, RpcError) else f"{type(error).__name__}: {error}"
+        if retryable and call.attempts < self._retry.max_attempts:
+            call.next_attempt_at = now + self._retry.delay(call.attempts)
+            await self._outbox.update_call(call)
+            logger.warning(
+                "rpc outbox: %s.%s (%s) failed, attempt %s: %s",
+                call.service, call.method, call.call_id, call.attempts, call.last_error,
+            )
+            return
+        await self._dead_letter.put(DeadLetter(
+            f"rpc:{call.service}.{call.method}", call, "rpc_outbox", error, call.attempts,
+            metadata={
+                "call_id": call.call_id,
+                "service": call.service,
+                "method": call.method,
+                "params": call.params,
+                "created_at": call.created_at,
+            },
+        ))
+        await self._outbox.finish_calls([call.call_id])
+        self._count(call.service, "dead_lettered")
+
+    def _count(self, service: str, outcome: str) -> None:
+        counts = self._stats.setdefault(service, {})
+        counts[outcome] = counts.get(outcome, 0) + 1
+        for observer in self._observers:
+            observer(service, outcome)
@@ -5,15 +5,47 @@ Transport and serialization — user's choice; optional minimal HTTP+JSON out of
 """
 from __future__ import annotations
 
+import inspect
+import logging
 from typing import Any, Callable
 
 from starlette.requests import Request
 from starlette.responses import Response
 
 from urich.core.app import Application
+from urich.core.context import bind_request, unbind_request
 from urich.core.module import Module
 from urich.discovery.protocol import ServiceDiscovery
+from urich.domain.errors import AppError
+from urich.rpc.interceptors import RpcCall, RpcInterceptor, _headers, rpc_call_headers
 from urich.rpc.protocol import RpcError, RpcServerHandler, RpcTransport
+from urich.rpc.selectors import InstanceSelector, RoundRobinSelector
+
+logger = logging.getLogger("urich.rpc")
+
+JSONRPC_VERSION = "2.0"
+PARSE_ERROR = -32700
+INVALID_REQUEST = -32600
+METHOD_NOT_FOUND = -32601
+INVALID_PARAMS = -32602
+INTERNAL_ERROR = -32603
+SERVER_ERROR = -32000
+# Error envelope codes from RpcServerHandler -> JSON-RPC codes; others become SERVER_ERROR with data.code.
+_JSONRPC_CODES = {"METHOD_NOT_FOUND": METHOD_NOT_FOUND, "INVALID_PARAMS": INVALID_PARAMS, "INTERNAL": INTERNAL_ERROR}
+_INTERNAL_ENVELOPE = b'{"error": {"code": "INTERNAL", "message": "Internal error"}}'
+
+# HTTP status of the RPC route for an error envelope code; other (application) codes -> 400.
+ERROR_STATUS = {
+    "NOT_FOUND": 404,
+    "INVALID_PARAMS": 422,
+    "PAYLOAD_TOO_LARGE": 413,
+    "INTERNAL": 500,
+    "NOT_IMPLEMENTED": 501,
+    "SERVICE_UNAVAILABLE": 503,
+}
+
+# Transport failures worth retrying on another instance.
+RETRYABLE_CODES = {"TRANSPORT_ERROR", "SERVICE_UNAVAILABLE"}
 
 
 class RpcModule(Module):
@@ -26,45 +58,131 @@ class RpcModule(Module):
         self._server_path: str | None = None
         self._server_transport: Any = None
         self._server_handler: RpcServerHandler | None = None
+        self._server_jsonrpc = False
         self._client_discovery: ServiceDiscovery | None = None
         self._client_transport: RpcTransport | None = None
+        self._client_max_payload_bytes: int | None = None
+        self._client_selector: InstanceSelector | None = None
+        self._client_attempts: int | None = None
+        self._client_interceptors: list[RpcInterceptor] = []
+        self._reliable: dict[str, Any] | None = None
 
     def server(
         self,
         path: str = "/rpc",
         transport: Any = None,
         handler: RpcServerHandler | type | None = None,
+        *,
+        jsonrpc: bool = False,
     ) -> RpcModule:
-        """Route for incoming RPC. handler: instance or class (then registered and resolved from container)."""
+        """Route for incoming RPC. handler: instance or class (then registered and resolved from container).
+        jsonrpc=True: JSON-RPC 2.0 at POST {path} (envelopes, error codes, batches) instead of POST {path}/{method}.
+        transport is ignored (deprecated): the server route is always HTTP.
+        """
+        if transport is not None:
+            from urich.core.deprecation import warn
+
+            warn(
+                "rpc.server_transport",
+                "RpcModule.server(transport=...) has no effect and will be removed.",
+                replacement="RpcModule.server(path, handler=...)",
+            )
         self._server_path = path.rstrip("/")
         self._server_transport = transport
         self._server_handler = handler
+        self._server_jsonrpc = jsonrpc
         return self
 
     def client(
         self,
         discovery: ServiceDiscovery | None = None,
         transport: RpcTransport | None = None,
+        *,
+        max_payload_bytes: int | None = None,
+        selector: InstanceSelector | None = None,
+        attempts: int | None = None,
+        interceptors: list[RpcInterceptor] | None = None,
     ) -> RpcModule:
-        """Client: discovery (resolve name -> URL) and transport."""
+        """Client: discovery (resolve name -> URL) and transport.
+        max_payload_bytes: RpcClient.call rejects larger params before any network attempt.
+        selector / attempts: instance order (default round-robin) and failover limit, see RpcClient.
+        interceptors: wrap every call (e.g. BearerTokenInterceptor, RetryInterceptor), first outermost.
+        """
         self._client_discovery = discovery
         self._client_transport = transport
+        self._client_max_payload_bytes = max_payload_bytes
+        self._client_selector = selector
+        self._client_attempts = attempts
+        self._client_interceptors = list(interceptors or [])
+        return self
+
+    def reliable(
+        self,
+        outbox: Any = None,
+        *,
+        retry: Any = None,
+        dead_letter: Any = None,
+        max_concurrency: int | None = None,
+        breaker: Any = None,
+    ) -> RpcModule:
+        """Register ReliableRpc (outbox-backed calls) on top of the client. outbox: a CallOutbox,
+        default the container's OutboxStorage; dead_letter defaults to a registered DeadLetterSink."""
+        self._reliable = {
+            "outbox": outbox,
+            "retry": retry,
+            "dead_letter": dead_letter,
+            "max_concurrency": max_concurrency,
+            "breaker": breaker,
+        }
         return self
 
     def register_into(self, app: Application) -> None:
         if self._server_path is not None:
             if self._server_handler is not None and isinstance(self._server_handler, type):
                 app.container.register_class(self._server_handler)
-            app.add_route(
-                f"{self._server_path}/{{path:path}}",
-                self._make_rpc_endpoint(app),
-                methods=["POST"],
-            )
+            if self._server_jsonrpc:
+                route_path = self._server_path or "/"
+                app.add_route(route_path, self._make_jsonrpc_endpoint(app), methods=["POST"])
+            else:
+                route_path = f"{self._server_path}/{{path:path}}"
+                app.add_route(route_path, self._make_rpc_endpoint(app), methods=["POST"])
+            app._rpc_methods[route_path] = _method_names(self._server_handler)
         if self._client_discovery is not None:
             app.container.register_instance(ServiceDiscovery, self._client_discovery)
         if self._client_transport is not None:
             app.container.register_instance(RpcTransport, self._client_transport)
-            app.container.register_class(RpcClient)
+            container = app.container
+            container.register(
+                RpcClient,
+                lambda c=container: RpcClient(
+                    c.resolve(ServiceDiscovery),
+                    c.resolve(RpcTransport),
+                    max_payload_bytes=self._client_max_payload_bytes,
+                    selector=self._client_selector,
+                    attempts=self._client_attempts,
+                    interceptors=self._client_interceptors,
+                ),
+            )
+        if self._reliable is not None:
+            from urich.rpc.reliable import ReliableRpc
+
+            container = app.container
+            container.register(ReliableRpc, lambda c=container: self._make_reliable(c))
+
+    def _make_reliable(self, container: Any) -> Any:
+        from urich.events.outbox import OutboxStorage
+        from urich.events.retry import DeadLetterSink
+        from urich.rpc.reliable import ReliableRpc
+
+        options = dict(self._reliable or {})
+        if options["outbox"] is None:
+            options["outbox"] = container.resolve(OutboxStorage)
+        if options["dead_letter"] is None:
+            try:
+                options["dead_letter"] = container.resolve(DeadLetterSink)
+            except KeyError:
+                pass
+        return ReliableRpc(container.resolve(RpcClient), options.pop("outbox"), **options)
 
     def _make_rpc_endpoint(self, app: Application) -> Callable:
         """Minimal endpoint: POST body = JSON {method, params}; response = JSON.
@@ -73,6 +191,13 @@ class RpcModule(Module):
         import json
 
         async def endpoint(request: Request) -> Response:
+            token = bind_request(request)
+            try:
+                return await handle(request)
+            finally:
+                unbind_request(token)
+
+        async def handle(request: Request) -> Response:
             method = request.path_params.get("path", "") if request.path_params else ""
             try:
                 body = await request.json()
@@ -82,15 +207,132 @@ class RpcModule(Module):
             payload_bytes = json.dumps(params).encode()
             if self._server_handler is not None:
                 h = app.container.resolve(self._server_handler) if isinstance(self._server_handler, type) else self._server_handler
-                result = await h.handle(method, payload_bytes)
+                try:
+                    result = await h.handle(method, payload_bytes)
+                except AppError as e:
+                    return Response(
+                        content=_error_envelope(e.code, e.message, e.details),
+                        status_code=e.status,
+                        media_type="application/json",
+                    )
+                except Exception:
+                    logger.exception("RPC handler failed for method %r", method)
+                    result = _INTERNAL_ENVELOPE
             else:
-                result = json.dumps({"error": "no handler"}).encode()
+                result = json.dumps({"error": {"code": "NOT_IMPLEMENTED", "message": "no handler"}}).encode()
             return Response(
                 content=result,
+                status_code=_envelope_status(result),
                 media_type="application/json",
             )
         return endpoint
 
+    def _make_jsonrpc_endpoint(self, app: Application) -> Callable:
+        """JSON-RPC 2.0 endpoint: single or batch calls; notifications (no id) get no response."""
+        import json
+
+        async def call_one(request_obj: Any) -> dict[str, Any] | None:
+            if not isinstance(request_obj, dict) or request_obj.get("jsonrpc") != JSONRPC_VERSION:
+                return _jsonrpc_error(None, INVALID_REQUEST, "Invalid Request")
+            method, params = request_obj.get("method"), request_obj.get("params", {})
+            is_notification = "id" not in request_obj
+            call_id = request_obj.get("id")
+            if not isinstance(method, str):
+                return _jsonrpc_error(call_id, INVALID_REQUEST, "Invalid Request")
+            if not isinstance(params, dict):
+                response = _jsonrpc_error(call_id, INVALID_PARAMS, "params must be an object (by-name)")
+                return None if is_notification else response
+            handler = self._server_handler
+            if handler is None:
+                response = _jsonrpc_error(call_id, METHOD_NOT_FOUND, "no handler")
+                return None if is_notification else response
+            h = app.container.resolve(handler) if isinstance(handler, type) else handler
+            if isinstance(h, RpcServer) and not h.has_method(method):
+                response = _jsonrpc_error(call_id, METHOD_NOT_FOUND, f"unknown method {method!r}")
+                return None if is_notification else response
+            try:
+                raw = await h.handle(method, json.dumps(params).encode())
+                data = json.loads(raw.decode()) if raw else None
+            except AppError as e:
+                data = json.loads(_error_envelope(e.code, e.message, e.details))
+            except Exception:
+                logger.exception("JSON-RPC handler failed for method %r", method)
+                response = _jsonrpc_error(call_id, INTERNAL_ERROR, "Internal error")
+                return None if is_notification else response
+            if is_notification:
+                return None
+            if _is_error_response(data):
+                err = data["error"]
+                code = err.get("code", "UNKNOWN") if isinstance(err, dict) else "UNKNOWN"
+                message = err.get("message", str(err)) if isinstance(err, dict) else str(err)
+                extra = {"code": code}
+                if isinstance(err, dict) and err.get("details") is not None:
+                    extra["details"] = err["details"]
+                return _jsonrpc_error(call_id, _JSONRPC_CODES.get(code, SERVER_ERROR), message, data=extra)
+            return {"jsonrpc": JSONRPC_VERSION, "id": call_id, "result": data}
+
+        async def endpoint(request: Request) -> Response:
+            token = bind_request(request)
+            try:
+                return await handle(request)
+            finally:
+                unbind_request(token)
+
+        async def handle(request: Request) -> Response:
+            try:
+                body = json.loads(await request.body())
+            except ValueError:
+                return Response(json.dumps(_jsonrpc_error(None, PARSE_ERROR, "Parse error")), media_type="application/json")
+            out: Any
+            if isinstance(body, list):
+                if not body:
+                    out = _jsonrpc_error(None, INVALID_REQUEST, "Invalid Request")
+                else:
+                    out = [r for r in [await call_one(item) for item in body] if r is not None] or None
+            else:
+                out = await call_one(body)
+            if out is None:
+                return Response(status_code=204)
+            return Response(json.dumps(out), media_type="application/json")
+
+        return endpoint
+
+
+def _error_envelope(code: str, message: str, details: Any = None) -> bytes:
+    """Standard error envelope bytes; details only when given."""
+    import json
+
+    err: dict[str, Any] = {"code": code, "message": message}
+    if details is not None:
+        err["details"] = details
+    return json.dumps({"error": err}).encode()
+
+
+def _jsonrpc_error(call_id: Any, code: int, message: str, *, data: Any = None) -> dict[str, Any]:
+    error: dict[str, Any] = {"code": code, "message": message}
+    if data is not None:
+        error["data"] = data
+    return {"jsonrpc": JSONRPC_VERSION, "id": call_id, "error": error}
+
+
+def _unwrap_jsonrpc(content: bytes) -> bytes:
+    """JSON-RPC response -> result JSON, or the standard error envelope (data.code kept when present)."""
+    import json
+
+    try:
+        data = json.loads(content.decode())
+    except ValueError as e:
+        raise RpcError("TRANSPORT_ERROR", f"invalid JSON-RPC response: {e}") from e
+    if not isinstance(data, dict) or ("result" not in data and "error" not in data):
+        raise RpcError("TRANSPORT_ERROR", "invalid JSON-RPC response")
+    if "error" in data:
+        err = data["error"] if isinstance(data["error"], dict) else {}
+        extra = err.get("data")
+        code = extra.get("code") if isinstance(extra, dict) and "code" in extra else f"JSONRPC_{err.get('code')}"
+        details = extra.get("details") if isinstance(extra, dict) else None
+        return _error_envelope(code, err.get("message", ""), details)
+    return json.dumps(data["result"]).encode()
+
 
 class RpcServer:
     """
@@ -111,38 +353,105 @@ class RpcServer:
         if not isinstance(params, dict):
             params = {}
 
-        name = (method or "").replace("/", "_").strip()
-        handler_fn = getattr(self, name, None) if name else None
-        if not callable(handler_fn):
+        handler_fn = self._method(method)
+        if handler_fn is None:
             return json.dumps({"error": {"code": "NOT_FOUND", "message": f"unknown method {method!r}"}}).encode()
+        try:
+            inspect.signature(handler_fn).bind(**params)
+        except TypeError as e:
+            return json.dumps({"error": {"code": "INVALID_PARAMS", "message": str(e)}}).encode()
 
         try:
             result = handler_fn(**params)
             if hasattr(result, "__await__"):
                 result = await result
-        except RpcError as e:
-            return json.dumps({"error": {"code": e.code, "message": e.message}}).encode()
-        except Exception as e:
-            return json.dumps({"error": {"code": "INTERNAL", "message": str(e)}}).encode()
+        except (RpcError, AppError) as e:
+            return _error_envelope(e.code, e.message, e.details)
+        except Exception:
+            # Details go to the log, not to the caller.
+            logger.exception("RPC method %r failed", method)
+            return _INTERNAL_ENVELOPE
 
         return json.dumps(result).encode()
 
+    def has_method(self, method: str) -> bool:
+        return self._method(method) is not None
+
+    def _method(self, method: str) -> Callable[..., Any] | None:
+        name = (method or "").replace("/", "_").strip()
+        if not name or name.startswith("_") or name in ("handle", "has_method"):
+            return None
+        handler_fn = getattr(self, name, None)
+        return handler_fn if callable(handler_fn) else None
+
+
+def _method_names(handler: Any) -> list[str]:
+    """Public RPC method names of an RpcServer (class or instance); [] for other handlers."""
+    cls = handler if isinstance(handler, type) else type(handler)
+    if not issubclass(cls, RpcServer):
+        return []
+    return sorted(
+        name for name in dir(cls)
+        if not name.startswith("_") and name not in ("handle", "has_method") and callable(getattr(cls, name))
+    )
+
 
 # Standard error envelope: {"error": {"code": "...", "message": "..."}} or {"error": "string"}
 def _is_error_response(data: dict) -> bool:
     return isinstance(data, dict) and "error" in data
 
 
+def _envelope_status(result: bytes) -> int:
+    """200 for results; status for {"error": {"code": ...}} from ERROR_STATUS (default 400)."""
+    import json
+
+    if not result or not result.lstrip().startswith(b"{"):
+        return 200
+    try:
+        data = json.loads(result)
+    except ValueError:
+        return 200
+    if not _is_error_response(data):
+        return 200
+    code = data["error"].get("code") if isinstance(data["error"], dict) else None
+    return ERROR_STATUS.get(code, 400)
+
+
 class RpcClient:
     """
     Facade: call(service_name, method, params) -> result dict or None.
     Uses ServiceDiscovery + RpcTransport; JSON encode/decode inside.
     On server error envelope or transport failure: return None or raise RpcError (see raise_on_error).
+    max_payload_bytes: larger params fail with PAYLOAD_TOO_LARGE before the transport is called.
+    selector orders a service's instances (default RoundRobinSelector); on a transport failure
+    the next instance is tried, up to attempts calls (default: each instance once).
+    Error envelopes from a server are not retried. last_url: instance that answered the last call.
+    interceptors (or add_interceptor) wrap the whole call, failover included; the first added is
+    outermost. They see error envelopes as RpcError.
     """
 
-    def __init__(self, discovery: ServiceDiscovery, transport: RpcTransport) -> None:
+    def __init__(
+        self,
+        discovery: ServiceDiscovery,
+        transport: RpcTransport,
+        *,
+        max_payload_bytes: int | None = None,
+        selector: InstanceSelector | None = None,
+        attempts: int | None = None,
+        interceptors: list[RpcInterceptor] | None = None,
+    ) -> None:
         self._discovery = discovery
         self._transport = transport
+        self._max_payload_bytes = max_payload_bytes
+        self._selector = selector if selector is not None else RoundRobinSelector()
+        self._attempts = attempts
+        self._interceptors: list[RpcInterceptor] = list(interceptors or [])
+        self.last_url: str | None = None
+
+    def add_interceptor(self, interceptor: RpcInterceptor) -> RpcClient:
+        """async def interceptor(call: RpcCall, call_next) -> result. Returns self."""
+        self._interceptors.append(interceptor)
+        return self
 
     async def call(
         self,
@@ -152,41 +461,111 @@ class RpcClient:
         *,
         raise_on_error: bool = False,
     ) -> dict | Any | None:
+        async def dispatch(index: int, call: RpcCall) -> Any:
+            if index == len(self._interceptors):
+                return await self._send(call)
+            return await self._interceptors[index](call, lambda c: dispatch(index + 1, c))
+
+        try:
+            return await dispatch(0, RpcCall(service_name, method, dict(params)))
+        except RpcError:
+            if raise_on_error:
+                raise
+            return None
+
+    async def _send(self, call: RpcCall) -> Any:
+        """Discovery, encoding and size check, failover over instances; error envelopes raise RpcError."""
         import json
 
+        service_name, method = call.service, call.method
         urls = self._discovery.resolve(service_name)
         if not urls:
-            if raise_on_error:
-                raise RpcError("SERVICE_UNAVAILABLE", f"Service {service_name!r} not found")
-            return None
+            raise RpcError("SERVICE_UNAVAILABLE", f"Service {service_name!r} not found")
         try:
-            payload = json.dumps(params).encode()
-            result = await self._transport.call(urls[0], method, payload)
-            data = json.loads(result.decode()) if result else None
-        except Exception as e:
-            if raise_on_error:
-                raise RpcError("TRANSPORT_ERROR", str(e)) from e
-            return None
+            payload = json.dumps(call.params).encode()
+        except (TypeError, ValueError) as e:
+            raise RpcError("INVALID_PARAMS", f"{service_name}.{method} params are not JSON-serializable: {e}") from e
+        if self._max_payload_bytes is not None and len(payload) > self._max_payload_bytes:
+            raise RpcError(
+                "PAYLOAD_TOO_LARGE",
+                f"{service_name}.{method} params are {len(payload)} bytes, limit is {self._max_payload_bytes}",
+            )
+        token = _headers.set({**rpc_call_headers(), **call.headers})
+        try:
+            data = await self._call_instances(service_name, urls, method, payload)
+        finally:
+            _headers.reset(token)
         if _is_error_response(data):
             err = data["error"]
+            details = None
             if isinstance(err, dict):
                 code = err.get("code", "UNKNOWN")
                 msg = err.get("message", str(err))
+                details = err.get("details")
             else:
+                from urich.core.deprecation import warn
+
+                warn(
+                    "rpc.string_error_envelope",
+                    'RPC servers returning {"error": "<string>"} are deprecated.',
+                    replacement='{"error": {"code": "...", "message": "..."}}',
+                )
                 code = "UNKNOWN"
                 msg = str(err)
-            if raise_on_error:
-                raise RpcError(code, msg)
-            return None
+            raise RpcError(code, msg, details=details)
         return data
 
+    async def _call_instances(self, service_name: str, urls: list[str], method: str, payload: bytes) -> Any:
+        """Try instances in selector order until one answers; raise the last transport RpcError."""
+        import json
+
+        ordered = self._selector.order(service_name, urls)
+        limit = self._attempts if self._attempts is not None else len(ordered)
+        last_error = RpcError("SERVICE_UNAVAILABLE", f"Service {service_name!r} has no reachable instance")
+        for url in ordered[: max(limit, 1)]:
+            try:
+                result = await self._transport.call(url, method, payload)
+                data = json.loads(result.decode()) if result else None
+            except RpcError as e:
+                if e.code not in RETRYABLE_CODES:
+                    raise
+                last_error = e
+            except Exception as e:
+                last_error = RpcError("TRANSPORT_ERROR", str(e))
+                last_error.__cause__ = e
+            else:
+                self.last_url = url
+                return data
+            logger.warning("rpc: %s.%s failed on %s: %s", service_name, method, url, last_error)
+        raise last_error
+
 
 class JsonHttpRpcTransport:
-    """Minimal transport out of the box: HTTP + JSON for quick start."""
+    """
+    Minimal transport out of the box: HTTP + JSON for quick start (requires httpx).
+    timeout: seconds per call; headers: sent with every call (e.g. Authorization), plus those set
+    by client interceptors for the current call.
+    jsonrpc=True: talk to a JSON-RPC 2.0 server at {base_path} and unwrap its envelopes.
+    Connection refused -> RpcError SERVICE_UNAVAILABLE; timeouts, other network failures and
+    non-JSON HTTP error responses -> RpcError TRANSPORT_ERROR. JSON error envelopes are returned
+    as-is for RpcClient to turn into RpcError(code, message).
+    """
 
-    def __init__(self, discovery: ServiceDiscovery, base_path: str = "/rpc") -> None:
+    def __init__(
+        self,
+        discovery: ServiceDiscovery,
+        base_path: str = "/rpc",
+        *,
+        timeout: float = 10.0,
+        headers: dict[str, str] | None = None,
+        jsonrpc: bool = False,
+    ) -> None:
         self._discovery = discovery
         self._base_path = base_path
+        self._timeout = timeout
+        self._headers = dict(headers or {})
+        self._jsonrpc = jsonrpc
+        self._next_id = 0
 
     async def call(self, url: str, method: str, payload: bytes) -> bytes:
         import json
@@ -194,8 +573,31 @@ class JsonHttpRpcTransport:
             import httpx
         except ImportError:
             raise RuntimeError("JsonHttpRpcTransport requires httpx; pip install httpx")
-        full_url = url.rstrip("/") + self._base_path + "/" + method
-        body = {"method": method, "params": json.loads(payload.decode() or "{}")}
-        async with httpx.AsyncClient() as client:
-            r = await client.post(full_url, json=body)
-            return r.content
+        params = json.loads(payload.decode() or "{}")
+        if self._jsonrpc:
+            self._next_id += 1
+            full_url = url.rstrip("/") + self._base_path
+            body = {"jsonrpc": JSONRPC_VERSION, "id": self._next_id, "method": method, "params": params}
+        else:
+            full_url = url.rstrip("/") + self._base_path + "/" + method
+            body = {"method": method, "params": params}
+        try:
+            headers = {**self._headers, **rpc_call_headers()}
+            async with httpx.AsyncClient(timeout=self._timeout, headers=headers) as client:
+                r = await client.post(full_url, json=body)
+        except httpx.ConnectError as e:
+            raise RpcError("SERVICE_UNAVAILABLE", f"{full_url}: {e}") from e
+        except httpx.TimeoutException as e:
+            raise RpcError("TRANSPORT_ERROR", f"{full_url}: timed out after {self._timeout}s") from e
+        except httpx.HTTPError as e:
+            raise RpcError("TRANSPORT_ERROR", f"{full_url}: {e}") from e
+        if r.status_code >= 400:
+            try:
+                data = r.json()
+            except ValueError:
+                data = None
+            if not _is_error_response(data):
+                raise RpcError("TRANSPORT_ERROR", f"{full_url}: HTTP {r.status_code}")
+        if self._jsonrpc:
+            return _unwrap_jsonrpc(r.content)
+        return r.content
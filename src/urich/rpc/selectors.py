@@ -0,0 +1,50 @@
+"""Instance selection for RpcClient: which service URL to try first, and in what order to fail over."""
+from __future__ import annotations
+
+import itertools
+import random
+import threading
+from typing import Protocol, runtime_checkable
+
+
+@runtime_checkable
+class InstanceSelector(Protocol):
+    """Order the resolved URLs of a service for one call; RpcClient tries them in this order."""
+
+    def order(self, service_name: str, urls: list[str]) -> list[str]:
+        ...
+
+
+class RoundRobinSelector:
+    """Rotate the starting instance per service (default). Thread-safe; state is per selector."""
+
+    def __init__(self) -> None:
+        self._counters: dict[str, itertools.count[int]] = {}
+        self._lock = threading.Lock()
+
+    def order(self, service_name: str, urls: list[str]) -> list[str]:
+        if not urls:
+            return []
+        with self._lock:
+            counter = self._counters.setdefault(service_name, itertools.count())
+            start = next(counter) % len(urls)
+        return urls[start:] + urls[:start]
+
+
+class RandomSelector:
+    """Random order per call."""
+
+    def __init__(self, seed: int | None = None) -> None:
+        self._random = random.Random(seed)
+
+    def order(self, service_name: str, urls: list[str]) -> list[str]:
+        shuffled = list(urls)
+        self._random.shuffle(shuffled)
+        return shuffled
+
+
+class FirstHealthySelector:
+    """Discovery order: always the first instance, the next ones only on failover."""
+
+    def order(self, service_name: str, urls: list[str]) -> list[str]:
+        return list(urls)
@@ -0,0 +1,26 @@
+from urich.saga.saga import (
+    Abort,
+    Complete,
+    InMemorySagaStore,
+    PublishEvent,
+    Saga,
+    SagaAction,
+    SagaInstance,
+    SagaStore,
+    SendCommand,
+)
+from urich.saga.saga_module import SagaManager, SagaModule
+
+__all__ = [
+    "Abort",
+    "Complete",
+    "InMemorySagaStore",
+    "PublishEvent",
+    "Saga",
+    "SagaAction",
+    "SagaInstance",
+    "SagaManager",
+    "SagaModule",
+    "SagaStore",
+    "SendCommand",
+]
@@ -0,0 +1,122 @@
+"""Saga (process manager): state per correlation id, reacting to events with actions."""
+from __future__ import annotations
+
+import copy
+import re
+from dataclasses import dataclass, field
+from typing import Any, ClassVar, Generic, Protocol, TypeVar, runtime_checkable
+
+S = TypeVar("S")
+
+RUNNING = "running"
+COMPLETED = "completed"
+ABORTED = "aborted"
+FAILED = "failed"  # aborted, and a compensation command failed too
+
+
+def _snake(name: str) -> str:
+    return re.sub(r"(?<!^)(?=[A-Z])", "_", name).lower()
+
+
+@dataclass
+class SendCommand:
+    """Dispatch command in-process (app.dispatch). compensation: command that undoes it, sent if the
+    saga is aborted later; recorded only once command succeeded."""
+
+    command: Any
+    compensation: Any = None
+
+
+@dataclass
+class PublishEvent:
+    """Publish event on the EventBus."""
+
+    event: Any
+
+
+@dataclass
+class Complete:
+    """The saga finished; later events for its correlation id are ignored."""
+
+
+@dataclass
+class Abort:
+    """The saga failed: compensations of the steps done so far are sent, most recent first."""
+
+    reason: str = ""
+
+
+SagaAction = SendCommand | PublishEvent | Complete | Abort
+
+
+class Saga(Generic[S]):
+    """
+    Process manager: subclass with events (types it reacts to), correlation_id(event) and
+    on_event(state, event) -> list of actions; initial_state() gives the state of a new instance.
+    starts: event types that may start an instance (default: any of events); other events without
+    a running instance are ignored.
+    name: stable store key (default: snake_case class name); class OrderFulfillment(Saga, name="...").
+    """
+
+    saga_name: ClassVar[str] = "saga"
+    events: ClassVar[tuple[type, ...]] = ()
+    starts: ClassVar[tuple[type, ...]] = ()
+
+    def __init_subclass__(cls, name: str | None = None, **kwargs: Any) -> None:
+        super().__init_subclass__(**kwargs)
+        cls.saga_name = name or _snake(cls.__name__)
+
+    def initial_state(self) -> S:
+        return {}  # type: ignore[return-value]
+
+    def correlation_id(self, event: Any) -> str:
+        raise NotImplementedError(f"{type(self).__qualname__} must implement correlation_id(event)")
+
+    def on_event(self, state: S, event: Any) -> list[SagaAction]:
+        """Update state (mutate it in place) and return the actions to run; may be async."""
+        raise NotImplementedError(f"{type(self).__qualname__} must implement on_event(state, event)")
+
+
+@dataclass
+class SagaInstance:
+    """Persisted progress of one saga run: state, status, compensations of the completed steps
+    (in order) and the abort reason or error."""
+
+    saga: str
+    correlation_id: str
+    state: Any
+    status: str = RUNNING
+    compensations: list[Any] = field(default_factory=list)
+    error: str | None = None
+
+    @property
+    def finished(self) -> bool:
+        return self.status != RUNNING
+
+
+@runtime_checkable
+class SagaStore(Protocol):
+    """Where saga instances live (memory, a table). Keyed by saga name and correlation id."""
+
+    async def get(self, saga: str, correlation_id: str) -> SagaInstance | None:
+        ...
+
+    async def save(self, instance: SagaInstance) -> None:
+        ...
+
+
+class InMemorySagaStore:
+    """Single-process store; get returns a copy, so only save changes what is stored."""
+
+    def __init__(self) -> None:
+        self._instances: dict[tuple[str, str], SagaInstance] = {}
+
+    async def get(self, saga: str, correlation_id: str) -> SagaInstance | None:
+        instance = self._instances.get((saga, correlation_id))
+        return copy.deepcopy(instance) if instance is not None else None
+
+    async def save(self, instance: SagaInstance) -> None:
+        self._instances[(instance.saga, instance.correlation_id)] = copy.deepcopy(instance)
+
+    def instances(self) -> list[SagaInstance]:
+        return [copy.deepcopy(i) for i in self._instances.values()]
@@ -0,0 +1,175 @@
+"""
+SagaModule — sagas (process managers) subscribed to their events, with state in a SagaStore.
+Configure via .saga(...) and .store(...); register with app.register(sagas).
+"""
+from __future__ import annotations
+
+import inspect
+import logging
+from typing import Any, Callable
+
+from urich.core.app import Application
+from urich.core.module import Module
+from urich.domain.events import EventBus
+from urich.saga.saga import (
+    ABORTED,
+    COMPLETED,
+    FAILED,
+    Abort,
+    Complete,
+    InMemorySagaStore,
+    PublishEvent,
+    Saga,
+    SagaInstance,
+    SagaStore,
+    SendCommand,
+)
+
+logger = logging.getLogger("urich.saga")
+
+
+class SagaManager:
+    """
+    Runs sagas for events: loads the instance for the event's correlation id, calls on_event,
+    saves the state and runs the actions. Commands go through app.dispatch; a command that raises
+    aborts the saga. Abort sends the recorded compensations, most recent first.
+    Registered in the container; instance(saga, correlation_id) reads progress.
+    """
+
+    def __init__(self, app: Application, store: SagaStore) -> None:
+        self._app = app
+        self.store = store
+
+    async def instance(self, saga: type[Saga[Any]] | str, correlation_id: str) -> SagaInstance | None:
+        name = saga if isinstance(saga, str) else saga.saga_name
+        return await self.store.get(name, correlation_id)
+
+    def handler(self, saga_type: type[Saga[Any]]) -> Callable[[Any], Any]:
+        """Event handler running saga_type (resolved from the container) for each event."""
+
+        async def handle(event: Any) -> None:
+            await self.handle(self._app.container.resolve(saga_type), event)
+
+        handle.__qualname__ = f"{saga_type.__qualname__}.on_event"
+        return handle
+
+    async def handle(self, saga: Saga[Any], event: Any) -> None:
+        name, correlation_id = saga.saga_name, saga.correlation_id(event)
+        instance = await self.store.get(name, correlation_id)
+        if instance is None:
+            if saga.starts and not isinstance(event, saga.starts):
+                return
+            instance = SagaInstance(name, correlation_id, saga.initial_state())
+        elif instance.finished:
+            return
+        actions = saga.on_event(instance.state, event)
+        if inspect.isawaitable(actions):
+            actions = await actions
+        await self.store.save(instance)
+        for action in actions or []:
+            if isinstance(action, SendCommand):
+                try:
+                    await self._app.dispatch(action.command)
+                except Exception as e:
+                    logger.exception("Saga %s %s: command %s failed", name, correlation_id, type(action.command).__name__)
+                    await self._abort(name, correlation_id, f"{type(action.command).__name__} failed: {e}")
+                    return
+                if action.compensation is not None and not await self._record(name, correlation_id, action.compensation):
+                    return
+            elif isinstance(action, PublishEvent):
+                await self._app.container.resolve(EventBus).publish(action.event)
+            elif isinstance(action, Complete):
+                await self._finish(name, correlation_id)
+                return
+            elif isinstance(action, Abort):
+                await self._abort(name, correlation_id, action.reason)
+                return
+            else:
+                raise TypeError(f"Saga {name}: unknown action {action!r}")
+
+    async def _record(self, name: str, correlation_id: str, compensation: Any) -> bool:
+        """Keep a compensation for a completed step. If the saga was aborted meanwhile (by an event
+        the command published), compensate the step right away; False then."""
+        instance = await self.store.get(name, correlation_id)
+        if instance is None:
+            return False
+        if instance.finished:
+            if instance.status in (ABORTED, FAILED):
+                await self._compensate(instance, [compensation])
+                await self.store.save(instance)
+            return False
+        instance.compensations.append(compensation)
+        await self.store.save(instance)
+        return True
+
+    async def _finish(self, name: str, correlation_id: str) -> None:
+        instance = await self.store.get(name, correlation_id)
+        if instance is not None and not instance.finished:
+            instance.status = COMPLETED
+            instance.compensations = []
+            await self.store.save(instance)
+
+    async def _abort(self, name: str, correlation_id: str, reason: str) -> None:
+        instance = await self.store.get(name, correlation_id)
+        if instance is None or instance.finished:
+            return
+        compensations = list(reversed(instance.compensations))
+        instance.status, instance.error, instance.compensations = ABORTED, reason or None, []
+        await self.store.save(instance)  # before compensating, so events they publish see it aborted
+        await self._compensate(instance, compensations)
+        await self.store.save(instance)
+
+    async def _compensate(self, instance: SagaInstance, compensations: list[Any]) -> None:
+        for command in compensations:
+            try:
+                await self._app.dispatch(command)
+            except Exception as e:
+                logger.exception(
+                    "Saga %s %s: compensation %s failed", instance.saga, instance.correlation_id, type(command).__name__
+                )
+                instance.status = FAILED
+                instance.error = f"{instance.error or 'aborted'}; compensation {type(command).__name__} failed: {e}"
+
+
+class SagaModule(Module):
+    """
+    Sagas as object: SagaModule().saga(OrderFulfillment).store(PgSagaStore(...)).
+    Each saga class is resolved from the container (constructor dependencies injected) and
+    subscribed to its events on the EventBus. State goes to the store (InMemorySagaStore by
+    default), registered as SagaStore; SagaManager is registered too.
+    """
+
+    def __init__(self) -> None:
+        self._sagas: list[type[Saga[Any]]] = []
+        self._store: SagaStore | None = None
+
+    def saga(self, saga_type: type[Saga[Any]]) -> SagaModule:
+        if not saga_type.events:
+            raise ValueError(f"Saga {saga_type.__qualname__} declares no events")
+        self._sagas.append(saga_type)
+        return self
+
+    def store(self, impl: SagaStore) -> SagaModule:
+        self._store = impl
+        return self
+
+    def register_into(self, app: Application) -> None:
+        container = app.container
+        store = self._store or InMemorySagaStore()
+        container.register_instance(SagaStore, store)
+        manager = SagaManager(app, store)
+        container.register_instance(SagaManager, manager)
+
+        # EventBus: if already registered (e.g. EventBusModule), use it; else default in-process
+        try:
+            event_bus = container.resolve(EventBus)
+        except KeyError:
+            from urich.domain.events import InProcessEventDispatcher
+            event_bus = InProcessEventDispatcher()
+            container.register_instance(EventBus, event_bus)
+            container.register_instance(InProcessEventDispatcher, event_bus)
+        for saga_type in self._sagas:
+            container.register_class(saga_type)
+            handler = manager.handler(saga_type)
+            for event_type in saga_type.events:
+                event_bus.subscribe(event_type, handler)
@@ -0,0 +1,4 @@
+from urich.scheduler.cron import CronError, CronSchedule
+from urich.scheduler.scheduler_module import JobStatus, Scheduler, SchedulerModule
+
+__all__ = ["CronError", "CronSchedule", "JobStatus", "Scheduler", "SchedulerModule"]
@@ -0,0 +1,77 @@
+"""Five-field cron expressions ("*/5 * * * *"): parsing and next fire time."""
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+
+_FIELDS = (("minute", 0, 59), ("hour", 0, 23), ("day of month", 1, 31), ("month", 1, 12), ("day of week", 0, 7))
+
+
+class CronError(ValueError):
+    """Invalid cron expression."""
+
+
+def _parse_field(text: str, name: str, low: int, high: int) -> set[int]:
+    values: set[int] = set()
+    for part in text.split(","):
+        part, _, step_text = part.partition("/")
+        try:
+            step = int(step_text) if step_text else 1
+            if part == "*":
+                start, end = low, high
+            elif "-" in part:
+                start, end = (int(x) for x in part.split("-", 1))
+            else:
+                start = int(part)
+                end = high if step_text else start
+        except ValueError:
+            raise CronError(f"Invalid {name} field {text!r}") from None
+        if step < 1 or start < low or end > high or start > end:
+            raise CronError(f"{name} field {text!r} out of range {low}-{high}")
+        values.update(range(start, end + 1, step))
+    return values
+
+
+class CronSchedule:
+    """
+    minute hour day-of-month month day-of-week, each *, a number, a range a-b, a step */n or a-b/n,
+    or a comma list of those. Day of week 0 or 7 is Sunday. When both day fields are restricted,
+    a day matching either one fires (as in classic cron).
+    """
+
+    def __init__(self, expression: str) -> None:
+        parts = expression.split()
+        if len(parts) != 5:
+            raise CronError(f"Cron expression needs 5 fields, got {len(parts)}: {expression!r}")
+        self.expression = expression
+        fields = [_parse_field(p, name, low, high) for p, (name, low, high) in zip(parts, _FIELDS)]
+        self.minutes, self.hours, self.days, self.months, weekdays = fields
+        self.weekdays = {d % 7 for d in weekdays}  # 0 = Sunday
+        self._any_day = parts[2] == "*"
+        self._any_weekday = parts[4] == "*"
+
+    def _day_matches(self, moment: datetime) -> bool:
+        day_ok = moment.day in self.days
+        weekday_ok = (moment.weekday() + 1) % 7 in self.weekdays
+        if self._any_day or self._any_weekday:
+            return day_ok and weekday_ok
+        return day_ok or weekday_ok
+
+    def next_after(self, moment: datetime) -> datetime:
+        """First matching minute strictly after moment (same tzinfo)."""
+        t = moment.replace(second=0, microsecond=0) + timedelta(minutes=1)
+        limit = t + timedelta(days=366 * 5)
+        while t < limit:
+            if t.month not in self.months:
+                t = (t.replace(day=1, hour=0, minute=0) + timedelta(days=32)).replace(day=1)
+            elif not self._day_matches(t):
+                t = t.replace(hour=0, minute=0) + timedelta(days=1)
+            elif t.hour not in self.hours:
+                t = t.replace(minute=0) + timedelta(hours=1)
+            elif t.minute not in self.minutes:
+                t += timedelta(minutes=1)
+            else:
+                return t
+        raise CronError(f"Cron expression {self.expression!r} never fires")
+
+    def __repr__(self) -> str:
+        return f"CronSchedule({self.expression!r})"
@@ -0,0 +1,188 @@
+"""
+SchedulerModule — periodic and cron jobs with access to the Container, started with the app and
+stopped on shutdown. Configure via .every(...) / .cron(...); register with app.register(scheduler).
+"""
+from __future__ import annotations
+
+import asyncio
+import contextlib
+import inspect
+import logging
+from dataclasses import asdict, dataclass
+from datetime import datetime, timedelta, timezone
+from typing import Any, Awaitable, Callable
+
+from urich.core.app import Application
+from urich.core.container import Container
+from urich.core.module import Module
+from urich.scheduler.cron import CronSchedule
+
+logger = logging.getLogger("urich.scheduler")
+
+Job = Callable[[Container], Any]
+Clock = Callable[[], datetime]
+Sleep = Callable[[float], Awaitable[Any]]
+
+
+def utc_now() -> datetime:
+    return datetime.now(timezone.utc)
+
+
+@dataclass
+class JobStatus:
+    """Execution history of one job; skips counts ticks dropped because the previous run was still active."""
+
+    name: str
+    schedule: str
+    runs: int = 0
+    skips: int = 0
+    failures: int = 0
+    running: bool = False
+    last_started: datetime | None = None
+    last_finished: datetime | None = None
+    last_error: str | None = None
+    next_run: datetime | None = None
+
+    def to_dict(self) -> dict[str, Any]:
+        return {k: v.isoformat() if isinstance(v, datetime) else v for k, v in asdict(self).items()}
+
+
+class ScheduledJob:
+    """A job and its schedule: a fixed interval in seconds, or a cron expression."""
+
+    def __init__(self, name: str, job: Job, *, interval: float | None = None, cron: CronSchedule | None = None) -> None:
+        self.name = name
+        self.job = job
+        self.interval = interval
+        self.cron = cron
+        self.status = JobStatus(name, cron.expression if cron is not None else f"every {interval}s")
+        self.task: asyncio.Task[None] | None = None
+
+    def next_run(self, now: datetime) -> datetime:
+        if self.cron is not None:
+            return self.cron.next_after(now)
+        return now + timedelta(seconds=self.interval or 0)
+
+
+class Scheduler:
+    """
+    Runs the jobs of a SchedulerModule; registered in the container. status() gives each job's
+    history; as a HealthProbe (health.probe("scheduler", Scheduler)) it fails while any job's
+    last run failed.
+    """
+
+    def __init__(self, container: Container, *, clock: Clock = utc_now, sleep: Sleep = asyncio.sleep) -> None:
+        self._container = container
+        self._clock = clock
+        self._sleep = sleep
+        self.jobs: dict[str, ScheduledJob] = {}
+        self._loops: list[asyncio.Task[None]] = []
+
+    def add(self, job: ScheduledJob) -> None:
+        if job.name in self.jobs:
+            raise ValueError(f"Job {job.name!r} already scheduled")
+        self.jobs[job.name] = job
+
+    @property
+    def running(self) -> bool:
+        return bool(self._loops)
+
+    def status(self) -> dict[str, JobStatus]:
+        return {name: job.status for name, job in self.jobs.items()}
+
+    def check(self) -> str | None:
+        failed = [s.name for s in self.status().values() if s.last_error is not None]
+        return f"last run failed: {', '.join(failed)}" if failed else None
+
+    async def start(self) -> None:
+        if self._loops:
+            return
+        self._loops = [asyncio.ensure_future(self._loop(job)) for job in self.jobs.values()]
+
+    async def stop(self) -> None:
+        """Cancel the schedule loops and any run still in progress."""
+        tasks = self._loops + [j.task for j in self.jobs.values() if j.task is not None and not j.task.done()]
+        self._loops = []
+        for task in tasks:
+            task.cancel()
+        for task in tasks:
+            with contextlib.suppress(asyncio.CancelledError):
+                await task
+
+    async def run_now(self, name: str) -> None:
+        """Run a job immediately (outside its schedule) and wait for it; skipped if it is running."""
+        job = self.jobs[name]
+        if self._trigger(job):
+            await asyncio.shield(job.task)  # type: ignore[arg-type]
+
+    async def _loop(self, job: ScheduledJob) -> None:
+        while True:
+            now = self._clock()
+            job.status.next_run = job.next_run(now)
+            await self._sleep(max((job.status.next_run - now).total_seconds(), 0.0))
+            self._trigger(job)
+
+    def _trigger(self, job: ScheduledJob) -> bool:
+        if job.task is not None and not job.task.done():
+            job.status.skips += 1
+            logger.warning("Job %s skipped: previous run still active", job.name)
+            return False
+        job.task = asyncio.ensure_future(self._run(job))
+        return True
+
+    async def _run(self, job: ScheduledJob) -> None:
+        status = job.status
+        status.running = True
+        status.runs += 1
+        status.last_started = self._clock()
+        try:
+            result = job.job(self._container)
+            if inspect.isawaitable(result):
+                await result
+        except Exception as e:
+            status.failures += 1
+            status.last_error = f"{type(e).__name__}: {e}"
+            logger.exception("Job %s failed", job.name)
+        else:
+            status.last_error = None
+        finally:
+            status.running = False
+            status.last_finished = self._clock()
+
+
+class SchedulerModule(Module):
+    """
+    Background jobs as object: SchedulerModule().every(60, "drain_outbox", drain).cron("0 3 * * *",
+    "report", build_report). A job is a sync or async callable taking the Container. Jobs start after
+    application startup and are cancelled on shutdown; a job never overlaps itself (a tick arriving
+    while it runs is skipped and counted); exceptions are logged and kept in its status.
+    clock (timezone-aware, UTC by default) and sleep can be replaced, e.g. in tests.
+    """
+
+    def __init__(self, *, clock: Clock = utc_now, sleep: Sleep = asyncio.sleep) -> None:
+        self._clock = clock
+        self._sleep = sleep
+        self._jobs: list[ScheduledJob] = []
+        self.scheduler: Scheduler | None = None
+
+    def every(self, interval: float | timedelta, name: str, job: Job) -> SchedulerModule:
+        """Run job every interval (seconds or timedelta), first run one interval after startup."""
+        seconds = interval.total_seconds() if isinstance(interval, timedelta) else float(interval)
+        if seconds <= 0:
+            raise ValueError(f"Job {name!r}: interval must be positive")
+        self._jobs.append(ScheduledJob(name, job, interval=seconds))
+        return self
+
+    def cron(self, expression: str, name: str, job: Job) -> SchedulerModule:
+        """Run job at the minutes matching a 5-field cron expression, evaluated in the clock's timezone."""
+        self._jobs.append(ScheduledJob(name, job, cron=CronSchedule(expression)))
+        return self
+
+    def register_into(self, app: Application) -> None:
+        scheduler = Scheduler(app.container, clock=self._clock, sleep=self._sleep)
+        for job in self._jobs:
+            scheduler.add(job)
+        self.scheduler = scheduler
+        app.container.register_instance(Scheduler, scheduler)
+        app.starlette.router.on_startup.append(scheduler.start)
+        app.starlette.router.on_shutdown.append(scheduler.stop)
@@ -0,0 +1,184 @@
+"""
+TestClient — in-process requests against an Application for tests. Requests go through the
+app's ASGI entry point like a server's would (middlewares, validation, error handlers), with
+in-memory receive/send instead of a socket; no httpx or running server needed.
+"""
+from __future__ import annotations
+
+import asyncio
+import json as jsonlib
+from typing import Any, Mapping, Sequence
+from urllib.parse import unquote, urlencode
+
+
+class TestResponse:
+    """Response of one TestClient request. headers: lower-case names (repeated headers joined with ", ")."""
+
+    __test__ = False  # not a pytest test class
+
+    def __init__(self, status_code: int, raw_headers: list[tuple[bytes, bytes]], content: bytes) -> None:
+        self.status_code = status_code
+        self.raw_headers = raw_headers
+        self.content = content
+        self.headers: dict[str, str] = {}
+        for key, value in raw_headers:
+            name, text = key.decode("latin-1").lower(), value.decode("latin-1")
+            self.headers[name] = f"{self.headers[name]}, {text}" if name in self.headers else text
+
+    @property
+    def text(self) -> str:
+        return self.content.decode("utf-8")
+
+    def json(self) -> Any:
+        return jsonlib.loads(self.content)
+
+    def __repr__(self) -> str:
+        return f"<TestResponse [{self.status_code}]>"
+
+
+class TestClient:
+    """
+    async with TestClient(app) as client:  # runs startup / shutdown hooks
+        response = await client.post("/orders/commands/create_order", json={...}, headers={...})
+        assert response.status_code == 200 and response.json()["ok"]
+    Without `async with` requests still work, but lifespan hooks don't run.
+    raise_server_exceptions=False returns the 500 response instead of re-raising handler errors.
+    """
+
+    __test__ = False
+
+    def __init__(
+        self,
+        app: Any,
+        *,
+        headers: Mapping[str, str] | None = None,
+        client: tuple[str, int] = ("testclient", 50000),
+        raise_server_exceptions: bool = True,
+    ) -> None:
+        self.app = app
+        self.headers = dict(headers or {})
+        self.client = client
+        self.raise_server_exceptions = raise_server_exceptions
+        self._lifespan: asyncio.Task[None] | None = None
+        self._lifespan_queue: asyncio.Queue[dict[str, Any]] = asyncio.Queue()
+        self._lifespan_events: asyncio.Queue[dict[str, Any]] = asyncio.Queue()
+
+    async def __aenter__(self) -> TestClient:
+        scope = {"type": "lifespan", "asgi": {"version": "3.0"}, "state": {}}
+        self._lifespan = asyncio.create_task(self.app(scope, self._lifespan_queue.get, self._lifespan_events.put))
+        await self._lifespan_queue.put({"type": "lifespan.startup"})
+        await self._lifespan_step("lifespan.startup")
+        return self
+
+    async def __aexit__(self, *exc_info: Any) -> None:
+        await self._lifespan_queue.put({"type": "lifespan.shutdown"})
+        await self._lifespan_step("lifespan.shutdown")
+        if self._lifespan is not None:
+            await self._lifespan
+            self._lifespan = None
+
+    async def _lifespan_step(self, step: str) -> None:
+        assert self._lifespan is not None
+        waiter = asyncio.ensure_future(self._lifespan_events.get())
+        done, _ = await asyncio.wait({waiter, self._lifespan}, return_when=asyncio.FIRST_COMPLETED)
+        if waiter not in done:
+            waiter.cancel()
+            self._lifespan.result()  # re-raise the app's error
+            raise RuntimeError(f"Application exited during {step}")
+        message = waiter.result()
+        if message["type"] == f"{step}.failed":
+            raise RuntimeError(f"{step} failed: {message.get('message', '')}")
+
+    async def request(
+        self,
+        method: str,
+        path: str,
+        *,
+        params: Mapping[str, Any] | Sequence[tuple[str, Any]] | None = None,
+        headers: Mapping[str, str] | None = None,
+        json: Any = None,
+        content: bytes | str | None = None,
+    ) -> TestResponse:
+        """Send one request; json= encodes the body and sets Content-Type: application/json."""
+        path, _, query = path.partition("?")
+        if params:
+            query = "&".join(q for q in (query, urlencode(params, doseq=True)) if q)
+        body = b""
+        request_headers = {"host": "testserver", "user-agent": "urich-testclient"}
+        if json is not None:
+            body = jsonlib.dumps(json).encode()
+            request_headers["content-type"] = "application/json"
+        elif content is not None:
+            body = content.encode() if isinstance(content, str) else content
+        if body:
+            request_headers["content-length"] = str(len(body))
+        request_headers.update({k.lower(): v for k, v in {**self.headers, **(headers or {})}.items()})
+        scope = {
+            "type": "http",
+            "asgi": {"version": "3.0"},
+            "http_version": "1.1",
+            "method": method.upper(),
+            "scheme": "http",
+            "path": unquote(path),  # decoded like a server does; raw_path keeps the escapes
+            "raw_path": path.encode(),
+            "query_string": query.encode(),
+            "root_path": "",
+            "headers": [(k.encode("latin-1"), v.encode("latin-1")) for k, v in request_headers.items()],
+            "client": self.client,
+            "server": ("testserver", 80),
+        }
+        request_sent = False
+        complete = asyncio.Event()
+        status: int | None = None
+        raw_headers: list[tuple[bytes, bytes]] = []
+        chunks: list[bytes] = []
+
+        async def receive() -> dict[str, Any]:
+            nonlocal request_sent
+            if not request_sent:
+                request_sent = True
+                return {"type": "http.request", "body": body, "more_body": False}
+            await complete.wait()
+            return {"type": "http.disconnect"}
+
+        async def send(message: dict[str, Any]) -> None:
+            nonlocal status, raw_headers
+            if message["type"] == "http.response.start":
+                status = message["status"]
+                raw_headers = list(message.get("headers", []))
+            elif message["type"] == "http.response.body":
+                chunks.append(message.get("body", b""))
+                if not message.get("more_body", False):
+                    complete.set()
+
+        try:
+            await self.app(scope, receive, send)
+        except Exception:
+            if self.raise_server_exceptions or status is None:
+                raise
+        finally:
+            complete.set()
+        if status is None:
+            raise RuntimeError(f"{method.upper()} {path}: application sent no response")
+        return TestResponse(status, raw_headers, b"".join(chunks))
+
+    async def get(self, path: str, **kwargs: Any) -> TestResponse:
+        return await self.request("GET", path, **kwargs)
+
+    async def post(self, path: str, **kwargs: Any) -> TestResponse:
+        return await self.request("POST", path, **kwargs)
+
+    async def put(self, path: str, **kwargs: Any) -> TestResponse:
+        return await self.request("PUT", path, **kwargs)
+
+    async def patch(self, path: str, **kwargs: Any) -> TestResponse:
+        return await self.request("PATCH", path, **kwargs)
+
+    async def delete(self, path: str, **kwargs: Any) -> TestResponse:
+        return await self.request("DELETE", path, **kwargs)
+
+    async def options(self, path: str, **kwargs: Any) -> TestResponse:
+        return await self.request("OPTIONS", path, **kwargs)
+
+    async def head(self, path: str, **kwargs: Any) -> TestResponse:
+        return await self.request("HEAD", path, **kwargs)
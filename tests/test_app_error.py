@@ -0,0 +1,88 @@
+"""AppError: constructor status mapping and the error body over HTTP, RPC and dead letters."""
+from dataclasses import dataclass
+
+import pytest
+
+from urich import Application
+from urich.ddd import Command, DomainModule
+from urich.domain import AppError, DomainEvent
+from urich.events.retry import InMemoryDeadLetterSink, RetryPolicy, with_retry
+from urich.rpc import RpcModule, RpcServer
+from urich.testing import TestClient
+
+CONSTRUCTORS = [
+    (AppError.bad_request, 400),
+    (AppError.forbidden, 403),
+    (AppError.not_found, 404),
+    (AppError.conflict, 409),
+    (AppError.unprocessable, 422),
+]
+
+
+@dataclass
+class ReserveStock(Command):
+    sku: str
+
+
+@dataclass
+class StockReserved(DomainEvent):
+    sku: str
+
+
+@pytest.mark.parametrize("constructor, status", CONSTRUCTORS)
+def test_constructor_status(constructor, status):
+    error = constructor("INSUFFICIENT_STOCK", "Only 2 left", details={"available": 2})
+    assert (error.code, error.message, error.status, error.details) == (
+        "INSUFFICIENT_STOCK", "Only 2 left", status, {"available": 2},
+    )
+    assert str(error) == "Only 2 left"
+
+
+def test_default_status_and_body_shape():
+    error = AppError("OUT_OF_RANGE", "Too many")
+    assert error.status == 400
+    assert error.to_dict() == {"error": {"code": "OUT_OF_RANGE", "message": "Too many"}}
+    assert AppError.conflict("C", "m", details=[1]).to_dict() == {"error": {"code": "C", "message": "m", "details": [1]}}
+
+
+@pytest.mark.parametrize("constructor, status", CONSTRUCTORS)
+async def test_http_response_uses_status_and_error_body(constructor, status):
+    async def reserve(cmd: ReserveStock):
+        raise constructor("INSUFFICIENT_STOCK", f"No stock for {cmd.sku}", details={"available": 0})
+
+    app = Application()
+    app.register(DomainModule("stock").command(ReserveStock, reserve))
+    async with TestClient(app) as client:
+        response = await client.post("/stock/commands/reserve_stock", json={"sku": "A-1"})
+    assert response.status_code == status
+    assert response.json() == {
+        "error": {"code": "INSUFFICIENT_STOCK", "message": "No stock for A-1", "details": {"available": 0}}
+    }
+
+
+async def test_rpc_error_envelope_keeps_code_and_details():
+    class Stock(RpcServer):
+        async def reserve(self, sku: str):
+            raise AppError.conflict("INSUFFICIENT_STOCK", f"No stock for {sku}", details={"available": 0})
+
+    app = Application()
+    app.register(RpcModule().server("/rpc", handler=Stock()))
+    async with TestClient(app) as client:
+        response = await client.post("/rpc/reserve", json={"params": {"sku": "A-1"}})
+    assert response.json() == {
+        "error": {"code": "INSUFFICIENT_STOCK", "message": "No stock for A-1", "details": {"available": 0}}
+    }
+
+
+async def test_dead_letter_keeps_the_code():
+    async def handler(event):
+        raise AppError.unprocessable("SKU_RETIRED", "Retired")
+
+    async def no_sleep(seconds):
+        pass
+
+    sink = InMemoryDeadLetterSink()
+    await with_retry(handler, RetryPolicy(max_attempts=1), sink, sleep=no_sleep)(StockReserved("A-1"))
+    (letter,) = sink.letters
+    assert letter.code == "SKU_RETIRED"
+    assert letter.error.status == 422
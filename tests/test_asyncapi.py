@@ -0,0 +1,104 @@
+"""AsyncAPI document: channels for subscribed and published events, and RPC methods."""
+from dataclasses import dataclass
+
+from urich import Application
+from urich.ddd import DomainModule
+from urich.domain import DomainEvent
+from urich.rpc import RpcModule, RpcServer
+from urich.testing import TestClient
+
+
+@dataclass
+class OrderPlaced(DomainEvent):
+    order_id: str
+    total: float
+
+
+@dataclass
+class PaymentFailed(DomainEvent):
+    order_id: str
+
+
+@dataclass
+class InvoiceDrafted(DomainEvent):
+    invoice_id: str
+    order_id: str
+
+
+async def issue_invoice(event: OrderPlaced):
+    pass
+
+
+async def notify_customer(event: PaymentFailed):
+    pass
+
+
+class Billing(RpcServer):
+    async def get_invoice(self, invoice_id: str):
+        return None
+
+    async def void_invoice(self, invoice_id: str):
+        return None
+
+
+def make_app():
+    app = Application()
+    app.register(
+        DomainModule("billing")
+        .on_event(OrderPlaced, issue_invoice)
+        .on_event(PaymentFailed, notify_customer, retry=3)
+    )
+    app.register(RpcModule().server("/rpc", handler=Billing()))
+    app.declare_published_event(InvoiceDrafted)
+    return app.asyncapi(title="Billing", version="1.2")
+
+
+def test_two_subscriptions_one_published_event_and_rpc_methods():
+    spec = make_app().asyncapi_spec("Billing", "1.2")
+    assert spec["asyncapi"] == "2.6.0"
+    assert spec["info"] == {"title": "Billing", "version": "1.2"}
+    assert list(spec["channels"]) == ["invoice_drafted", "order_placed", "payment_failed"]
+
+    placed = spec["channels"]["order_placed"]
+    assert list(placed) == ["publish"]
+    assert placed["publish"]["operationId"] == "consume_order_placed"
+    assert placed["publish"]["x-handlers"] == ["issue_invoice"]
+    assert placed["publish"]["message"]["name"] == "order_placed"
+    payload = placed["publish"]["message"]["payload"]
+    assert payload["properties"]["order_id"]["type"] == "string"
+    assert payload["properties"]["total"]["type"] == "number"
+
+    failed = spec["channels"]["payment_failed"]
+    assert list(failed) == ["publish"]
+    assert failed["publish"]["x-handlers"] == ["notify_customer"]
+
+    drafted = spec["channels"]["invoice_drafted"]
+    assert list(drafted) == ["subscribe"]
+    assert drafted["subscribe"]["operationId"] == "emit_invoice_drafted"
+    assert set(drafted["subscribe"]["message"]["payload"]["properties"]) == {"invoice_id", "order_id"}
+
+    assert spec["x-rpc-methods"] == [{"path": "/rpc/{path:path}", "methods": ["get_invoice", "void_invoice"]}]
+
+
+def test_published_event_declared_by_name_and_schema():
+    app = Application().declare_published_event("stock_low", {"type": "object", "required": ["sku"]})
+    spec = app.asyncapi_spec()
+    assert spec["channels"] == {
+        "stock_low": {
+            "subscribe": {
+                "operationId": "emit_stock_low",
+                "message": {"name": "stock_low", "payload": {"type": "object", "required": ["sku"]}},
+            }
+        }
+    }
+    assert "x-rpc-methods" not in spec
+
+
+async def test_served_at_asyncapi_json_with_etag():
+    app = make_app()
+    async with TestClient(app) as client:
+        first = await client.get("/asyncapi.json")
+        second = await client.get("/asyncapi.json", headers={"If-None-Match": first.headers["etag"]})
+    assert first.status_code == 200
+    assert first.json() == app.asyncapi_spec("Billing", "1.2")
+    assert second.status_code == 304
@@ -0,0 +1,72 @@
+"""JWT decoding and AuthModule."""
+import pytest
+from starlette.responses import JSONResponse
+
+from urich import Application
+from urich.http.auth import AuthModule, InvalidToken, decode_jwt, encode_jwt
+from urich.testing import TestClient
+
+SECRET = "s3cret"
+
+
+def test_valid_exp_and_nbf():
+    token = encode_jwt({"sub": "u1", "exp": 2000, "nbf": 1000.5}, SECRET)
+    assert decode_jwt(token, SECRET, now=1500)["sub"] == "u1"
+    with pytest.raises(InvalidToken, match="token expired"):
+        decode_jwt(token, SECRET, now=2001)
+    with pytest.raises(InvalidToken, match="not yet valid"):
+        decode_jwt(token, SECRET, now=1000)
+
+
+@pytest.mark.parametrize("claim, value", [
+    ("exp", "tomorrow"),
+    ("exp", "2000"),
+    ("exp", None),
+    ("exp", True),
+    ("exp", [2000]),
+    ("nbf", {"at": 1000}),
+    ("nbf", "soon"),
+])
+def test_non_numeric_time_claim_is_an_invalid_token(claim, value):
+    token = encode_jwt({"sub": "u1", claim: value}, SECRET)
+    with pytest.raises(InvalidToken, match=f"invalid {claim} claim"):
+        decode_jwt(token, SECRET, now=1500)
+
+
+async def test_non_numeric_exp_is_a_401_not_a_500():
+    app = Application()
+
+    async def me(request):
+        return JSONResponse({"ok": True})
+
+    app.add_route("/me", me, methods=["GET"])
+    app.register(AuthModule(SECRET))
+    async with TestClient(app) as client:
+        token = encode_jwt({"sub": "u1", "exp": "never"}, SECRET)
+        response = await client.get("/me", headers={"Authorization": f"Bearer {token}"})
+        assert response.status_code == 401
+        assert "invalid exp claim" in response.json()["error"]["message"]
+
+
+@pytest.mark.parametrize("alg", ['HS256"; realm="evil', "HS256é☃", "HS\\256\r\n"])
+async def test_hostile_alg_gives_a_safe_www_authenticate(alg):
+    import base64
+    import json
+
+    app = Application()
+
+    async def me(request):
+        return JSONResponse({"ok": True})
+
+    app.add_route("/me", me, methods=["GET"])
+    app.register(AuthModule(SECRET))
+    header = base64.urlsafe_b64encode(json.dumps({"alg": alg}).encode()).rstrip(b"=").decode()
+    _, payload, signature = encode_jwt({"sub": "u1"}, SECRET).split(".")
+    async with TestClient(app) as client:
+        response = await client.get("/me", headers={"Authorization": f"Bearer {header}.{payload}.{signature}"})
+    assert response.status_code == 401
+    assert "not allowed" in response.json()["error"]["message"]
+    challenge = response.headers["www-authenticate"]
+    description = challenge.split('error_description="', 1)[1]
+    assert description.endswith('"') and '"' not in description[:-1] and "\\" not in description
+    assert all(" " <= c <= "~" for c in challenge)
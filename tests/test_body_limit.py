@@ -0,0 +1,75 @@
+"""Request body size limit: 413 before the handler runs, per-route overrides, streamed bodies."""
+from starlette.responses import JSONResponse
+
+from urich import Application
+from urich.testing import TestClient
+
+
+def make_app(**options):
+    calls = []
+
+    async def echo(request):
+        body = await request.body()
+        calls.append(len(body))
+        return JSONResponse({"size": len(body)})
+
+    app = Application(**options)
+    app.add_route("/notes", echo, methods=["POST"])
+    app.add_route("/uploads", echo, methods=["POST"], max_body_size=1000)
+    app.add_route("/dumps", echo, methods=["POST"], max_body_size=None)
+    return app, calls
+
+
+async def test_oversized_body_gets_413_without_calling_the_handler():
+    app, calls = make_app(max_body_size=100)
+    client = TestClient(app)
+    response = await client.post("/notes", content=b"x" * 101)
+    assert response.status_code == 413
+    assert response.json()["error"]["code"] == "PAYLOAD_TOO_LARGE"
+    assert "100 bytes" in response.json()["error"]["message"]
+    assert calls == []
+    assert (await client.post("/notes", content=b"x" * 100)).json() == {"size": 100}
+    assert calls == [100]
+
+
+async def test_per_route_limit_overrides_the_app_default():
+    app, calls = make_app(max_body_size=100)
+    client = TestClient(app)
+    assert (await client.post("/uploads", content=b"x" * 900)).status_code == 200
+    assert (await client.post("/uploads", content=b"x" * 1001)).status_code == 413
+    assert (await client.post("/dumps", content=b"x" * 5000)).status_code == 200
+    assert calls == [900, 5000]
+
+
+async def test_streamed_body_without_content_length_stops_at_the_limit():
+    app, calls = make_app(max_body_size=100)
+    chunks = [{"type": "http.request", "body": b"x" * 60, "more_body": True} for _ in range(10)]
+    received = []
+
+    async def receive():
+        received.append(1)
+        return chunks.pop(0)
+
+    sent = []
+
+    async def send(message):
+        sent.append(message)
+
+    scope = {
+        "type": "http",
+        "asgi": {"version": "3.0"},
+        "http_version": "1.1",
+        "method": "POST",
+        "scheme": "http",
+        "path": "/notes",
+        "raw_path": b"/notes",
+        "query_string": b"",
+        "root_path": "",
+        "headers": [(b"host", b"testserver")],
+        "client": ("127.0.0.1", 1234),
+        "server": ("testserver", 80),
+    }
+    await app(scope, receive, send)
+    assert sent[0]["status"] == 413
+    assert len(received) == 2
+    assert calls == []
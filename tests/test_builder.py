@@ -0,0 +1,245 @@
+"""Application builder: build-time validation and settings reaching the application and the server."""
+import asyncio
+import socket
+from dataclasses import dataclass
+
+import pytest
+from starlette.responses import JSONResponse
+
+from urich import Application
+from urich.core import ConfigError, ServerConfig
+from urich.ddd import DomainModule, Query
+from urich.testing import TestClient
+
+
+@dataclass
+class ListOrders(Query):
+    pass
+
+
+async def slow(request):
+    await asyncio.sleep(0.5)
+    return JSONResponse({"ok": True})
+
+
+async def echo_size(request):
+    return JSONResponse({"size": len(await request.body())})
+
+
+def free_port():
+    with socket.socket() as sock:
+        sock.bind(("127.0.0.1", 0))
+        return sock.getsockname()[1]
+
+
+def test_validation_problems_reported_together():
+    with pytest.raises(ConfigError) as exc:
+        Application.builder().port(70000).openapi(" ", "1.0").request_timeout(-1).build()
+    assert exc.value.problems == [
+        "port 70000 is out of range 0-65535 (0 picks a free port)",
+        "request_timeout must be positive or None, got -1",
+        "OpenAPI title is empty",
+    ]
+
+
+async def test_builder_settings_reach_the_application():
+    app = Application.builder().request_timeout(0.05).max_body_size(10).openapi("Orders", "2.0").build()
+    app.add_route("/slow", slow, methods=["GET"])
+    app.add_route("/size", echo_size, methods=["POST"])
+    client = TestClient(app)
+    timed_out = await client.get("/slow")
+    assert timed_out.status_code == 504
+    assert timed_out.json()["error"]["code"] == "GATEWAY_TIMEOUT"
+    too_large = await client.post("/size", content=b"x" * 11)
+    assert too_large.status_code == 413
+    fits = await client.post("/size", content=b"x" * 10)
+    assert fits.json() == {"size": 10}
+    spec = (await client.get("/openapi.json")).json()
+    assert spec["info"] == {"title": "Orders", "version": "2.0"}
+
+
+async def test_config_dict_reaches_the_application():
+    app = Application.builder({"request_timeout": "0.05", "max_body_size": "10"}).build()
+    app.add_route("/slow", slow, methods=["GET"])
+    app.add_route("/size", echo_size, methods=["POST"])
+    client = TestClient(app)
+    assert (await client.get("/slow")).status_code == 504
+    assert (await client.post("/size", content=b"x" * 11)).status_code == 413
+
+
+async def test_route_settings_override_the_builder():
+    app = Application.builder().request_timeout(0.05).max_body_size(10).build()
+    app.add_route("/slow", slow, methods=["GET"], timeout=None)
+    app.add_route("/size", echo_size, methods=["POST"], max_body_size=None)
+    client = TestClient(app)
+    assert (await client.get("/slow")).json() == {"ok": True}
+    assert (await client.post("/size", content=b"x" * 100)).json() == {"size": 100}
+
+
+async def test_openapi_is_applied_after_modules_and_only_when_configured():
+    async def list_orders(query: ListOrders):
+        return []
+
+    module = DomainModule("orders").query(ListOrders, list_orders)
+    documented = Application.builder().register(module).openapi("Orders").build()
+    spec = (await TestClient(documented).get("/openapi.json")).json()
+    assert "/orders/queries/list_orders" in spec["paths"]
+
+    undocumented = Application.builder(ServerConfig.production()).build()
+    assert (await TestClient(undocumented).get("/openapi.json")).status_code == 404
+
+
+def test_build_server_keeps_its_own_config():
+    builder = Application.builder(ServerConfig.production()).port(9000).server_options(limit_concurrency=10)
+    server = builder.build_server()
+    builder.port(9001)
+    assert server.config.port == 9000
+    assert server.config.server_options == {"proxy_headers": True, "access_log": False, "limit_concurrency": 10}
+    assert server.config.overrides() == {
+        "port": (8000, 9000),
+        "server_options": (
+            {"proxy_headers": True, "access_log": False},
+            {"proxy_headers": True, "access_log": False, "limit_concurrency": 10},
+        ),
+    }
+
+
+def test_server_settings_reach_uvicorn():
+    pytest.importorskip("uvicorn")
+    server = (
+        Application.builder()
+        .host("0.0.0.0")
+        .port(9100)
+        .log_level("warning")
+        .server_options(limit_concurrency=7, timeout_keep_alive=3)
+        .build_server()
+    )
+    config = server.uvicorn_config()
+    assert config.app is server.app
+    assert (config.host, config.port, config.log_level) == ("0.0.0.0", 9100, "warning")
+    assert (config.limit_concurrency, config.timeout_keep_alive) == (7, 3)
+
+
+async def test_timeout_fires_through_the_served_application():
+    uvicorn = pytest.importorskip("uvicorn")
+    port = free_port()
+    configured = Application.builder().port(port).request_timeout(0.05).log_level("warning").build_server()
+    configured.app.add_route("/slow", slow, methods=["GET"])
+    server = uvicorn.Server(configured.uvicorn_config())
+    serving = asyncio.create_task(server.serve())
+    try:
+        while not server.started:
+            await asyncio.sleep(0.01)
+        reader, writer = await asyncio.open_connection("127.0.0.1", port)
+        writer.write(b"GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        await writer.drain()
+        response = await reader.read()
+        writer.close()
+    finally:
+        server.should_exit = True
+        await serving
+    assert response.startswith(b"HTTP/1.1 504 ")
+    assert b'"GATEWAY_TIMEOUT"' in response
+
+
+def test_shutdown_grace_is_checked_and_read_from_dicts():
+    with pytest.raises(ConfigError, match="shutdown_grace must be >= 0 or None, got -1"):
+        Application.builder().shutdown_grace(-1).build()
+    assert ServerConfig.from_dict({"shutdown_grace": "5"}).shutdown_grace == 5.0
+    assert ServerConfig.from_dict({"shutdown_grace": "none"}).shutdown_grace is None
+
+
+async def request_slow(port):
+    reader, writer = await asyncio.open_connection("127.0.0.1", port)
+    writer.write(b"GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+    await writer.drain()
+    try:
+        return await reader.read()
+    finally:
+        writer.close()
+
+
+def served_app(port, grace, events, delay):
+    configured = Application.builder().port(port).shutdown_grace(grace).log_level("warning").build_server()
+
+    async def slow_handler(request):
+        await asyncio.sleep(delay)
+        events.append("handled")
+        return JSONResponse({"ok": True})
+
+    configured.app.add_route("/slow", slow_handler, methods=["GET"])
+    configured.app.starlette.router.on_shutdown.append(lambda: events.append("lifespan shutdown"))
+    return configured
+
+
+async def test_shutdown_drains_requests_in_flight_then_runs_lifespan():
+    pytest.importorskip("uvicorn")
+    port, events = free_port(), []
+    handle = await served_app(port, 5, events, 0.3).start()
+    in_flight = asyncio.create_task(request_slow(port))
+    await asyncio.sleep(0.1)
+    assert handle.active_connections == 1
+    await handle.shutdown()
+    assert (await in_flight).startswith(b"HTTP/1.1 200 ")
+    assert events == ["handled", "lifespan shutdown"]
+    assert not handle.running
+    with pytest.raises(OSError):
+        await asyncio.open_connection("127.0.0.1", port)
+
+
+async def test_grace_period_cancels_slow_requests():
+    pytest.importorskip("uvicorn")
+    port, events = free_port(), []
+    handle = await served_app(port, 0.1, events, 10).start()
+    in_flight = asyncio.create_task(request_slow(port))
+    await asyncio.sleep(0.1)
+    started = asyncio.get_running_loop().time()
+    await handle.shutdown()
+    assert asyncio.get_running_loop().time() - started < 2
+    assert events == ["lifespan shutdown"]
+    assert not (await asyncio.wait_for(in_flight, 5)).startswith(b"HTTP/1.1 200 ")
+
+
+async def test_serve_stops_when_the_shutdown_awaitable_completes():
+    pytest.importorskip("uvicorn")
+    port, events = free_port(), []
+    stop = asyncio.Event()
+    serving = asyncio.create_task(served_app(port, 5, events, 0).serve(shutdown=stop.wait()))
+    while True:
+        try:
+            _, writer = await asyncio.open_connection("127.0.0.1", port)
+            writer.close()
+            break
+        except OSError:
+            await asyncio.sleep(0.01)
+    stop.set()
+    await asyncio.wait_for(serving, 5)
+    assert events == ["lifespan shutdown"]
+
+
+def test_bind_reports_the_port_and_fails_fast_when_taken():
+    server = Application.builder().port(0).build_server()
+    assert server.config.validate() == []
+    sock = server.bind()
+    try:
+        host, port = sock.getsockname()[:2]
+        assert (host, port != 0) == ("127.0.0.1", True)
+        sock.listen()
+        taken = Application.builder().port(port).build_server()
+        with pytest.raises(OSError):
+            taken.bind()
+    finally:
+        sock.close()
+
+
+async def test_port_zero_serves_on_the_reported_address():
+    pytest.importorskip("uvicorn")
+    events = []
+    handle = await served_app(0, 5, events, 0).start()
+    try:
+        host, port = handle.local_addr
+        assert host == "127.0.0.1" and port != 0
+        assert (await request_slow(port)).startswith(b"HTTP/1.1 200 ")
+    finally:
+        await handle.shutdown()
+    assert events == ["handled", "lifespan shutdown"]
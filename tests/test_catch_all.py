@@ -0,0 +1,52 @@
+"""Catch-all (*rest) route segments: nested prefixes, empty remainders, lowest priority."""
+import pytest
+from starlette.responses import JSONResponse
+
+from urich import Application
+from urich.testing import TestClient
+
+
+def answering(route):
+    async def endpoint(request):
+        return JSONResponse({"route": route, "params": dict(request.path_params)})
+
+    return endpoint
+
+
+def make_app():
+    app = Application()
+    for path in ("/api/v1/files/*rest", "/api/v1/files/{name}", "/api/v1/files/index"):
+        app.add_route(path, answering(path))
+    return app
+
+
+@pytest.mark.parametrize(
+    "url, route, params",
+    [
+        ("/api/v1/files/index", "/api/v1/files/index", {}),
+        ("/api/v1/files/a.txt", "/api/v1/files/{name}", {"name": "a.txt"}),
+        ("/api/v1/files/a/b/c.txt", "/api/v1/files/*rest", {"rest": "a/b/c.txt"}),
+        ("/api/v1/files/", "/api/v1/files/*rest", {"rest": ""}),
+    ],
+)
+async def test_catch_all_matches_after_exact_and_param_routes(url, route, params):
+    response = await TestClient(make_app()).get(url)
+    assert response.status_code == 200
+    assert response.json() == {"route": route, "params": params}
+
+
+def test_second_catch_all_on_the_same_prefix_is_rejected():
+    app = make_app()
+    with pytest.raises(ValueError, match=r"Catch-all route /api/v1/files/\{rest:path\} already registered"):
+        app.add_route("/api/v1/files/*other", answering("other"))
+    with pytest.raises(ValueError, match="already registered"):
+        app.add_route("/api/v1/files/{other:path}", answering("other"))
+    app.add_route("/api/v1/files/*upload", answering("upload"), methods=["POST"])
+    app.add_route("/api/v2/files/*rest", answering("v2"))
+
+
+async def test_catch_all_is_documented_as_a_path_parameter():
+    app = make_app().openapi()
+    async with TestClient(app) as client:
+        paths = (await client.get("/openapi.json")).json()["paths"]
+    assert "/api/v1/files/{rest}" in paths
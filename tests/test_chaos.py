@@ -0,0 +1,119 @@
+"""ChaosModule: error and abort probabilities, latency, bypass and the admin endpoint."""
+import os
+import time
+
+import pytest
+from starlette.responses import JSONResponse
+
+from urich import Application
+from urich.http import ChaosModule
+from urich.testing import TestClient
+
+TOKEN = "smoke-tests"
+
+
+def make_app(chaos):
+    calls = []
+
+    async def orders(request):
+        calls.append(request.url.path)
+        return JSONResponse({"ok": True})
+
+    app = Application()
+    app.add_route("/orders", orders)
+    app.add_route("/ordersv2", orders)
+    saved = os.environ.get("URICH_CHAOS")
+    os.environ["URICH_CHAOS"] = "1"
+    try:
+        app.register(chaos)
+    finally:
+        if saved is None:
+            del os.environ["URICH_CHAOS"]
+        else:
+            os.environ["URICH_CHAOS"] = saved
+    return app, calls
+
+
+async def statuses(client, n, path="/orders", headers=None):
+    return [(await client.get(path, headers=headers)).status_code for _ in range(n)]
+
+
+async def test_error_rate_zero_and_one():
+    app, _ = make_app(ChaosModule(seed=1).rule("/orders", error_rate=1.0, error_status=503))
+    client = TestClient(app)
+    response = await client.get("/orders")
+    assert response.status_code == 503
+    assert response.json()["error"]["code"] == "CHAOS_INJECTED"
+    assert response.headers["x-chaos-injected"] == "error"
+
+    app, calls = make_app(ChaosModule(seed=1).rule("/orders", error_rate=0.0))
+    assert set(await statuses(TestClient(app), 50)) == {200}
+    assert len(calls) == 50
+
+
+async def test_half_error_rate_within_tolerance():
+    app, _ = make_app(ChaosModule(seed=7).rule("/orders", error_rate=0.5))
+    errors = (await statuses(TestClient(app), 400)).count(503)
+    assert 160 <= errors <= 240
+
+
+async def test_abort_sends_no_response_and_skips_the_handler():
+    app, calls = make_app(ChaosModule(seed=1).rule("/orders", abort_rate=1.0))
+    with pytest.raises(RuntimeError, match="application sent no response"):
+        await TestClient(app).get("/orders")
+    assert calls == []
+
+    app, calls = make_app(ChaosModule(seed=1).rule("/orders", abort_rate=0.0))
+    assert (await TestClient(app).get("/orders")).status_code == 200
+
+
+async def test_latency_is_added():
+    app, _ = make_app(ChaosModule(seed=1).rule("/orders", latency_ms=80))
+    client = TestClient(app)
+    started = time.monotonic()
+    response = await client.get("/orders")
+    assert time.monotonic() - started >= 0.08
+    assert response.status_code == 200
+    assert response.headers["x-chaos-injected"] == "latency"
+
+
+async def test_prefix_matches_whole_segments():
+    app, calls = make_app(ChaosModule(seed=1).rule("/orders", error_rate=1.0))
+    client = TestClient(app)
+    assert (await client.get("/orders")).status_code == 503
+    assert (await client.get("/ordersv2")).status_code == 200
+    assert calls == ["/ordersv2"]
+
+
+async def test_bypass_token_skips_faults():
+    app, calls = make_app(ChaosModule(bypass_token=TOKEN, seed=1).rule("/orders", error_rate=1.0))
+    client = TestClient(app)
+    response = await client.get("/orders", headers={"X-Chaos-Bypass": TOKEN})
+    assert response.status_code == 200
+    assert "x-chaos-injected" not in response.headers
+    assert (await client.get("/orders", headers={"X-Chaos-Bypass": "wrong"})).status_code == 503
+    assert calls == ["/orders"]
+
+
+def test_admin_requires_a_bypass_token():
+    with pytest.raises(ValueError, match="requires a bypass_token"):
+        ChaosModule().admin()
+
+
+async def test_admin_endpoint_is_authenticated():
+    chaos = ChaosModule(bypass_token=TOKEN, seed=1).rule("/orders", error_rate=1.0).admin("/_chaos")
+    app, _ = make_app(chaos)
+    client = TestClient(app)
+    assert (await client.get("/_chaos")).status_code == 403
+    assert (await client.put("/_chaos", json=[], headers={"X-Chaos-Bypass": "wrong"})).status_code == 403
+    assert [r.prefix for r in chaos.rules] == ["/orders"]
+
+    listed = await client.get("/_chaos", headers={"X-Chaos-Bypass": TOKEN})
+    assert listed.status_code == 200
+    assert listed.json()[0]["prefix"] == "/orders"
+
+    replaced = await client.put("/_chaos", json=[{"prefix": "/payments", "error_rate": 1.0}],
+                                headers={"X-Chaos-Bypass": TOKEN})
+    assert replaced.status_code == 200
+    assert [r.prefix for r in chaos.rules] == ["/payments"]
+    assert (await client.get("/orders")).status_code == 200
@@ -0,0 +1,103 @@
+"""Optimistic concurrency: versioned saves and 409 on interleaved updates."""
+import asyncio
+from dataclasses import dataclass
+
+import pytest
+
+from urich import Application
+from urich.ddd import Command, DomainModule
+from urich.domain import AggregateRoot, ConcurrencyError, InMemoryRepository, Repository
+from urich.events import UnitOfWork
+from urich.testing import TestClient
+
+
+class Order(AggregateRoot):
+    def __init__(self, id: str, status: str = "new", version: int = 0) -> None:
+        super().__init__(id)
+        self.status = status
+        self.version = version
+
+
+class IOrderRepository(Repository[Order]):
+    pass
+
+
+class OrderRepository(InMemoryRepository[Order], IOrderRepository):
+    pass
+
+
+@dataclass
+class SetStatus(Command):
+    order_id: str
+    status: str
+
+
+async def test_in_memory_repository_rejects_stale_version():
+    repo = OrderRepository()
+    await repo.add(Order("o-1"))
+    first, second = await repo.get("o-1"), await repo.get("o-1")
+    first.status = "paid"
+    await repo.save_versioned(first, first.version)
+    assert first.version == 1
+    second.status = "cancelled"
+    with pytest.raises(ConcurrencyError, match="expected version 0, found 1"):
+        await repo.save_versioned(second, second.version)
+    assert second.version == 0
+    stored = await repo.get("o-1")
+    assert (stored.status, stored.version) == ("paid", 1)
+
+
+async def test_default_save_versioned_sets_version_and_saves():
+    class DictRepository(Repository[Order]):
+        def __init__(self):
+            self.saved = []
+
+        async def get(self, id):
+            return None
+
+        async def add(self, aggregate):
+            pass
+
+        async def save(self, aggregate):
+            self.saved.append(aggregate.version)
+
+    repo = DictRepository()
+    order = Order("o-1", version=4)
+    await repo.save_versioned(order, 4)
+    assert repo.saved == [5]
+    assert order.version == 5
+
+
+async def test_second_of_two_interleaved_updates_gets_409():
+    loaded = 0
+    both_loaded = asyncio.Event()
+
+    async def set_status(cmd: SetStatus, orders: IOrderRepository, uow: UnitOfWork):
+        nonlocal loaded
+        order = await orders.get(cmd.order_id)
+        loaded += 1
+        if loaded == 2:
+            both_loaded.set()
+        await both_loaded.wait()
+        order.status = cmd.status
+        await uow.save(orders, order, expected_version=order.version)
+        return {"status": order.status, "version": order.version}
+
+    app = Application()
+    app.register(DomainModule("orders").repository(IOrderRepository, OrderRepository).command(SetStatus, set_status))
+    async with TestClient(app) as client:
+        orders = app.container.resolve(IOrderRepository)
+        await orders.add(Order("o-1"))
+        responses = await asyncio.gather(
+            client.post("/orders/commands/set_status", json={"order_id": "o-1", "status": "paid"}),
+            client.post("/orders/commands/set_status", json={"order_id": "o-1", "status": "cancelled"}),
+        )
+        stored = await orders.get("o-1")
+
+    saved, rejected = sorted(responses, key=lambda r: r.status_code)
+    assert (saved.status_code, rejected.status_code) == (200, 409)
+    assert saved.json()["result"] == {"status": stored.status, "version": 1}
+    error = rejected.json()["error"]
+    assert error["code"] == "CONFLICT"
+    assert error["details"] == {"id": "o-1", "expected_version": 0, "actual_version": 1}
+    assert stored.version == 1
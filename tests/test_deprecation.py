@@ -0,0 +1,84 @@
+"""Runtime deprecation warnings: once per key, deny mode, instrumented legacy paths."""
+import os
+import warnings
+
+import pytest
+
+from urich.core.deprecation import (
+    ENV_MODE,
+    DeprecationInfo,
+    UrichDeprecationError,
+    deprecations_seen,
+    reset_deprecations,
+    warn,
+)
+from urich.discovery.protocol import StaticDiscovery
+from urich.domain.events import in_process_dispatcher
+from urich.rpc import RpcClient, RpcError, RpcModule
+
+
+class StringErrorTransport:
+    async def call(self, url, method, payload):
+        return b'{"error": "boom"}'
+
+
+class deny_mode:
+    def __enter__(self):
+        self.previous = os.environ.get(ENV_MODE)
+        os.environ[ENV_MODE] = "deny"
+
+    def __exit__(self, *exc):
+        if self.previous is None:
+            del os.environ[ENV_MODE]
+        else:
+            os.environ[ENV_MODE] = self.previous
+
+
+def test_each_key_warns_once_per_process():
+    reset_deprecations()
+    with warnings.catch_warnings(record=True) as caught:
+        warnings.simplefilter("always")
+        for _ in range(3):
+            warn("test.old_thing", "old_thing() is deprecated.", replacement="new_thing()")
+        warn("test.other", "other() is deprecated.")
+    assert [str(w.message) for w in caught] == [
+        "[test.old_thing] old_thing() is deprecated. Use instead: new_thing()",
+        "[test.other] other() is deprecated.",
+    ]
+    assert all(w.category is DeprecationWarning for w in caught)
+    assert deprecations_seen() == [
+        DeprecationInfo("test.old_thing", "old_thing() is deprecated.", "new_thing()"),
+        DeprecationInfo("test.other", "other() is deprecated."),
+    ]
+
+
+def test_deny_mode_raises_on_every_use():
+    reset_deprecations()
+    with deny_mode():
+        for _ in range(2):
+            with pytest.raises(UrichDeprecationError, match=r"\[test.denied\] denied\(\) is deprecated"):
+                warn("test.denied", "denied() is deprecated.")
+    assert [d.feature_key for d in deprecations_seen()] == ["test.denied"]
+
+
+async def test_legacy_paths_are_instrumented():
+    reset_deprecations()
+    with warnings.catch_warnings():
+        warnings.simplefilter("ignore")
+        in_process_dispatcher()
+        RpcModule().server("/rpc", transport=object())
+        client = RpcClient(StaticDiscovery({"billing": "http://billing"}), StringErrorTransport())
+        with pytest.raises(RpcError, match="boom"):
+            await client.call("billing", "charge", {}, raise_on_error=True)
+    assert [d.feature_key for d in deprecations_seen()] == [
+        "events.in_process_dispatcher",
+        "rpc.server_transport",
+        "rpc.string_error_envelope",
+    ]
+
+
+def test_deny_mode_fails_a_legacy_path():
+    reset_deprecations()
+    with deny_mode():
+        with pytest.raises(UrichDeprecationError, match="events.in_process_dispatcher"):
+            in_process_dispatcher()
@@ -0,0 +1,74 @@
+"""DomainEvent wire names and envelopes."""
+import asyncio
+from dataclasses import dataclass
+
+import pytest
+
+from urich.domain import DomainEvent, EventEnvelope, InProcessEventDispatcher
+
+
+@dataclass
+class InvoiceIssued(DomainEvent, name="billing.invoice_issued"):
+    invoice_id: str
+
+
+def test_duplicate_wire_name_is_rejected():
+    with pytest.raises(TypeError, match="billing.invoice_issued"):
+
+        @dataclass
+        class OtherInvoiceIssued(DomainEvent, name="billing.invoice_issued"):
+            number: int
+
+
+def test_duplicate_default_name_is_rejected():
+    @dataclass
+    class CreditNoted(DomainEvent):
+        amount: int
+
+    with pytest.raises(TypeError, match="already used by"):
+        type("CreditNoted", (DomainEvent,), {"__module__": "elsewhere"})
+
+
+def test_unwrap_returns_the_registered_type():
+    envelope = EventEnvelope.wrap(InvoiceIssued(invoice_id="i-1"))
+    assert envelope.event_type == "billing.invoice_issued"
+    assert EventEnvelope.from_json(envelope.to_json()).unwrap() == InvoiceIssued(invoice_id="i-1")
+
+
+@dataclass
+class StockCounted(DomainEvent):
+    sku: str
+
+
+async def test_concurrent_delivery_propagates_handler_cancellation():
+    seen = []
+
+    async def cancelled(event):
+        raise asyncio.CancelledError()
+
+    async def counted(event):
+        seen.append(event.sku)
+
+    dispatcher = InProcessEventDispatcher(delivery="concurrent")
+    dispatcher.subscribe(StockCounted, cancelled)
+    dispatcher.subscribe(StockCounted, counted)
+    with pytest.raises(asyncio.CancelledError):
+        await dispatcher.publish(StockCounted(sku="s-1"))
+    assert seen == ["s-1"]
+
+
+async def test_concurrent_delivery_is_cancelled_with_the_publisher():
+    started = asyncio.Event()
+
+    async def slow(event):
+        started.set()
+        await asyncio.sleep(10)
+
+    dispatcher = InProcessEventDispatcher(delivery="concurrent")
+    dispatcher.subscribe(StockCounted, slow)
+    task = asyncio.create_task(dispatcher.publish(StockCounted(sku="s-2")))
+    await started.wait()
+    task.cancel()
+    with pytest.raises(asyncio.CancelledError):
+        await task
+    assert task.cancelled()
@@ -0,0 +1,108 @@
+"""Error reporting: one event per failing request, request id, redaction and rate limiting."""
+from starlette.responses import JSONResponse
+
+from urich import Application
+from urich.core.error_reporting import ErrorReportingDispatcher
+from urich.http.request_id import RequestIdModule
+from urich.testing import TestClient
+
+
+class RecordingReporter:
+    def __init__(self):
+        self.events = []
+
+    def report(self, event):
+        self.events.append(event)
+
+
+async def explode(request):
+    raise ValueError(f"order {request.path_params['order_id']} is corrupt")
+
+
+async def unavailable(request):
+    return JSONResponse({"error": "down"}, status_code=503)
+
+
+async def missing(request):
+    return JSONResponse({"error": "nope"}, status_code=404)
+
+
+def make_app(**options):
+    reporter = RecordingReporter()
+    app = Application().error_reporter(reporter, **options)
+    app.add_route("/orders/{order_id}", explode, methods=["GET"])
+    app.add_route("/unavailable", unavailable, methods=["GET"])
+    app.add_route("/missing", missing, methods=["GET"])
+    return app, reporter, app.container.resolve(ErrorReportingDispatcher)
+
+
+async def test_unhandled_exception_gives_exactly_one_event():
+    app, reporter, dispatcher = make_app()
+    client = TestClient(app, raise_server_exceptions=False)
+    response = await client.get(
+        "/orders/42?page=1",
+        headers={"Authorization": "Bearer secret", "Cookie": "session=abc", "X-Request-Id": "req-1"},
+    )
+    assert response.status_code == 500
+    await dispatcher.flush()
+    (event,) = reporter.events
+    assert (event.kind, event.route, event.status) == ("exception", "/orders/{order_id}", 500)
+    assert event.message == "order 42 is corrupt"
+    assert event.exception_type == "ValueError"
+    assert "ValueError: order 42 is corrupt" in event.traceback
+    assert event.request_id == "req-1"
+    assert event.request["method"] == "GET"
+    assert event.request["path"] == "/orders/42"
+    assert event.request["query_string"] == "page=1"
+    assert event.request["headers"]["authorization"] == "[redacted]"
+    assert event.request["headers"]["cookie"] == "[redacted]"
+
+
+async def test_status_threshold():
+    app, reporter, dispatcher = make_app(status_threshold=500)
+    client = TestClient(app)
+    assert (await client.get("/missing")).status_code == 404
+    assert (await client.get("/unavailable")).status_code == 503
+    await dispatcher.flush()
+    (event,) = reporter.events
+    assert (event.kind, event.route, event.status, event.message) == ("http_error", "/unavailable", 503, "HTTP 503")
+
+    app, reporter, dispatcher = make_app(status_threshold=400)
+    await TestClient(app).get("/missing")
+    await dispatcher.flush()
+    assert [e.status for e in reporter.events] == [404]
+
+
+async def test_identical_events_are_rate_limited():
+    app, reporter, dispatcher = make_app(rate_limit=3)
+    client = TestClient(app)
+    for _ in range(5):
+        await client.get("/unavailable")
+    await client.get("/missing")
+    await dispatcher.flush()
+    assert len(reporter.events) == 3
+    assert dispatcher.rate_limited == 2
+
+
+async def test_generated_request_id_is_reported():
+    app, reporter, dispatcher = make_app()
+    app.register(RequestIdModule())
+    response = await TestClient(app).get("/unavailable")
+    await dispatcher.flush()
+    (event,) = reporter.events
+    assert event.request_id is not None
+    assert event.request_id == response.headers["x-request-id"]
+    assert "x-request-id" not in event.request["headers"]
+
+
+async def test_failing_reporter_does_not_break_requests():
+    class Broken:
+        def report(self, event):
+            raise RuntimeError("tracker down")
+
+    app = Application().error_reporter(Broken())
+    app.add_route("/unavailable", unavailable, methods=["GET"])
+    client = TestClient(app)
+    assert (await client.get("/unavailable")).status_code == 503
+    await app.container.resolve(ErrorReportingDispatcher).flush()
+    assert (await client.get("/unavailable")).status_code == 503
@@ -0,0 +1,83 @@
+"""Event handler retries and dead letters."""
+from dataclasses import dataclass
+
+from urich import Application
+from urich.ddd import DomainModule
+from urich.domain import DomainEvent, EventBus
+from urich.events.retry import DeadLetterSink, InMemoryDeadLetterSink, RetryPolicy, with_retry
+from urich.testing import TestClient
+
+
+@dataclass
+class InvoiceIssued(DomainEvent):
+    invoice_id: str
+    lines: list
+
+
+class Flaky:
+    """Fails the first `failures` calls, then records the event."""
+
+    def __init__(self, failures):
+        self.failures = failures
+        self.calls = 0
+        self.handled = []
+
+    async def __call__(self, event):
+        self.calls += 1
+        if self.calls <= self.failures:
+            raise RuntimeError(f"attempt {self.calls} failed")
+        self.handled.append(event)
+
+
+class Sleeps:
+    def __init__(self):
+        self.delays = []
+
+    async def __call__(self, seconds):
+        self.delays.append(seconds)
+
+
+async def test_handler_failing_twice_succeeds_on_third_attempt():
+    handler = Flaky(failures=2)
+    sleep = Sleeps()
+    sink = InMemoryDeadLetterSink()
+    event = InvoiceIssued("inv-1", [{"sku": "A", "qty": 2}])
+    await with_retry(handler, RetryPolicy(max_attempts=3, backoff=0.5), sink, sleep=sleep)(event)
+    assert handler.calls == 3
+    assert handler.handled == [event]
+    assert sleep.delays == [0.5, 1.0]
+    assert sink.letters == []
+
+
+async def test_exhausted_retries_land_in_dead_letter_sink_with_original_event():
+    handler = Flaky(failures=5)
+    sink = InMemoryDeadLetterSink()
+    event = InvoiceIssued("inv-2", [{"sku": "B", "qty": 1}])
+    await with_retry(handler, RetryPolicy(max_attempts=3, backoff=0), sink, sleep=Sleeps())(event)
+    assert handler.calls == 3
+    (letter,) = sink.letters
+    assert letter.event is event
+    assert letter.event == InvoiceIssued("inv-2", [{"sku": "B", "qty": 1}])
+    assert letter.event_type == "invoice_issued"
+    assert letter.attempts == 3
+    assert str(letter.error) == "attempt 3 failed"
+    assert letter.handler == "Flaky"
+
+
+async def test_on_event_retry_uses_container_sink_and_other_subscribers_still_run():
+    failing = Flaky(failures=10)
+    healthy = Flaky(failures=0)
+    sink = InMemoryDeadLetterSink()
+    app = Application()
+    app.container.register_instance(DeadLetterSink, sink)
+    app.register(
+        DomainModule("billing")
+        .on_event(InvoiceIssued, failing, retry=RetryPolicy(max_attempts=2, backoff=0))
+        .on_event(InvoiceIssued, healthy)
+    )
+    event = InvoiceIssued("inv-3", [])
+    async with TestClient(app):
+        await app.container.resolve(EventBus).publish(event)
+    assert failing.calls == 2
+    assert healthy.handled == [event]
+    assert [(letter.event, letter.attempts) for letter in sink.letters] == [(event, 2)]
@@ -0,0 +1,104 @@
+"""Route examples: shown in the OpenAPI spec and run as contract tests by run_example_tests()."""
+from dataclasses import dataclass
+
+from urich import Application
+from urich.core import Example
+from urich.ddd import Command, DomainModule, Query
+from urich.http.auth import AuthModule
+from urich.testing import TestClient
+
+
+@dataclass
+class OpenAccount(Command):
+    owner: str
+    limit: int
+
+
+@dataclass
+class Account:
+    account_id: str
+    owner: str
+
+
+@dataclass
+class FindAccount(Query):
+    owner: str
+
+
+async def open_account(cmd: OpenAccount) -> Account:
+    if cmd.owner == "broken":
+        raise RuntimeError("storage down")
+    return Account(f"acc-{cmd.owner}", cmd.owner)
+
+
+async def find_account(query: FindAccount) -> dict:
+    return {"account_id": 7, "owner": query.owner}
+
+
+def make_app(*examples):
+    app = Application()
+    app.register(
+        DomainModule("accounts")
+        .command(OpenAccount, open_account, examples=examples)
+        .query(FindAccount, find_account, response=Account, examples=[Example("by owner", body={"owner": "ann"})])
+    )
+    return app
+
+
+VALID = Example("valid", body={"owner": "ann", "limit": 100}, summary="A new account")
+INVALID = Example("limit as text", body={"owner": "ann", "limit": "high"}).expect_status(422)
+
+
+async def test_passing_expected_422_and_broken_examples():
+    app = make_app(VALID, INVALID, Example("broken", body={"owner": "broken", "limit": 1}))
+    report = await app.run_example_tests()
+    results = {r.example: r for r in report.results}
+    assert set(results) == {"valid", "limit as text", "broken", "by owner"}
+    assert results["valid"].passed and results["valid"].status == 200
+    assert results["limit as text"].passed and results["limit as text"].status == 422
+    broken = results["broken"]
+    assert not broken.passed
+    assert (broken.method, broken.path, broken.status) == ("POST", "/accounts/commands/open_account", 500)
+    assert broken.problems[0].startswith("status 500, expected 2xx: ")
+    assert not report.all_passed()
+    assert [r.example for r in report.failures] == ["broken", "by owner"]
+    assert str(report).endswith("2 passed, 2 failed")
+
+
+async def test_response_is_checked_against_the_documented_schema():
+    report = await make_app(VALID).run_example_tests()
+    (failure,) = report.failures
+    assert failure.example == "by owner"
+    assert failure.status == 200
+    assert failure.problems == ("/account_id: expected string, got integer",)
+    assert (await make_app(VALID).run_example_tests(validate_responses=False)).all_passed()
+
+
+async def test_expected_422_fails_when_the_request_succeeds():
+    report = await make_app(Example("not actually invalid", body={"owner": "ann", "limit": 1}).expect_status(422))\
+        .run_example_tests(validate_responses=False)
+    (failure,) = report.failures
+    assert failure.problems[0].startswith("status 200, expected 422")
+
+
+async def test_bypass_middleware_skips_auth():
+    app = make_app(VALID)
+    app.register(AuthModule("s3cret"))
+    blocked = await app.run_example_tests(validate_responses=False)
+    assert {r.status for r in blocked.results} == {401}
+    assert not blocked.all_passed()
+    assert (await app.run_example_tests(bypass_middleware=True, validate_responses=False)).all_passed()
+
+
+async def test_examples_are_in_the_openapi_spec():
+    app = make_app(VALID, INVALID).openapi()
+    async with TestClient(app) as client:
+        paths = (await client.get("/openapi.json")).json()["paths"]
+    content = paths["/accounts/commands/open_account"]["post"]["requestBody"]["content"]["application/json"]
+    assert content["examples"] == {
+        "valid": {"summary": "A new account", "value": {"owner": "ann", "limit": 100}},
+        "limit as text": {"value": {"owner": "ann", "limit": "high"}},
+    }
+    assert "$ref" in content["schema"]
+    examples = paths["/accounts/queries/find_account"]["post"]["requestBody"]["content"]["application/json"]["examples"]
+    assert examples == {"by owner": {"value": {"owner": "ann"}}}
@@ -0,0 +1,96 @@
+"""Handler dependencies: resolved from the container by annotation, planned at registration."""
+import typing
+from dataclasses import dataclass
+
+import pytest
+
+from urich import Application
+from urich.core.container import Container
+from urich.ddd import Command, DomainModule, Query
+from urich.testing import TestClient
+
+
+class Clock:
+    def now(self):
+        return "2026-01-01"
+
+
+class Audit:
+    def __init__(self):
+        self.entries = []
+
+
+@dataclass
+class Stamp(Command):
+    label: str
+
+
+@dataclass
+class WhatTime(Query):
+    pass
+
+
+def test_container_has():
+    container = Container()
+    assert not container.has(Clock)
+    container.register_instance(Clock, Clock())
+    assert container.has(Clock)
+
+
+async def test_function_class_and_sync_handlers_get_dependencies():
+    audit = Audit()
+
+    async def stamp(cmd: Stamp, clock: Clock, audit: Audit = None):
+        audit.entries.append((cmd.label, clock.now()))
+        return cmd.label
+
+    class WhatTimeHandler:
+        def __init__(self, clock: Clock):
+            self.clock = clock
+
+        def __call__(self, query: WhatTime, audit: Audit, missing: int = 5):
+            return {"now": self.clock.now(), "entries": len(audit.entries), "missing": missing}
+
+    app = Application()
+    app.container.register_instance(Clock, Clock())
+    app.container.register_instance(Audit, audit)
+    app.register(DomainModule("clock").command(Stamp, stamp).query(WhatTime, WhatTimeHandler))
+    async with TestClient(app) as client:
+        assert (await client.post("/clock/commands/stamp", json={"label": "a"})).json() == {"ok": True, "result": "a"}
+        response = await client.get("/clock/queries/what_time")
+        assert response.json() == {"now": "2026-01-01", "entries": 1, "missing": 5}
+        assert await app.dispatch(Stamp(label="b")) == "b"
+    assert audit.entries == [("a", "2026-01-01"), ("b", "2026-01-01")]
+
+
+async def test_signature_is_inspected_once_at_registration():
+    calls = []
+    original = typing.get_type_hints
+
+    def counting(obj, *args, **kwargs):
+        calls.append(obj)
+        return original(obj, *args, **kwargs)
+
+    async def stamp(cmd: Stamp, clock: Clock):
+        return clock.now()
+
+    app = Application()
+    app.container.register_instance(Clock, Clock())
+    typing.get_type_hints = counting
+    try:
+        app.register(DomainModule("clock").command(Stamp, stamp))
+        registered = calls.count(stamp)
+        async with TestClient(app) as client:
+            for _ in range(3):
+                assert (await client.post("/clock/commands/stamp", json={"label": "a"})).status_code == 200
+    finally:
+        typing.get_type_hints = original
+    assert calls.count(stamp) == registered
+
+
+def test_unannotated_dependency_fails_at_registration():
+    async def stamp(cmd: Stamp, clock):
+        return None
+
+    with pytest.raises(TypeError, match="parameter 'clock' needs a type annotation"):
+        Application().register(DomainModule("clock").command(Stamp, stamp))
@@ -0,0 +1,91 @@
+"""Handler failures are isolated: generic 500s, logged with the route, the app keeps serving."""
+import logging
+from dataclasses import dataclass
+
+import pytest
+from starlette.responses import JSONResponse
+
+from urich import Application
+from urich.domain import DomainEvent, InProcessEventDispatcher
+from urich.rpc import RpcModule, RpcServer
+from urich.testing import TestClient
+
+
+class RecordingHandler(logging.Handler):
+    def __init__(self, name):
+        super().__init__()
+        self.records = []
+        self.logger = logging.getLogger(name)
+
+    def __enter__(self):
+        self.logger.addHandler(self)
+        return self.records
+
+    def __exit__(self, *exc):
+        self.logger.removeHandler(self)
+
+    def emit(self, record):
+        self.records.append(record)
+
+
+async def crash(request):
+    raise KeyError(f"secret-{request.path_params['order_id']}")
+
+
+async def healthy(request):
+    return JSONResponse({"ok": True})
+
+
+async def test_failing_handler_gets_a_generic_500_and_the_app_keeps_serving():
+    app = Application()
+    app.add_route("/orders/{order_id}", crash)
+    app.add_route("/health", healthy)
+    client = TestClient(app, raise_server_exceptions=False)
+    with RecordingHandler("urich.errors") as records:
+        for _ in range(3):
+            response = await client.get("/orders/42")
+            assert response.status_code == 500
+            assert response.json() == {"error": {"code": "INTERNAL", "message": "Internal server error"}}
+            assert b"secret" not in response.content
+            assert (await client.get("/health")).json() == {"ok": True}
+    assert [r.getMessage() for r in records] == ["Unhandled KeyError in GET /orders/{order_id}"] * 3
+
+
+class Billing(RpcServer):
+    async def charge(self, amount: int):
+        raise RuntimeError("card vault password is hunter2")
+
+
+async def test_rpc_method_failure_is_internal_without_details():
+    app = Application()
+    app.register(RpcModule().server("/rpc", handler=Billing()))
+    app.register(RpcModule().server("/jsonrpc", handler=Billing(), jsonrpc=True))
+    client = TestClient(app)
+    with RecordingHandler("urich.rpc") as records:
+        response = await client.post("/rpc/charge", json={"params": {"amount": 1}})
+        jsonrpc = await client.post(
+            "/jsonrpc", json={"jsonrpc": "2.0", "id": 1, "method": "charge", "params": {"amount": 1}}
+        )
+    assert response.status_code == 500
+    assert response.json() == {"error": {"code": "INTERNAL", "message": "Internal error"}}
+    assert jsonrpc.json()["error"]["code"] == -32603
+    assert b"hunter2" not in response.content + jsonrpc.content
+    assert "'charge'" in records[0].getMessage()
+    assert "hunter2" in str(records[0].exc_info[1])
+
+
+@dataclass
+class ParcelShipped(DomainEvent, name="handler_failures.parcel_shipped"):
+    parcel_id: str
+
+
+async def test_event_handler_failure_names_the_handler():
+    async def notify_courier(event):
+        raise ConnectionError("courier api down")
+
+    bus = InProcessEventDispatcher()
+    bus.subscribe(ParcelShipped, notify_courier)
+    with pytest.raises(ConnectionError) as caught:
+        await bus.publish(ParcelShipped(parcel_id="p-1"))
+    (note,) = caught.value.__notes__
+    assert note.startswith("in event handler ") and note.endswith("notify_courier for ParcelShipped")
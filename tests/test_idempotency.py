@@ -0,0 +1,90 @@
+"""Idempotent routes: replay, key reuse with a different body, and TTL expiry."""
+import json
+
+from starlette.requests import Request
+from starlette.responses import JSONResponse
+
+from urich import Application
+from urich.core.idempotency import IdempotencyRecord, InMemoryIdempotencyStore
+from urich.testing import TestClient
+
+
+class Clock:
+    def __init__(self):
+        self.now = 1000.0
+
+    def __call__(self):
+        return self.now
+
+
+def make_app(ttl=60.0):
+    clock = Clock()
+    calls = []
+
+    async def create_order(request: Request):
+        body = await request.json()
+        calls.append(body)
+        return JSONResponse({"order_id": f"o-{len(calls)}", "sku": body["sku"]}, status_code=201)
+
+    app = Application().idempotency(InMemoryIdempotencyStore(clock=clock), ttl=ttl)
+    app.add_route("/orders", create_order, methods=["POST"], idempotent=True)
+    return app, clock, calls
+
+
+async def test_replay_returns_byte_identical_body():
+    app, _, calls = make_app()
+    body = json.dumps({"sku": "A-1"})
+    async with TestClient(app) as client:
+        first = await client.post("/orders", content=body, headers={"Idempotency-Key": "k-1"})
+        second = await client.post("/orders", content=body, headers={"Idempotency-Key": "k-1"})
+    assert first.status_code == second.status_code == 201
+    assert second.content == first.content
+    assert "idempotency-replayed" not in first.headers
+    assert second.headers["idempotency-replayed"] == "true"
+    assert len(calls) == 1
+
+
+async def test_same_key_with_different_body_is_rejected():
+    app, _, calls = make_app()
+    async with TestClient(app) as client:
+        await client.post("/orders", json={"sku": "A-1"}, headers={"Idempotency-Key": "k-1"})
+        response = await client.post("/orders", json={"sku": "B-2"}, headers={"Idempotency-Key": "k-1"})
+    assert response.status_code == 422
+    assert response.json()["error"]["code"] == "IDEMPOTENCY_KEY_REUSED"
+    assert len(calls) == 1
+
+
+async def test_expired_key_runs_the_handler_again():
+    app, clock, calls = make_app(ttl=60.0)
+    async with TestClient(app) as client:
+        first = await client.post("/orders", json={"sku": "A-1"}, headers={"Idempotency-Key": "k-1"})
+        clock.now += 59
+        replayed = await client.post("/orders", json={"sku": "A-1"}, headers={"Idempotency-Key": "k-1"})
+        clock.now += 1
+        again = await client.post("/orders", json={"sku": "A-1"}, headers={"Idempotency-Key": "k-1"})
+    assert replayed.content == first.content
+    assert "idempotency-replayed" not in again.headers
+    assert again.json()["order_id"] == "o-2"
+    assert len(calls) == 2
+
+
+async def test_expired_keys_are_freed_on_write():
+    clock = Clock()
+    store = InMemoryIdempotencyStore(clock=clock)
+    for i in range(3):
+        await store.put(f"k-{i}", IdempotencyRecord("f", 201, [], b"{}"), ttl=60.0)
+    clock.now += 60
+    await store.claim("k-new", IdempotencyRecord("f"), ttl=60.0)
+    assert len(store) == 1
+    assert await store.get("k-0") is None
+
+
+async def test_oldest_entries_are_evicted_beyond_max_entries():
+    store = InMemoryIdempotencyStore(max_entries=2, clock=Clock())
+    for key in ("a", "b", "c"):
+        assert await store.claim(key, IdempotencyRecord("f"), ttl=60.0)
+    await store.put("b", IdempotencyRecord("f", 201, [], b"{}"), ttl=60.0)
+    assert len(store) == 2
+    assert await store.get("a") is None
+    assert (await store.get("b")).completed
+    assert await store.get("c") is not None
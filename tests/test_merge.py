@@ -0,0 +1,122 @@
+"""Application.merge: combining two applications in one process."""
+from dataclasses import dataclass
+
+import pytest
+from starlette.responses import JSONResponse
+
+from urich import Application, MergeError
+from urich.domain import DomainEvent, EventBus
+from urich.events import EventBusModule, TopicHub
+from urich.testing import TestClient
+
+
+@dataclass
+class InvoicePaid(DomainEvent, name="merge_test.invoice_paid"):
+    invoice_id: str
+
+
+async def list_orders(request):
+    return JSONResponse({"orders": [], "tagged": request.headers.get("x-billing-tagged")})
+
+
+async def list_invoices(request):
+    return JSONResponse({"invoices": [], "tagged": request.headers.get("x-billing-tagged")})
+
+
+async def other_orders(request):
+    return JSONResponse({})
+
+
+def make_apps(started):
+    orders, billing = Application(), Application()
+    orders.add_route("/orders", list_orders)
+    billing.add_route("/invoices", list_invoices)
+
+    async def tag(request, call_next):
+        request.scope["headers"].append((b"x-billing-tagged", b"yes"))
+        response = await call_next(request)
+        response.headers["x-billing"] = "1"
+        return response
+
+    billing.add_middleware(tag)
+    orders.starlette.router.on_startup.append(lambda: started.append("orders"))
+    billing.starlette.router.on_startup.append(lambda: started.append("billing"))
+    return orders, billing
+
+
+async def test_both_apps_serve_through_one_client_with_scoped_middlewares():
+    started = []
+    orders, billing = make_apps(started)
+    app = orders.merge(billing)
+    assert app is orders
+    async with TestClient(app) as client:
+        assert started == ["orders", "billing"]
+        own = await client.get("/orders")
+        merged = await client.get("/invoices")
+    assert own.json() == {"orders": [], "tagged": None}
+    assert "x-billing" not in own.headers
+    assert merged.json() == {"invoices": [], "tagged": "yes"}
+    assert merged.headers["x-billing"] == "1"
+
+
+def test_duplicate_route_is_reported_with_both_endpoints():
+    orders, billing = make_apps([])
+    billing.add_route("/orders", other_orders)
+    with pytest.raises(MergeError) as caught:
+        orders.merge(billing)
+    assert caught.value.collisions == ["route GET /orders: host list_orders vs merged other_orders"]
+    assert orders.find_route("GET", "/invoices") is None
+
+
+def test_container_collision_is_reported():
+    class Clock:
+        pass
+
+    orders, billing = make_apps([])
+    orders.container.register_instance(Clock, Clock())
+    billing.container.register_instance(Clock, Clock())
+    with pytest.raises(MergeError, match="Clock"):
+        orders.merge(billing)
+
+    shared = Clock()
+    orders, billing = make_apps([])
+    orders.container.register_instance(Clock, shared)
+    billing.container.register_instance(Clock, shared)
+    assert orders.merge(billing).container.resolve(Clock) is shared
+
+
+async def test_events_reach_subscribers_of_both_apps():
+    orders, billing = make_apps([])
+    orders.register(EventBusModule().in_memory())
+    billing.register(EventBusModule().in_memory())
+    seen = []
+
+    async def on_paid_orders(event):
+        seen.append(("orders", event.invoice_id))
+
+    async def on_paid_billing(event):
+        seen.append(("billing", event.invoice_id))
+
+    orders.container.resolve(EventBus).subscribe(InvoicePaid, on_paid_orders)
+    billing.container.resolve(EventBus).subscribe(InvoicePaid, on_paid_billing)
+    orders.merge(billing)
+    await billing.container.resolve(EventBus).publish(InvoicePaid(invoice_id="i-1"))
+    assert sorted(seen) == [("billing", "i-1"), ("orders", "i-1")]
+
+
+async def test_merged_openapi_lists_both_apps():
+    orders, billing = make_apps([])
+    app = orders.merge(billing).openapi()
+    async with TestClient(app) as client:
+        paths = (await client.get("/openapi.json")).json()["paths"]
+    assert {"/orders", "/invoices"} <= set(paths)
+
+
+async def test_merge_shares_the_host_topic_hub():
+    host, other = Application(), Application()
+    host_hub = host.container.resolve(TopicHub)
+    host.merge(other)
+    assert other.container.resolve(TopicHub) is host_hub
+    subscription = host_hub.subscribe("orders")
+    assert other.container.resolve(TopicHub).publish("orders", {"id": 1}) == 1
+    assert await subscription.next_message() == ("orders", {"id": 1})
@@ -0,0 +1,101 @@
+"""multipart/form-data commands: files, text fields, limits and malformed bodies."""
+from dataclasses import dataclass
+from typing import Optional
+
+from urich import Application
+from urich.core import UploadedFile
+from urich.ddd import Command, DomainModule
+from urich.testing import TestClient
+
+BOUNDARY = "----urich-test-boundary"
+PATH = "/orders/commands/attach_documents"
+
+
+@dataclass
+class AttachDocuments(Command):
+    order_id: str
+    copies: int
+    files: list[UploadedFile]
+    cover: Optional[UploadedFile] = None
+
+
+def form(*parts, boundary=BOUNDARY):
+    """parts: (name, value) text fields or (name, filename, content_type, data) files."""
+    body = b""
+    for part in parts:
+        body += f"--{boundary}\r\n".encode()
+        if len(part) == 2:
+            body += f'Content-Disposition: form-data; name="{part[0]}"\r\n\r\n{part[1]}\r\n'.encode()
+        else:
+            name, filename, content_type, data = part
+            body += (
+                f'Content-Disposition: form-data; name="{name}"; filename="{filename}"\r\n'
+                f"Content-Type: {content_type}\r\n\r\n"
+            ).encode() + data + b"\r\n"
+    return body + f"--{boundary}--\r\n".encode()
+
+
+def make_app(received, **limits):
+    async def attach_documents(cmd: AttachDocuments):
+        received.append(cmd)
+        return {"files": [f.filename for f in cmd.files]}
+
+    app = Application()
+    app.register(DomainModule("orders").command(AttachDocuments, attach_documents, **limits))
+    return app.openapi(title="Orders")
+
+
+async def post(app, body, content_type=f"multipart/form-data; boundary={BOUNDARY}"):
+    async with TestClient(app) as client:
+        return await client.post(PATH, content=body, headers={"content-type": content_type})
+
+
+async def test_two_files_and_text_fields_reach_the_handler():
+    received = []
+    body = form(
+        ("order_id", "o-1"),
+        ("files", "a.txt", "text/plain", b"first\r\nline"),
+        ("copies", "2"),
+        ("files", "b.bin", "application/octet-stream", bytes(range(256))),
+    )
+    response = await post(make_app(received), body)
+    assert response.status_code == 200
+    assert response.json()["result"] == {"files": ["a.txt", "b.bin"]}
+    (cmd,) = received
+    assert (cmd.order_id, cmd.copies, cmd.cover) == ("o-1", 2, None)
+    first, second = cmd.files
+    assert (first.field, first.content_type, first.text()) == ("files", "text/plain", "first\r\nline")
+    assert (second.content_type, second.data, second.size) == ("application/octet-stream", bytes(range(256)), 256)
+
+
+async def test_file_over_max_file_size_is_413():
+    received = []
+    body = form(("order_id", "o-1"), ("copies", "1"), ("files", "big.txt", "text/plain", b"x" * 11))
+    response = await post(make_app(received, max_file_size=10), body)
+    assert response.status_code == 413
+    assert response.json()["error"]["code"] == "PAYLOAD_TOO_LARGE"
+    assert received == []
+
+
+async def test_body_over_max_body_size_is_413():
+    body = form(("order_id", "o-1"), ("copies", "1"), ("files", "a.txt", "text/plain", b"x" * 200))
+    response = await post(make_app([], max_body_size=100), body)
+    assert response.status_code == 413
+
+
+async def test_malformed_boundary_is_400():
+    body = form(("order_id", "o-1"), boundary="other")
+    for content_type in ("multipart/form-data", f"multipart/form-data; boundary={BOUNDARY}"):
+        response = await post(make_app([]), body, content_type)
+        assert response.status_code == 400
+        assert response.json()["error"]["code"] == "INVALID_MULTIPART"
+
+
+async def test_openapi_declares_multipart_request_body():
+    async with TestClient(make_app([])) as client:
+        spec = (await client.get("/openapi.json")).json()
+    content = spec["paths"][PATH]["post"]["requestBody"]["content"]
+    assert content["multipart/form-data"]["schema"] == {"$ref": "#/components/schemas/AttachDocuments"}
+    properties = spec["components"]["schemas"]["AttachDocuments"]["properties"]
+    assert properties["files"]["items"] == {"type": "string", "format": "binary"}
+    assert (properties["cover"]["type"], properties["cover"]["format"]) == ("string", "binary")
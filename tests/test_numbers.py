@@ -0,0 +1,145 @@
+"""JSON number handling: integer fields, large ids, ranges and query coercion."""
+from dataclasses import dataclass
+from typing import Optional
+
+import pytest
+
+from urich import Application
+from urich.core.openapi import schema_from_dataclass
+from urich.core.validation import Int32, UInt64, validate_payload
+from urich.ddd import Command, DomainModule, Query
+from urich.testing import TestClient
+
+BIG_ID = 2**63 + 12345  # above 2^53: a double would round it
+
+
+@dataclass
+class RenameUser(Command):
+    user_id: UInt64
+    name: str
+
+
+@dataclass
+class SetQuota(Command):
+    limit: int
+    ratio: float = 1.0
+
+
+@dataclass
+class GetUser(Query):
+    user_id: UInt64
+    page: Optional[Int32] = None
+    ratio: float = 1.0
+
+
+def make_app():
+    async def rename(cmd: RenameUser):
+        return {"user_id": cmd.user_id, "name": cmd.name}
+
+    async def set_quota(cmd: SetQuota):
+        return {"limit": cmd.limit, "ratio": cmd.ratio}
+
+    async def get_user(query: GetUser):
+        return {"user_id": query.user_id, "page": query.page, "ratio": query.ratio}
+
+    app = Application()
+    app.register(
+        DomainModule("users")
+        .command(RenameUser, rename)
+        .command(SetQuota, set_quota)
+        .query(GetUser, get_user)
+    )
+    return app
+
+
+async def test_id_above_2_53_round_trips_byte_identically():
+    raw = b'{"user_id": %d, "name": "ada"}' % BIG_ID
+    async with TestClient(make_app()) as client:
+        response = await client.post("/users/commands/rename_user", content=raw,
+                                     headers={"content-type": "application/json"})
+        assert response.status_code == 200
+        assert str(BIG_ID).encode() in response.content
+        assert response.json()["result"]["user_id"] == BIG_ID
+        response = await client.get(f"/users/queries/get_user?user_id={BIG_ID}")
+        assert response.json()["user_id"] == BIG_ID
+        assert b'"user_id":%d' % BIG_ID in response.content.replace(b" ", b"")
+
+
+@pytest.mark.parametrize("literal, kind", [
+    (b"1.5", "fractional"),
+    (b"2.0", "integral"),
+    (b"1e3", "integral"),
+    (b"1E+2", "integral"),
+])
+async def test_int_field_rejects_fraction_and_exponent(literal, kind):
+    async with TestClient(make_app()) as client:
+        response = await client.post("/users/commands/set_quota", content=b'{"limit": %s}' % literal,
+                                     headers={"content-type": "application/json"})
+    assert response.status_code == 422
+    (issue,) = response.json()["error"]["details"]["errors"]
+    assert issue["path"] == "/limit"
+    assert issue["keyword"] == "type"
+    assert f"got {kind} number" in issue["message"]
+
+
+async def test_float_field_accepts_exponent_notation():
+    async with TestClient(make_app()) as client:
+        response = await client.post("/users/commands/set_quota", content=b'{"limit": 5, "ratio": 2.5e-1}',
+                                     headers={"content-type": "application/json"})
+    assert response.json()["result"] == {"limit": 5, "ratio": 0.25}
+
+
+async def test_out_of_range_numbers_name_the_field():
+    async with TestClient(make_app()) as client:
+        too_big = await client.post("/users/commands/rename_user", json={"user_id": 2**64, "name": "ada"})
+        negative = await client.post("/users/commands/rename_user", json={"user_id": -1, "name": "ada"})
+        overflow = await client.post("/users/commands/set_quota", content=b'{"limit": 1, "ratio": 1e400}',
+                                     headers={"content-type": "application/json"})
+        page = await client.get(f"/users/queries/get_user?user_id=1&page={2**31}")
+    assert too_big.status_code == 422
+    assert too_big.json()["error"]["details"]["errors"][0] == {
+        "path": "/user_id",
+        "message": f"{2**64} is above the uint64 maximum {2**64 - 1}",
+        "keyword": "maximum",
+    }
+    assert negative.json()["error"]["details"]["errors"][0]["keyword"] == "minimum"
+    assert overflow.status_code == 422
+    assert overflow.json()["error"]["details"]["errors"][0]["path"] == "/ratio"
+    assert page.status_code == 422
+    assert "page" in page.json()["error"]["message"]
+
+
+async def test_nan_and_infinity_are_not_json():
+    async with TestClient(make_app()) as client:
+        response = await client.post("/users/commands/set_quota", content=b'{"limit": 1, "ratio": NaN}',
+                                     headers={"content-type": "application/json"})
+    assert response.status_code == 400
+    assert response.json()["error"]["code"] == "INVALID_JSON"
+
+
+@pytest.mark.parametrize("query, status", [
+    ("user_id=7", 200),
+    ("user_id=7.0", 422),
+    ("user_id=7e0", 422),
+    ("user_id=1_000", 422),
+    ("user_id=+7", 422),
+    ("user_id=7&ratio=1e-1", 200),
+    ("user_id=7&ratio=nan", 422),
+    ("user_id=7&ratio=inf", 422),
+])
+async def test_query_coercion_follows_json_rules(query, status):
+    async with TestClient(make_app()) as client:
+        response = await client.get(f"/users/queries/get_user?{query}")
+    assert response.status_code == status
+    if status == 422:
+        assert "expected" in response.json()["error"]["message"]
+
+
+def test_validate_payload_reports_ranges_in_strict_mode():
+    issues = validate_payload(GetUser, {"user_id": -5, "page": 2**40, "ratio": "x"}, strict=True)
+    assert [(i.path, i.keyword) for i in issues] == [("/user_id", "minimum"), ("/page", "maximum"), ("/ratio", "type")]
+
+
+def test_openapi_shows_integer_bounds():
+    schema = schema_from_dataclass(RenameUser)["properties"]["user_id"]
+    assert (schema["format"], schema["minimum"], schema["maximum"]) == ("uint64", 0, 2**64 - 1)
@@ -0,0 +1,90 @@
+"""/openapi.json: serialized once, byte-identical, ETag and 304 on If-None-Match."""
+import json
+from dataclasses import dataclass
+
+import pytest
+
+from urich import Application
+from urich.ddd import Command, DomainModule, Query
+from urich.testing import TestClient
+
+
+@dataclass
+class CreateOrder(Command):
+    order_id: str
+
+
+@dataclass
+class GetOrder(Query):
+    order_id: str
+
+
+@dataclass
+class ListCustomers(Query):
+    pass
+
+
+async def create_order(cmd: CreateOrder):
+    return None
+
+
+async def get_order(query: GetOrder):
+    return None
+
+
+async def list_customers(query: ListCustomers):
+    return []
+
+
+def make_app(reverse=False):
+    modules = [
+        DomainModule("orders").command(CreateOrder, create_order).query(GetOrder, get_order),
+        DomainModule("customers").query(ListCustomers, list_customers),
+    ]
+    app = Application()
+    for module in reversed(modules) if reverse else modules:
+        app.register(module)
+    return app.openapi(title="Shop", version="1.0")
+
+
+async def test_consecutive_requests_are_byte_identical_with_etag():
+    app = make_app()
+    async with TestClient(app) as client:
+        first = await client.get("/openapi.json")
+        second = await client.get("/openapi.json")
+    assert first.status_code == second.status_code == 200
+    assert first.content == second.content == app.openapi_spec_cached()
+    assert first.headers["etag"] == second.headers["etag"]
+    assert first.headers["etag"].startswith('"') and first.headers["etag"].endswith('"')
+    assert first.headers["cache-control"] == "no-cache"
+    assert int(first.headers["content-length"]) == len(first.content)
+
+
+@pytest.mark.parametrize("header", ["{etag}", "W/{etag}", '"other", {etag}', "*"])
+async def test_if_none_match_gets_304(header):
+    async with TestClient(make_app()) as client:
+        etag = (await client.get("/openapi.json")).headers["etag"]
+        response = await client.get("/openapi.json", headers={"If-None-Match": header.format(etag=etag)})
+    assert response.status_code == 304
+    assert response.content == b""
+    assert response.headers["etag"] == etag
+
+
+async def test_stale_etag_gets_the_body():
+    async with TestClient(make_app()) as client:
+        response = await client.get("/openapi.json", headers={"If-None-Match": '"stale"'})
+    assert response.status_code == 200
+    assert response.json()["info"]["title"] == "Shop"
+
+
+async def test_spec_does_not_depend_on_registration_order():
+    forward, reverse = make_app(), make_app(reverse=True)
+    assert forward.openapi_spec_cached() == reverse.openapi_spec_cached()
+    paths = list(json.loads(forward.openapi_spec_cached())["paths"])
+    assert paths == sorted(paths)
+    assert "/customers/queries/list_customers" in paths
+
+
+def test_spec_cached_before_openapi_is_an_error():
+    with pytest.raises(LookupError, match="openapi\\(\\) has not been called"):
+        Application().openapi_spec_cached()
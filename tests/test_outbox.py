@@ -0,0 +1,132 @@
+"""Bundled outbox stores and the relay."""
+import asyncio
+import json
+from dataclasses import dataclass
+
+import pytest
+
+from urich.domain import DomainEvent, EventEnvelope, InProcessEventDispatcher
+from urich.events.outbox_stores import InMemoryOutbox, JsonFileOutbox, relay_outbox
+from urich.events.retry import InMemoryDeadLetterSink, RetryPolicy
+
+
+@dataclass
+class ParcelShipped(DomainEvent):
+    parcel_id: str
+
+
+def envelopes(*ids):
+    return [EventEnvelope.wrap(ParcelShipped(parcel_id=i)) for i in ids]
+
+
+async def test_in_memory_partial_marking_and_duplicates():
+    outbox = InMemoryOutbox()
+    a, b, c = envelopes("a", "b", "c")
+    await outbox.append([a, b, c])
+    await outbox.append([a])
+    await outbox.mark_published([b.event_id, "unknown"])
+    assert [e.event_id for e in await outbox.fetch_pending()] == [a.event_id, c.event_id]
+    assert len(outbox.records) == 3
+
+
+async def test_json_file_survives_restart(tmp_path):
+    path = tmp_path / "outbox.jsonl"
+    a, b = envelopes("a", "b")
+    first = JsonFileOutbox(path)
+    await first.append([a, b])
+    await first.mark_published([a.event_id])
+    pending = await JsonFileOutbox(path).fetch_pending()
+    assert [e.event_id for e in pending] == [b.event_id]
+    assert pending[0].unwrap() == ParcelShipped(parcel_id="b")
+
+
+async def test_json_file_ignores_duplicates_across_restart(tmp_path):
+    path = tmp_path / "outbox.jsonl"
+    [a] = envelopes("a")
+    await JsonFileOutbox(path).append([a])
+    again = JsonFileOutbox(path)
+    await again.append([a, a])
+    assert len(path.read_text().splitlines()) == 1
+    await again.mark_published([a.event_id])
+    await again.append([a])
+    assert await again.fetch_pending() == []
+
+
+async def test_json_file_truncates_torn_last_line(tmp_path):
+    path = tmp_path / "outbox.jsonl"
+    a, b = envelopes("a", "b")
+    await JsonFileOutbox(path).append([a])
+    with path.open("a") as f:
+        f.write(json.dumps(b.to_dict())[:20])
+    outbox = JsonFileOutbox(path)
+    assert [e.event_id for e in await outbox.fetch_pending()] == [a.event_id]
+    await outbox.append([b])
+    assert [e.event_id for e in await JsonFileOutbox(path).fetch_pending()] == [a.event_id, b.event_id]
+
+
+async def test_json_file_rejects_corrupt_middle_line(tmp_path):
+    path = tmp_path / "outbox.jsonl"
+    [a] = envelopes("a")
+    path.write_text("not json\n" + json.dumps(a.to_dict()) + "\n")
+    with pytest.raises(ValueError, match="line 1"):
+        await JsonFileOutbox(path).fetch_pending()
+
+
+async def test_json_file_compacts_published_records(tmp_path):
+    path = tmp_path / "outbox.jsonl"
+    outbox = JsonFileOutbox(path, compact_after=3)
+    records = envelopes("a", "b", "c", "d")
+    await outbox.append(records)
+    await outbox.mark_published([records[0].event_id, records[1].event_id])
+    assert len(path.read_text().splitlines()) == 4
+    await outbox.mark_published([records[2].event_id])
+    assert [json.loads(line)["event_id"] for line in path.read_text().splitlines()] == [records[3].event_id]
+    assert outbox.published_path.read_text() == ""
+    assert [e.event_id for e in await JsonFileOutbox(path).fetch_pending()] == [records[3].event_id]
+
+
+async def test_json_file_crash_between_compaction_steps(tmp_path):
+    path = tmp_path / "outbox.jsonl"
+    a, b = envelopes("a", "b")
+    path.write_text(json.dumps(b.to_dict()) + "\n")  # file already compacted...
+    (tmp_path / "outbox.jsonl.published").write_text(a.event_id + "\n")  # ...sidecar not yet emptied
+    assert [e.event_id for e in await JsonFileOutbox(path).fetch_pending()] == [b.event_id]
+
+
+async def test_json_file_concurrent_appends(tmp_path):
+    path = tmp_path / "outbox.jsonl"
+    outbox = JsonFileOutbox(path)
+    batches = [envelopes(f"{n}-1", f"{n}-2") for n in range(10)]
+    await asyncio.gather(*(outbox.append(batch) for batch in batches))
+    assert len(path.read_text().splitlines()) == 20
+    assert len(await JsonFileOutbox(path).fetch_pending()) == 20
+
+
+async def test_relay_publishes_and_marks():
+    bus = InProcessEventDispatcher()
+    seen = []
+    bus.subscribe(ParcelShipped, lambda e: seen.append(e.parcel_id))
+    outbox = InMemoryOutbox()
+    await outbox.append(envelopes("a", "b"))
+    assert await relay_outbox(outbox, bus) == 2
+    assert seen == ["a", "b"]
+    assert await outbox.fetch_pending() == []
+
+
+async def test_relay_dead_letters_after_retries():
+    bus = InProcessEventDispatcher()
+
+    def fail(event):
+        raise RuntimeError("down")
+
+    bus.subscribe(ParcelShipped, fail)
+    outbox = InMemoryOutbox()
+    [a] = envelopes("a")
+    await outbox.append([a])
+    sink = InMemoryDeadLetterSink()
+    sent = await relay_outbox(outbox, bus, retry=RetryPolicy(max_attempts=2, backoff=0), dead_letter=sink)
+    assert sent == 0
+    [letter] = sink.letters
+    assert letter.attempts == 2
+    assert letter.metadata["event_id"] == a.event_id
+    assert await outbox.fetch_pending() == []
@@ -0,0 +1,63 @@
+"""Prefix exclusion is segment-aware in every module that supports .exclude()."""
+import pytest
+from starlette.responses import PlainTextResponse
+
+from urich import Application
+from urich.core.urls import under_prefix
+from urich.http.compression import CompressionModule
+from urich.http.concurrency import ConcurrencyLimitModule
+from urich.http.health import HealthModule
+from urich.testing import TestClient
+
+
+@pytest.mark.parametrize("path, prefix, expected", [
+    ("/files", "/files", True),
+    ("/files/a.txt", "/files", True),
+    ("/files/a.txt", "/files/", True),
+    ("/filesystem", "/files", False),
+    ("/file", "/files", False),
+    ("/anything", "/", True),
+])
+def test_under_prefix(path, prefix, expected):
+    assert under_prefix(path, prefix) is expected
+
+
+def test_compression_exclusion_matches_segments():
+    module = CompressionModule().exclude("/files")
+    assert module.is_excluded("/files/a.txt")
+    assert not module.is_excluded("/filesystem/report")
+
+
+def test_concurrency_exclusion_matches_segments_and_app_exempt_paths():
+    app = Application()
+    module = ConcurrencyLimitModule(1).exclude("/metrics")
+    app.register(module)
+    app.exempt_from_middleware("/ping")
+    assert module.is_excluded("/metrics/raw")
+    assert not module.is_excluded("/metricsx")
+    assert module.is_excluded("/ping")
+    assert not module.is_excluded("/ping/deep")
+
+
+async def test_compression_skips_only_the_excluded_segment():
+    app = Application()
+    body = "x" * 4096
+
+    async def text(request):
+        return PlainTextResponse(body)
+
+    app.add_route("/files/big", text, methods=["GET"])
+    app.add_route("/filesystem/big", text, methods=["GET"])
+    app.register(CompressionModule(minimum_size=100).exclude("/files"))
+    async with TestClient(app) as client:
+        excluded = await client.get("/files/big", headers={"Accept-Encoding": "gzip"})
+        compressed = await client.get("/filesystem/big", headers={"Accept-Encoding": "gzip"})
+    assert "content-encoding" not in excluded.headers
+    assert compressed.headers["content-encoding"] == "gzip"
+
+
+def test_health_routes_are_middleware_exempt():
+    app = Application()
+    app.register(HealthModule())
+    assert app.is_middleware_exempt("/health/live")
+    assert app.is_middleware_exempt("/health/ready")
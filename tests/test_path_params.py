@@ -0,0 +1,82 @@
+"""Path parameters: merged into DomainModule payloads, exact routes win over {param} ones."""
+from dataclasses import dataclass
+
+from starlette.responses import JSONResponse
+
+from urich import Application
+from urich.ddd import Command, DomainModule, Query
+from urich.testing import TestClient
+
+
+@dataclass
+class PlaceTenantOrder(Command):
+    tenant_id: str
+    sku: str
+
+
+@dataclass
+class ListTenantOrders(Query):
+    tenant_id: str
+    status: str
+
+
+async def place_tenant_order(cmd: PlaceTenantOrder) -> dict:
+    return {"tenant_id": cmd.tenant_id, "sku": cmd.sku}
+
+
+async def list_tenant_orders(query: ListTenantOrders) -> dict:
+    return {"tenant_id": query.tenant_id, "status": query.status}
+
+
+def make_app():
+    app = Application()
+    app.register(
+        DomainModule("tenant_orders", prefix="/tenants/{tenant_id}/orders")
+        .command(PlaceTenantOrder, place_tenant_order)
+        .query(ListTenantOrders, list_tenant_orders)
+    )
+    return app
+
+
+async def test_path_params_fill_command_and_query_fields():
+    client = TestClient(make_app())
+    placed = await client.post("/tenants/t-1/orders/commands/place_tenant_order", json={"sku": "s-1"})
+    assert placed.status_code == 200
+    assert placed.json()["result"] == {"tenant_id": "t-1", "sku": "s-1"}
+    listed = await client.get("/tenants/t-2/orders/queries/list_tenant_orders", params={"status": "open"})
+    assert listed.status_code == 200
+    assert listed.json() == {"tenant_id": "t-2", "status": "open"}
+
+
+async def test_path_param_wins_over_body():
+    client = TestClient(make_app())
+    placed = await client.post(
+        "/tenants/t-1/orders/commands/place_tenant_order", json={"tenant_id": "t-9", "sku": "s-1"}
+    )
+    assert placed.json()["result"]["tenant_id"] == "t-1"
+
+
+def answering(route):
+    async def endpoint(request):
+        return JSONResponse({"route": route, "params": dict(request.path_params)})
+
+    return endpoint
+
+
+async def test_exact_route_wins_regardless_of_registration_order():
+    app = Application()
+    for path in ("/users/{user_id}", "/users/me", "/users/{user_id}/profile"):
+        app.add_route(path, answering(path))
+    client = TestClient(app)
+    assert (await client.get("/users/me")).json() == {"route": "/users/me", "params": {}}
+    assert (await client.get("/users/42")).json() == {"route": "/users/{user_id}", "params": {"user_id": "42"}}
+    profile = await client.get("/users/42/profile")
+    assert profile.json() == {"route": "/users/{user_id}/profile", "params": {"user_id": "42"}}
+
+
+async def test_trailing_slash_mismatch_redirects():
+    app = Application()
+    app.add_route("/users/{user_id}", answering("/users/{user_id}"))
+    response = await TestClient(app).get("/users/42/")
+    assert response.status_code == 307
+    assert response.headers["location"].endswith("/users/42")
@@ -0,0 +1,58 @@
+"""Outbound payload guards: event publish and RPC client params."""
+from dataclasses import dataclass
+
+import pytest
+
+from urich.discovery.protocol import StaticDiscovery
+from urich.domain import DomainEvent, EventPayloadTooLarge, InProcessEventDispatcher
+from urich.rpc import RpcClient, RpcError
+
+
+@dataclass
+class ReportGenerated(DomainEvent):
+    report_id: str
+    body: str
+
+
+class RecordingTransport:
+    def __init__(self):
+        self.calls = []
+
+    async def call(self, url, method, payload):
+        self.calls.append(payload)
+        return b'{"ok": true}'
+
+
+def client(transport, **options):
+    return RpcClient(StaticDiscovery({"reports": "http://reports"}), transport, **options)
+
+
+async def test_oversized_event_is_rejected_at_publish_with_its_size():
+    handled = []
+    bus = InProcessEventDispatcher(max_payload_bytes=100)
+    bus.subscribe(ReportGenerated, handled.append)
+    with pytest.raises(EventPayloadTooLarge, match=r"Event ReportGenerated payload is \d+ bytes, limit is 100"):
+        await bus.publish(ReportGenerated("r-1", "x" * 200))
+    assert handled == []
+    await bus.publish(ReportGenerated("r-2", "small"))
+    assert len(handled) == 1
+
+
+async def test_oversized_rpc_params_fail_before_the_transport():
+    transport = RecordingTransport()
+    rpc = client(transport, max_payload_bytes=50)
+    with pytest.raises(RpcError, match="reports.store params are \\d+ bytes, limit is 50") as exc:
+        await rpc.call("reports", "store", {"body": "x" * 100}, raise_on_error=True)
+    assert exc.value.code == "PAYLOAD_TOO_LARGE"
+    assert await rpc.call("reports", "store", {"body": "x" * 100}) is None
+    assert transport.calls == []
+
+
+async def test_unserializable_rpc_params_are_an_rpc_error():
+    transport = RecordingTransport()
+    rpc = client(transport)
+    with pytest.raises(RpcError, match="not JSON-serializable") as exc:
+        await rpc.call("reports", "store", {"at": object()}, raise_on_error=True)
+    assert exc.value.code == "INVALID_PARAMS"
+    assert await rpc.call("reports", "store", {"at": object()}) is None
+    assert transport.calls == []
@@ -0,0 +1,227 @@
+"""Outbox-backed reliable RPC: delivery, retries, dead letters, breaker and concurrency limits."""
+import asyncio
+import json
+
+import pytest
+
+from urich import Application
+from urich.discovery.protocol import StaticDiscovery
+from urich.events.outbox_stores import InMemoryOutbox, JsonFileOutbox
+from urich.events.retry import InMemoryDeadLetterSink, RetryPolicy
+from urich.rpc import CircuitBreaker, ReliableRpc, RpcError, RpcModule
+from urich.rpc.rpc_module import RpcClient
+
+
+class ScriptedTransport:
+    """Answers each call with the next scripted step (an exception or a result dict), then {"ok": True}."""
+
+    def __init__(self, *steps):
+        self.steps = list(steps)
+        self.calls = []
+        self.delivered = []
+
+    async def call(self, url, method, payload):
+        self.calls.append((url, method, json.loads(payload)))
+        step = self.steps.pop(0) if self.steps else {"ok": True}
+        if isinstance(step, Exception):
+            raise step
+        if "error" not in step:
+            self.delivered.append((method, json.loads(payload)))
+        return json.dumps(step).encode()
+
+
+class Clock:
+    def __init__(self):
+        self.now = 1000.0
+
+    def __call__(self):
+        return self.now
+
+
+def make(transport, outbox=None, **options):
+    client = RpcClient(StaticDiscovery({"billing": "http://billing"}), transport)
+    clock = Clock()
+    sink = InMemoryDeadLetterSink()
+    rpc = ReliableRpc(
+        client,
+        outbox if outbox is not None else InMemoryOutbox(),
+        retry=options.pop("retry", RetryPolicy(max_attempts=3, backoff=10.0)),
+        dead_letter=sink,
+        clock=clock,
+        **options,
+    )
+    return rpc, clock, sink
+
+
+async def test_failing_then_succeeding_call_is_delivered_once():
+    transport = ScriptedTransport(RpcError("TRANSPORT_ERROR", "reset"), RpcError("TRANSPORT_ERROR", "reset"))
+    outbox = InMemoryOutbox()
+    rpc, clock, sink = make(transport, outbox)
+    call_id = await rpc.enqueue("billing", "order_completed", {"order_id": "o-1"})
+
+    assert await rpc.relay() == 0
+    (pending,) = await outbox.fetch_pending_calls()
+    assert (pending.call_id, pending.attempts, pending.next_attempt_at) == (call_id, 1, 1010.0)
+    assert await rpc.relay() == 0  # not due yet
+    assert len(transport.calls) == 1
+
+    clock.now = 1010.0
+    assert await rpc.relay() == 0
+    clock.now = 1030.0
+    assert await rpc.relay() == 1
+    assert transport.delivered == [("order_completed", {"order_id": "o-1"})]
+    assert await outbox.fetch_pending_calls() == []
+    assert outbox.finished_calls == {call_id}
+
+    clock.now = 5000.0
+    assert await rpc.relay() == 0
+    assert len(transport.delivered) == 1
+    assert sink.letters == []
+    assert rpc.stats("billing") == {"queued": 1, "delivered": 1, "dead_lettered": 0}
+
+
+async def test_exhausted_retries_go_to_dead_letter():
+    transport = ScriptedTransport(*[RpcError("SERVICE_UNAVAILABLE", "down")] * 3)
+    outbox = InMemoryOutbox()
+    rpc, clock, sink = make(transport, outbox)
+    call_id = await rpc.enqueue("billing", "order_completed", {"order_id": "o-2"})
+    for _ in range(3):
+        await rpc.relay()
+        clock.now += 100
+    assert len(transport.calls) == 3
+    (letter,) = sink.letters
+    assert letter.event_type == "rpc:billing.order_completed"
+    assert letter.attempts == 3
+    assert letter.code == "SERVICE_UNAVAILABLE"
+    assert letter.metadata["call_id"] == call_id
+    assert letter.metadata["params"] == {"order_id": "o-2"}
+    assert await outbox.fetch_pending_calls() == []
+    assert await rpc.relay() == 0
+    assert len(transport.calls) == 3
+    assert rpc.stats("billing")["dead_lettered"] == 1
+
+
+async def test_client_error_envelope_is_not_retried():
+    transport = ScriptedTransport({"error": {"code": "INVALID_PARAMS", "message": "order_id missing"}})
+    rpc, _, sink = make(transport)
+    await rpc.enqueue("billing", "order_completed", {})
+    await rpc.relay()
+    assert len(transport.calls) == 1
+    (letter,) = sink.letters
+    assert (letter.code, letter.attempts) == ("INVALID_PARAMS", 1)
+
+
+async def test_server_error_envelope_is_retried():
+    transport = ScriptedTransport({"error": {"code": "INTERNAL", "message": "Internal error"}})
+    rpc, clock, sink = make(transport)
+    await rpc.enqueue("billing", "order_completed", {})
+    assert await rpc.relay() == 0
+    clock.now += 100
+    assert await rpc.relay() == 1
+    assert sink.letters == []
+
+
+async def test_json_file_outbox_keeps_call_state_across_restarts(tmp_path):
+    path = tmp_path / "outbox.jsonl"
+    transport = ScriptedTransport(RpcError("TRANSPORT_ERROR", "reset"))
+    rpc, clock, _ = make(transport, JsonFileOutbox(path))
+    call_id = await rpc.enqueue("billing", "order_completed", {"order_id": "o-3"})
+    await rpc.relay()
+
+    restarted = JsonFileOutbox(path)
+    (pending,) = await restarted.fetch_pending_calls()
+    assert (pending.call_id, pending.attempts, pending.last_error) == (call_id, 1, "[TRANSPORT_ERROR] reset")
+    assert await restarted.fetch_pending() == []
+
+    rpc, clock, _ = make(transport, restarted)
+    clock.now = pending.next_attempt_at
+    assert await rpc.relay() == 1
+    assert await JsonFileOutbox(path).fetch_pending_calls() == []
+
+
+async def test_json_file_outbox_compacts_call_updates(tmp_path):
+    path = tmp_path / "outbox.jsonl"
+    outbox = JsonFileOutbox(path, compact_after=3)
+    rpc, clock, _ = make(ScriptedTransport(*[RpcError("TRANSPORT_ERROR", "reset")] * 3),
+                         outbox, retry=RetryPolicy(max_attempts=10, backoff=1.0))
+    await rpc.enqueue("billing", "a", {})
+    for _ in range(3):
+        await rpc.relay()
+        clock.now += 100
+    assert len(path.read_text().splitlines()) == 1
+    (pending,) = await JsonFileOutbox(path).fetch_pending_calls()
+    assert pending.attempts == 3
+
+
+async def test_circuit_breaker_holds_calls_without_spending_attempts():
+    transport = ScriptedTransport(RpcError("TRANSPORT_ERROR", "down"), RpcError("TRANSPORT_ERROR", "down"))
+    outbox = InMemoryOutbox()
+    rpc, clock, sink = make(
+        transport, outbox,
+        retry=RetryPolicy(max_attempts=10, backoff=0.0),
+        breaker=CircuitBreaker(failure_threshold=2, reset_after=60.0),
+    )
+    await rpc.enqueue("billing", "a", {})
+    await rpc.relay()
+    await rpc.relay()
+    await rpc.relay()  # breaker open: skipped
+    assert len(transport.calls) == 2
+    (pending,) = await outbox.fetch_pending_calls()
+    assert pending.attempts == 2
+
+    clock.now += 60
+    assert await rpc.relay() == 1
+    assert len(transport.calls) == 3
+    assert sink.letters == []
+
+
+async def test_per_service_concurrency_limit():
+    in_flight = 0
+    peak = 0
+
+    class SlowTransport:
+        async def call(self, url, method, payload):
+            nonlocal in_flight, peak
+            in_flight += 1
+            peak = max(peak, in_flight)
+            await asyncio.sleep(0.01)
+            in_flight -= 1
+            return b"{}"
+
+    rpc, _, _ = make(SlowTransport(), max_concurrency=2)
+    for i in range(5):
+        await rpc.enqueue("billing", "a", {"n": i})
+    assert await rpc.relay() == 5
+    assert peak == 2
+
+
+def test_outbox_without_call_support_is_rejected():
+    class EventsOnly:
+        async def append(self, events, *, connection=None):
+            pass
+
+    with pytest.raises(TypeError, match="cannot store RPC calls"):
+        make(ScriptedTransport(), EventsOnly())
+
+
+async def test_rpc_module_registers_reliable_rpc_on_the_outbox():
+    from urich.events import OutboxModule, OutboxStorage
+    from urich.http.metrics import MetricsModule
+    from urich.testing import TestClient
+
+    app = Application()
+    metrics = MetricsModule()
+    app.register(metrics)
+    app.register(OutboxModule().in_memory())
+    app.register(
+        RpcModule()
+        .client(discovery=StaticDiscovery({"billing": "http://billing"}), transport=ScriptedTransport())
+        .reliable()
+    )
+    async with TestClient(app):
+        rpc = app.container.resolve(ReliableRpc)
+        call_id = await rpc.enqueue("billing", "order_completed", {"order_id": "o-4"})
+        assert [c.call_id for c in await app.container.resolve(OutboxStorage).fetch_pending_calls()] == [call_id]
+        assert await rpc.relay() == 1
+    assert metrics.rpc_outbox.get(service="billing", outcome="queued") == 1
+    assert metrics.rpc_outbox.get(service="billing", outcome="delivered") == 1
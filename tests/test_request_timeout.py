@@ -0,0 +1,81 @@
+"""Per-request timeout: 504 naming the route, the handler is cancelled, started streams run on."""
+import asyncio
+
+from starlette.responses import JSONResponse, StreamingResponse
+
+from urich import Application
+from urich.rpc import RpcModule, RpcServer
+from urich.testing import TestClient
+
+
+def make_app(**options):
+    events = []
+
+    async def slow(request):
+        events.append("started")
+        try:
+            await asyncio.sleep(10)
+        except asyncio.CancelledError:
+            events.append("cancelled")
+            raise
+        return JSONResponse({"done": True})
+
+    async def quick(request):
+        await asyncio.sleep(0.01)
+        return JSONResponse({"done": True})
+
+    async def stream(request):
+        async def chunks():
+            for i in range(3):
+                await asyncio.sleep(0.05)
+                yield f"{i}\n"
+
+        return StreamingResponse(chunks(), media_type="text/plain")
+
+    app = Application(**options)
+    app.add_route("/reports/{report_id}", slow)
+    app.add_route("/quick", quick)
+    app.add_route("/patient", quick, timeout=None)
+    app.add_route("/stream", stream)
+    return app, events
+
+
+async def test_slow_handler_gets_504_naming_the_route_and_is_cancelled():
+    app, events = make_app(request_timeout=0.05)
+    client = TestClient(app)
+    response = await client.get("/reports/7")
+    assert response.status_code == 504
+    assert response.json()["error"] == {
+        "code": "GATEWAY_TIMEOUT",
+        "message": "GET /reports/{report_id} did not respond within 0.05s",
+        "details": {"route": "/reports/{report_id}", "timeout": 0.05},
+    }
+    assert events == ["started", "cancelled"]
+    assert (await client.get("/quick")).status_code == 200
+
+
+async def test_per_route_timeout_overrides_the_app_default():
+    app, _ = make_app(request_timeout=0.001)
+    client = TestClient(app)
+    assert (await client.get("/quick")).status_code == 504
+    assert (await client.get("/patient")).status_code == 200
+
+
+async def test_started_stream_is_not_cut_off():
+    app, _ = make_app(request_timeout=0.08)
+    response = await TestClient(app).get("/stream")
+    assert response.status_code == 200
+    assert response.content == b"0\n1\n2\n"
+
+
+class Reports(RpcServer):
+    async def build(self):
+        await asyncio.sleep(10)
+
+
+async def test_rpc_route_respects_the_timeout():
+    app = Application(request_timeout=0.05)
+    app.register(RpcModule().server("/rpc", handler=Reports()))
+    response = await TestClient(app).post("/rpc/build", json={"params": {}})
+    assert response.status_code == 504
+    assert response.json()["error"]["details"]["route"] == "/rpc/{path:path}"
@@ -0,0 +1,122 @@
+"""schema_compat classification rules (table-driven)."""
+import pytest
+
+from urich.core.schema_compat import schema_compat
+
+
+def obj(properties, required=(), **extra):
+    return {"type": "object", "properties": properties, "required": list(required), **extra}
+
+
+STR = {"type": "string"}
+INT = {"type": "integer"}
+
+CASES = [
+    # (name, old, new, expected level)
+    ("identical", obj({"a": STR}, ["a"]), obj({"a": STR}, ["a"]), "compatible"),
+    ("optional field added", obj({"a": STR}), obj({"a": STR, "b": INT}), "compatible"),
+    ("required field added", obj({"a": STR}), obj({"a": STR, "b": INT}, ["b"]), "compatible_for_readers"),
+    ("field removed", obj({"a": STR, "b": INT}), obj({"a": STR}), "compatible_for_writers"),
+    ("field became required", obj({"a": STR}), obj({"a": STR}, ["a"]), "compatible_for_readers"),
+    ("field became optional", obj({"a": STR}, ["a"]), obj({"a": STR}), "compatible_for_writers"),
+    ("type narrowed", {"type": "number"}, INT, "compatible_for_readers"),
+    ("type widened", INT, {"type": "number"}, "compatible_for_writers"),
+    ("type changed", STR, {"type": "object"}, "breaking"),
+    ("type restricted", {}, STR, "compatible_for_readers"),
+    ("type restriction removed", STR, {}, "compatible_for_writers"),
+    ("nullable added", STR, {"type": "string", "nullable": True}, "compatible_for_writers"),
+    ("enum widened", {"enum": ["a"]}, {"enum": ["a", "b"]}, "compatible_for_writers"),
+    ("enum narrowed", {"enum": ["a", "b"]}, {"enum": ["a"]}, "compatible_for_readers"),
+    ("enum introduced", STR, {"type": "string", "enum": ["a"]}, "compatible_for_readers"),
+    ("enum removed", {"type": "string", "enum": ["a"]}, STR, "compatible_for_writers"),
+    ("enum reordered", {"enum": ["a", "b"]}, {"enum": ["b", "a"]}, "compatible"),
+    ("maxLength tightened", {"maxLength": 10}, {"maxLength": 5}, "compatible_for_readers"),
+    ("maxLength relaxed", {"maxLength": 5}, {"maxLength": 10}, "compatible_for_writers"),
+    ("minimum tightened", {"minimum": 0}, {"minimum": 1}, "compatible_for_readers"),
+    ("minimum relaxed", {"minimum": 1}, {"minimum": 0}, "compatible_for_writers"),
+    ("bound added", INT, {"type": "integer", "maximum": 9}, "compatible_for_readers"),
+    ("bound removed", {"type": "integer", "maximum": 9}, INT, "compatible_for_writers"),
+    ("extra properties disallowed", obj({}), obj({}, additionalProperties=False), "compatible_for_readers"),
+    ("extra properties allowed", obj({}, additionalProperties=False), obj({}), "compatible_for_writers"),
+    (
+        "nested required field added",
+        obj({"address": obj({"city": STR})}),
+        obj({"address": obj({"city": STR, "zip": STR}, ["zip"])}),
+        "compatible_for_readers",
+    ),
+    (
+        "array item type changed",
+        {"type": "array", "items": STR},
+        {"type": "array", "items": {"type": "object"}},
+        "breaking",
+    ),
+    (
+        "required field added and field removed",
+        obj({"a": STR}),
+        obj({"b": STR}, ["b"]),
+        "breaking",
+    ),
+]
+
+
+@pytest.mark.parametrize("name, old, new, level", CASES)
+def test_classification(name, old, new, level):
+    assert schema_compat(old, new).level == level, name
+
+
+def test_change_paths_point_at_nested_fields():
+    old = obj({"items": {"type": "array", "items": obj({"sku": STR})}})
+    new = obj({"items": {"type": "array", "items": obj({"sku": INT})}})
+    [change] = schema_compat(old, new).changes
+    assert change.path == "$.items[].sku"
+    assert change.level == "breaking"
+
+
+def test_refs_resolve_against_each_documents_components():
+    ref = {"$ref": "#/components/schemas/Item"}
+    old_components = {"Item": obj({"sku": STR, "qty": INT}, ["sku"])}
+    new_components = {"Item": obj({"sku": STR}, ["sku"])}
+    report = schema_compat(
+        obj({"item": ref}), obj({"item": ref}), old_components=old_components, new_components=new_components
+    )
+    assert report.level == "compatible_for_writers"
+    assert [c.path for c in report.changes] == ["$.item.qty"]
+
+
+def test_required_field_added_inside_shared_component_breaks_writers():
+    ref = {"$ref": "#/components/schemas/Item"}
+    report = schema_compat(
+        ref,
+        ref,
+        old_components={"Item": obj({"sku": STR})},
+        new_components={"Item": obj({"sku": STR, "qty": INT}, ["qty"])},
+    )
+    assert not report.compatible_for_writers
+
+
+def test_shared_components_used_for_both_sides():
+    ref = {"$ref": "Item"}
+    report = schema_compat(ref, obj({"sku": INT}), components={"Item": obj({"sku": STR})})
+    assert report.level == "breaking"
+
+
+def test_recursive_refs_terminate():
+    components = {"Node": obj({"next": {"$ref": "#/components/schemas/Node"}})}
+    ref = {"$ref": "#/components/schemas/Node"}
+    assert schema_compat(ref, ref, components=components).level == "compatible"
+
+
+def test_report_to_dict():
+    report = schema_compat(obj({"a": STR}), obj({}))
+    assert report.to_dict() == {
+        "level": "compatible_for_writers",
+        "changes": [
+            {
+                "path": "$.a",
+                "message": "field removed",
+                "level": "compatible_for_writers",
+                "breaks_readers": True,
+                "breaks_writers": False,
+            }
+        ],
+    }
@@ -0,0 +1,91 @@
+"""X-Schema-Hash headers and the schema_hash column of route_table()."""
+from dataclasses import dataclass
+
+from urich import Application
+from urich.ddd import Command, DomainModule
+from urich.testing import TestClient
+
+
+@dataclass
+class CreateOrder(Command):
+    order_id: str
+
+
+async def create_order(cmd: CreateOrder) -> dict:
+    return {"id": cmd.order_id}
+
+
+def make_app(strict: bool = False) -> Application:
+    app = Application()
+    app.register(DomainModule("orders").command(CreateOrder, create_order))
+
+    async def health(request):
+        from starlette.responses import JSONResponse
+
+        return JSONResponse({"ok": True})
+
+    app.add_route("/health", health, methods=["GET"])
+    app.schema_hashes(strict=strict)
+    return app
+
+
+def test_route_table_has_schema_hash():
+    app = make_app()
+    rows = {(r["method"], r["path"]): r for r in app.route_table()}
+    command = rows[("POST", "/orders/commands/create_order")]
+    assert command["schema_hash"] == app.route_schema_hashes()["POST /orders/commands/create_order"]
+    assert rows[("GET", "/health")]["schema_hash"] == app.route_schema_hashes()["GET /health"]
+
+
+async def test_response_carries_hash_and_drift():
+    app = make_app()
+    expected = app.route_schema_hashes()["POST /orders/commands/create_order"]
+    client = TestClient(app)
+    ok = await client.post("/orders/commands/create_order", json={"order_id": "o1"})
+    assert ok.headers["x-schema-hash"] == expected
+    assert "x-schema-drift" not in ok.headers
+    drift = await client.post(
+        "/orders/commands/create_order", json={"order_id": "o1"}, headers={"X-Expected-Schema-Hash": "stale"}
+    )
+    assert drift.status_code == 200
+    assert drift.headers["x-schema-drift"] == "true"
+    plain = await client.get("/health")
+    assert plain.headers["x-schema-hash"] == app.route_schema_hashes()["GET /health"]
+    missing = await client.get("/nowhere")
+    assert "x-schema-hash" not in missing.headers
+
+
+async def test_strict_rejects_drift():
+    client = TestClient(make_app(strict=True))
+    response = await client.post(
+        "/orders/commands/create_order", json={"order_id": "o1"}, headers={"X-Expected-Schema-Hash": "stale"}
+    )
+    assert response.status_code == 412
+    assert response.json()["error"]["code"] == "SCHEMA_DRIFT"
+
+
+def test_hash_is_stable_across_processes():
+    # Golden value: changes only when the operation's schema (or the hashing scheme) changes.
+    hashes = make_app().route_schema_hashes()
+    assert hashes["POST /orders/commands/create_order"] == (
+        "fb6513cc381191f2f79c77e000a58c33c235cab3ea2b788ac88f0d906fe27832"
+    )
+
+
+async def test_replaced_route_serves_the_new_hash():
+    app = make_app()
+    client = TestClient(app)
+    before = (await client.get("/health")).headers["x-schema-hash"]
+
+    async def health(request):
+        from starlette.responses import JSONResponse
+
+        return JSONResponse({"ok": True, "version": 2})
+
+    app.add_route(
+        "/health", health, methods=["GET"], replace=True,
+        openapi_response_schema={"type": "object", "properties": {"version": {"type": "integer"}}},
+    )
+    after = (await client.get("/health")).headers["x-schema-hash"]
+    assert after != before
+    assert after == app.route_schema_hashes()["GET /health"]
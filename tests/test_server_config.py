@@ -0,0 +1,111 @@
+"""ServerConfig profiles, validation, overrides and warnings."""
+import pytest
+
+from urich.core import ConfigError, DocsConfig, ServerConfig
+from urich.core.builder import PROFILES
+
+
+def test_production_profile_values():
+    config = ServerConfig.production()
+    assert config.profile == "production"
+    assert config.host == "0.0.0.0"
+    assert config.request_timeout == 30.0
+    assert config.max_body_size == 1024 * 1024
+    assert config.docs is None
+    assert config.log_level == "warning"
+    assert config.server_options == {"proxy_headers": True, "access_log": False}
+    assert config.overrides() == {}
+    assert config.warnings() == []
+
+
+def test_development_profile_values():
+    config = ServerConfig.development()
+    assert config.profile == "development"
+    assert config.host == "127.0.0.1"
+    assert config.request_timeout is None
+    assert config.max_body_size is None
+    assert config.docs == DocsConfig()
+    assert config.log_level == "debug"
+    assert config.warnings() == []
+
+
+def test_profiles_do_not_share_mutable_state():
+    ServerConfig.production().server_options["reload"] = True
+    assert "reload" not in ServerConfig.production().server_options
+    assert "reload" not in PROFILES["production"]["server_options"]
+
+
+def test_unknown_profile():
+    with pytest.raises(ConfigError, match="unknown profile 'staging'"):
+        ServerConfig.for_profile("staging")
+
+
+def test_profile_from_env():
+    import os
+
+    saved = {k: v for k, v in os.environ.items() if k.startswith("URICH_")}
+    try:
+        for key in saved:
+            del os.environ[key]
+        os.environ["URICH_PROFILE"] = "production"
+        os.environ["URICH_PORT"] = "9000"
+        config = ServerConfig.from_env()
+    finally:
+        for key in [k for k in os.environ if k.startswith("URICH_")]:
+            del os.environ[key]
+        os.environ.update(saved)
+    assert config.profile == "production"
+    assert config.port == 9000
+    assert config.log_level == "warning"
+    assert config.overrides() == {"port": (8000, 9000)}
+
+
+def test_override_detection_and_summary():
+    config = ServerConfig.from_dict({"profile": "production", "request_timeout": "5", "openapi_title": "Orders"})
+    overrides = config.overrides()
+    assert overrides["request_timeout"] == (30.0, 5.0)
+    assert overrides["docs"] == (None, DocsConfig(title="Orders"))
+    assert set(overrides) == {"request_timeout", "docs"}
+    summary = config.summary()
+    assert summary.splitlines()[0] == "Server profile: production (0.0.0.0:8000)"
+    assert "override request_timeout: 30.0 -> 5.0" in summary
+
+
+def test_custom_config_has_no_profile():
+    config = ServerConfig(port=9000)
+    assert config.overrides() == {}
+    assert config.summary() == "Server profile: custom (127.0.0.1:9000)"
+
+
+@pytest.mark.parametrize(
+    "config, warning",
+    [
+        (ServerConfig.production(docs=DocsConfig()), "OpenAPI docs are served in the production profile"),
+        (ServerConfig.production(request_timeout=None), "no request timeout in the production profile"),
+        (ServerConfig.production(max_body_size=None), "no body size limit in the production profile"),
+        (ServerConfig.production(log_level="debug"), "debug logging in the production profile"),
+        (
+            ServerConfig.production(server_options={"reload": True}),
+            "auto-reload is enabled in the production profile",
+        ),
+        (
+            ServerConfig.development(host="0.0.0.0"),
+            "development profile listens on 0.0.0.0, not only on localhost",
+        ),
+        (
+            ServerConfig(request_timeout=None, server_options={"timeout_keep_alive": 120}),
+            "no request timeout while keep-alive connections are held over 60 s",
+        ),
+    ],
+)
+def test_warning_rules(config, warning):
+    assert config.warnings() == [warning]
+    assert config.validate() == []
+
+
+def test_from_dict_rejects_unknown_and_bad_values():
+    with pytest.raises(ConfigError, match="unknown setting 'prot'"):
+        ServerConfig.from_dict({"prot": 1})
+    with pytest.raises(ConfigError, match="port 'abc' is not a number"):
+        ServerConfig.from_dict({"port": "abc"})
+    assert ServerConfig.from_dict({"max_body_size": "none"}).max_body_size is None
@@ -0,0 +1,79 @@
+"""Named routes and Application.url_for reverse routing."""
+from dataclasses import dataclass
+
+import pytest
+from starlette.responses import JSONResponse
+
+from urich import Application
+from urich.core.urls import UrlError
+from urich.ddd import DomainModule, Query
+from urich.testing import TestClient
+
+
+async def echo(request):
+    return JSONResponse({"path": dict(request.path_params), "query": dict(request.query_params)})
+
+
+@dataclass
+class GetOrder(Query):
+    order_id: str
+
+
+async def get_order(query: GetOrder) -> dict:
+    return {"order_id": query.order_id}
+
+
+def make_app():
+    app = Application()
+    app.add_route("/orders/{order_id}/items", echo, methods=["GET"], name="order_items")
+    app.add_route("/files/{name:path}", echo, methods=["GET"], name="file")
+    app.add_route("/orders/{order_id}/cancel", echo, methods=["POST"], name="cancel_order")
+    app.register(DomainModule("shop").query(GetOrder, get_order))
+    return app
+
+
+@pytest.mark.parametrize("order_id", ["42", "A 1", "x?y=1&z#frag", "100%", "ä€"])
+async def test_url_for_round_trips_through_the_router(order_id):
+    app = make_app()
+    url = app.url_for("order_items", {"order_id": order_id, "page": 2})
+    response = await TestClient(app).get(url)
+    assert response.status_code == 200
+    assert response.json() == {"path": {"order_id": order_id}, "query": {"page": "2"}}
+
+
+async def test_path_converter_round_trips_slashes():
+    app = make_app()
+    response = await TestClient(app).get(app.url_for("file", {"name": "docs/a b.txt"}))
+    assert response.json()["path"] == {"name": "docs/a b.txt"}
+
+
+def test_reserved_characters_are_percent_encoded():
+    app = make_app()
+    assert app.url_for("order_items", {"order_id": "a/b c?"}) == "/orders/a%2Fb%20c%3F/items"
+    assert app.url_for("file", {"name": "docs/a b.txt"}) == "/files/docs/a%20b.txt"
+    assert app.url_for("order_items", {"order_id": 1, "tag": ["x&y", "z"], "flag": True}) == (
+        "/orders/1/items?flag=true&tag=x%26y&tag=z"
+    )
+
+
+def test_missing_unexpected_and_unknown():
+    app = make_app()
+    with pytest.raises(UrlError, match="Missing path parameters .*: order_id"):
+        app.url_for("order_items", {"page": 1})
+    with pytest.raises(UrlError, match="Unexpected parameters .*: reason"):
+        app.url_for("cancel_order", {"order_id": "1", "reason": "late"})
+    with pytest.raises(UrlError, match="No route named 'nope'"):
+        app.url_for("nope")
+
+
+def test_names_are_unique():
+    app = make_app()
+    with pytest.raises(ValueError, match="'order_items' already registered"):
+        app.add_route("/other", echo, name="order_items")
+
+
+def test_domain_module_routes_are_named_and_base_url():
+    app = make_app()
+    assert app.url_for("shop.queries.get_order", {"order_id": "42"}, base_url="https://api.example.com/") == (
+        "https://api.example.com/shop/queries/get_order?order_id=42"
+    )
@@ -0,0 +1,45 @@
+"""Body validation: the first issue by default, every issue in strict mode."""
+from dataclasses import dataclass
+
+from urich import Application
+from urich.ddd import Command, DomainModule
+from urich.testing import TestClient
+
+BODY = {"quantity": "two", "tags": ["new", 3]}  # order_id missing, quantity and tags/1 mistyped
+
+
+@dataclass
+class CreateOrder(Command):
+    order_id: str
+    quantity: int
+    tags: list[str]
+
+
+def make_app(strict):
+    async def create_order(cmd: CreateOrder):
+        return {"order_id": cmd.order_id}
+
+    app = Application()
+    app.register(DomainModule("orders").command(CreateOrder, create_order, strict=strict))
+    return app
+
+
+async def test_strict_mode_reports_every_violation():
+    async with TestClient(make_app(strict=True)) as client:
+        response = await client.post("/orders/commands/create_order", json=BODY)
+    assert response.status_code == 422
+    error = response.json()["error"]
+    assert error["code"] == "VALIDATION_ERROR"
+    assert error["message"] == "Invalid CreateOrder: 3 errors"
+    assert sorted((e["path"], e["keyword"]) for e in error["details"]["errors"]) == [
+        ("/order_id", "required"),
+        ("/quantity", "type"),
+        ("/tags/1", "type"),
+    ]
+
+
+async def test_lenient_mode_stops_at_the_first_violation():
+    async with TestClient(make_app(strict=False)) as client:
+        response = await client.post("/orders/commands/create_order", json=BODY)
+    assert response.status_code == 422
+    assert len(response.json()["error"]["details"]["errors"]) == 1
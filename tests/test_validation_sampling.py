@@ -0,0 +1,97 @@
+"""Per-route skip_validation, validation sampling and validation timing in stats and metrics."""
+from dataclasses import dataclass
+
+import pytest
+
+from urich import Application
+from urich.ddd import Command, DomainModule
+from urich.http import MetricsModule
+from urich.testing import TestClient
+
+PATH = "/ledger/commands/post_entry"
+
+
+@dataclass
+class PostEntry(Command):
+    account: str
+    amount: int
+
+
+@dataclass
+class PostBulkEntry(Command):
+    account: str
+    amount: int
+
+
+def make_app(**options):
+    received = []
+
+    async def post_entry(cmd: PostEntry):
+        received.append(cmd.amount)
+
+    async def post_bulk_entry(cmd: PostBulkEntry):
+        received.append(cmd.amount)
+
+    app = Application()
+    metrics = MetricsModule()
+    app.register(metrics)
+    app.register(
+        DomainModule("ledger")
+        .command(PostEntry, post_entry, **options)
+        .command(PostBulkEntry, post_bulk_entry, skip_validation=True)
+    )
+    return app.openapi(), metrics, received
+
+
+async def test_skipped_route_passes_mistyped_bodies_and_is_documented():
+    app, _, received = make_app()
+    client = TestClient(app)
+    skipped = await client.post("/ledger/commands/post_bulk_entry", json={"account": "a-1", "amount": "12"})
+    assert skipped.status_code == 200
+    assert received == ["12"]
+    unknown = await client.post("/ledger/commands/post_bulk_entry", json={"account": "a-1", "amount": 1, "x": 1})
+    assert unknown.status_code == 422
+    assert (await client.post(PATH, json={"account": "a-1", "amount": "12"})).status_code == 422
+
+    assert app.find_route("POST", "/ledger/commands/post_bulk_entry")["validation"] == "skipped"
+    assert app.find_route("POST", PATH)["validation"] == "on"
+    assert app.find_route("GET", "/openapi.json")["validation"] is None
+    paths = (await client.get("/openapi.json")).json()["paths"]
+    assert paths["/ledger/commands/post_bulk_entry"]["post"]["x-skip-validation"] is True
+    assert "x-skip-validation" not in paths[PATH]["post"]
+
+
+async def test_sampling_validates_the_configured_share():
+    app, _, received = make_app()
+    app.validation_sampling(25)
+    client = TestClient(app)
+    statuses = [(await client.post(PATH, json={"account": "a-1", "amount": "x"})).status_code for _ in range(400)]
+    rejected = statuses.count(422)
+    assert 90 <= rejected <= 110
+    assert statuses.count(200) == len(received) == 400 - rejected
+    stats = app.validation_stats()[f"POST {PATH}"]
+    assert stats["mode"] == "sampled"
+    assert stats["validated"] == rejected
+
+
+def test_sampling_percent_is_checked():
+    with pytest.raises(ValueError, match="between 0 and 100"):
+        Application().validation_sampling(150)
+
+
+async def test_metrics_time_validated_requests_only():
+    app, metrics, _ = make_app()
+    app.validation_sampling(50)
+    client = TestClient(app)
+    for amount in range(10):
+        assert (await client.post(PATH, json={"account": "a-1", "amount": amount})).status_code == 200
+    for amount in range(4):
+        await client.post("/ledger/commands/post_bulk_entry", json={"account": "a-1", "amount": amount})
+    stats = app.validation_stats()
+    assert stats[f"POST {PATH}"]["validated"] == 5
+    assert stats[f"POST {PATH}"]["seconds"] > 0
+    assert stats["POST /ledger/commands/post_bulk_entry"] == {"mode": "skipped", "validated": 0, "seconds": 0.0}
+    assert metrics.validation.count(method="POST", path=PATH) == 5
+    assert metrics.validation.count(method="POST", path="/ledger/commands/post_bulk_entry") == 0
+    body = (await client.get("/metrics")).content.decode()
+    assert f'urich_validation_duration_seconds_count{{method="POST",path="{PATH}"}} 5' in body